@@ -13,7 +13,7 @@ mod destride {
     fn destride_two(b: &mut Bencher) {
         let a = [0u8; 4096];
         b.iter(|| {
-            for v in a.simd_iter(u8s(0)).unroll(2) {
+            for v in a.simd_iter(u8s(0)).unroll::<2>() {
                 let _ = black_box(v[0].destride_two(v[1]));
             }
         })
@@ -24,7 +24,7 @@ mod destride {
     fn destride_four(b: &mut Bencher) {
         let a = [0u8; 4096];
         b.iter(|| {
-            for v in a.simd_iter(u8s(0)).unroll(4) {
+            for v in a.simd_iter(u8s(0)).unroll::<4>() {
                 let _ = black_box(v[0].destride_four(v[1], v[2], v[3]));
             }
         })
@@ -35,7 +35,7 @@ mod destride {
     fn destride_two_16(b: &mut Bencher) {
         let a = [0u16; 4096];
         b.iter(|| {
-            for v in a.simd_iter(u16s(0)).unroll(2) {
+            for v in a.simd_iter(u16s(0)).unroll::<2>() {
                 let _ = black_box(v[0].destride_two(v[1]));
             }
         })
@@ -46,7 +46,7 @@ mod destride {
     fn destride_four_16(b: &mut Bencher) {
         let a = [0u16; 4096];
         b.iter(|| {
-            for v in a.simd_iter(u16s(0)).unroll(4) {
+            for v in a.simd_iter(u16s(0)).unroll::<4>() {
                 let _ = v[0].destride_four(v[1], v[2], v[3]);
             }
         })
@@ -57,7 +57,7 @@ mod destride {
     fn destride_two_32(b: &mut Bencher) {
         let a = [0u32; 4096];
         b.iter(|| {
-            for v in a.simd_iter(u32s(0)).unroll(2) {
+            for v in a.simd_iter(u32s(0)).unroll::<2>() {
                 let _ = black_box(v[0].destride_two(v[1]));
             }
         })
@@ -68,7 +68,7 @@ mod destride {
     fn destride_four_32(b: &mut Bencher) {
         let a = [0u32; 4096];
         b.iter(|| {
-            for v in a.simd_iter(u32s(0)).unroll(4) {
+            for v in a.simd_iter(u32s(0)).unroll::<4>() {
                 let _ = v[0].destride_four(v[1], v[2], v[3]);
             }
         })
@@ -53,7 +53,7 @@ mod usage {
     fn for_unrolled_simd(b: &mut Bencher) {
         let mut out = [0f32; 1024];
         b.iter(|| {
-            for (i, v) in [-123.456f32; 1024].simd_iter(f32s(0.0)).unroll(8).enumerate() {
+            for (i, v) in [-123.456f32; 1024].simd_iter(f32s(0.0)).unroll::<8>().enumerate() {
                 macro_rules! compute {
                     ($($idx:expr),*) => {
                         $(
@@ -97,7 +97,7 @@ mod usage {
     fn high_latency_unrolled(b: &mut Bencher) {
         let mut out = [0f32; 1024];
         b.iter(|| {
-            for (i, v) in [-123.456f32; 1024].simd_iter(f32s(0.0)).unroll(8).enumerate() {
+            for (i, v) in [-123.456f32; 1024].simd_iter(f32s(0.0)).unroll::<8>().enumerate() {
                 macro_rules! compute {
                     ($($idx:expr),*) => {
                         $(
@@ -191,6 +191,15 @@ mod usage {
         })
     }
 
+    #[bench]
+    fn reduce_n_simd(b: &mut Bencher) {
+        b.iter(|| {
+            black_box(
+                [-123.456f32; 1024].simd_iter(f32s(0.0))
+                    .simd_reduce_n::<4>(f32s(0.0), |a, v| a + f32s(9.0) * v.abs().sqrt().rsqrt().ceil().sqrt(), |a, b| a + b).sum())
+        })
+    }
+
     #[bench]
     fn reduce_scalar(b: &mut Bencher) {
         b.iter(|| {
@@ -360,9 +369,61 @@ mod usage {
     fn stride_zip(b: &mut Bencher) {
         let a = [0u8; 4096];
         b.iter(|| {
-            (&a[..]).simd_iter(u8s(0)).stride_zip()
+            (&a[..]).simd_iter(u8s(0)).stride_zip::<2>()
                 .simd_map(|(a, b)| a + b)
                 .scalar_collect()
         })
     }
+
+    // Large enough not to fit into the cache, so the loads are bound by
+    // memory latency, which prefetching hides.
+    #[cfg(feature = "std")]
+    const PREFETCH_LEN: usize = 1 << 24;
+
+    #[bench]
+    #[cfg(feature = "std")]
+    fn reduce_large_simd(b: &mut Bencher) {
+        let a = vec![1.0f32; PREFETCH_LEN];
+        b.iter(|| {
+            black_box(&a[..]).simd_iter(f32s(0.0))
+                .simd_reduce(f32s(0.0), |acc, v| acc + v)
+                .sum()
+        })
+    }
+
+    #[bench]
+    #[cfg(feature = "std")]
+    fn reduce_large_prefetch_simd(b: &mut Bencher) {
+        let a = vec![1.0f32; PREFETCH_LEN];
+        b.iter(|| {
+            black_box(&a[..]).simd_iter(f32s(0.0))
+                .prefetch(256, Locality::NonTemporal)
+                .simd_reduce(f32s(0.0), |acc, v| acc + v)
+                .sum()
+        })
+    }
+
+    #[bench]
+    #[cfg(feature = "std")]
+    fn stride_large_simd(b: &mut Bencher) {
+        let a = vec![1.0f32; PREFETCH_LEN];
+        b.iter(|| {
+            let data = black_box(&a[..]);
+            let (x, _, _, _) = data.stride_four(tuplify!(4, f32s(0.0)));
+            x.simd_reduce(f32s(0.0), |acc, v| acc + v).sum()
+        })
+    }
+
+    #[bench]
+    #[cfg(feature = "std")]
+    fn stride_large_prefetch_simd(b: &mut Bencher) {
+        let a = vec![1.0f32; PREFETCH_LEN];
+        b.iter(|| {
+            let data = black_box(&a[..]);
+            let (x, _, _, _) = data.stride_four(tuplify!(4, f32s(0.0)));
+            x.prefetch(64, Locality::NonTemporal)
+                .simd_reduce(f32s(0.0), |acc, v| acc + v)
+                .sum()
+        })
+    }
 }
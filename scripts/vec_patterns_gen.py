@@ -28,7 +28,7 @@ header = lambda imports: f"""
 #![allow(unused_imports)]
 
 use crate::arch::current::vecs::*;
-use crate::std::mem::transmute;
+use crate::core::mem::transmute;
 use crate::vecs::*;
 
 {newline.join(imports)}
@@ -71,6 +71,7 @@ def generate_vec_patterns(arch, headers, els, vecs, lens, feats, blends, elsz, m
         for e, v, l, ft, b, s, m in zip(els, vecs, lens, feats, blends, elsz, masks):
             # Generate halfs
             fprint(f"impl Pattern for {v} {{")
+            fprint(f"    type Array = [{e}; {l}];\n")
             fprint(f"    #[inline(always)]")
             fprint(f"    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {{")
             first = ", ".join("hi" for _ in range(l // 2))
@@ -134,6 +135,33 @@ def generate_vec_patterns(arch, headers, els, vecs, lens, feats, blends, elsz, m
                 Self::splat(unsafe {{ transmute(0x{'0' * (s // 4)}{m}) }})
             }}""")
 
+            # Generate iota, using wrapping arithmetic for integers
+            fprint(f"")
+            fprint(f"    #[inline(always)]")
+            fprint(f"    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {{")
+            if e.startswith("f"):
+                lanes = ["start", "start + step"] + [f"start + step * {i}.0" for i in range(2, l)]
+            else:
+                lanes = ["start", "start.wrapping_add(step)"] + [f"start.wrapping_add(step.wrapping_mul({i}))" for i in range(2, l)]
+            fprint(f"        Self::new({', '.join(lanes)})")
+            fprint(f"    }}")
+
+            # Generate from_fn
+            fprint(f"")
+            fprint(f"    #[inline(always)]")
+            fprint(f"    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {{")
+            args = ", ".join(f"f({i})" for i in range(l))
+            fprint(f"        Self::new({args})")
+            fprint(f"    }}")
+
+            # Generate from_array
+            fprint(f"")
+            fprint(f"    #[inline(always)]")
+            fprint(f"    fn from_array(data: Self::Array) -> Self {{")
+            args = ", ".join(f"data[{i}]" for i in range(l))
+            fprint(f"        Self::new({args})")
+            fprint(f"    }}")
+
 
             fprint(f"}}\n")
 
@@ -157,13 +185,13 @@ if "x86":
 
 
 if "unknown":
-    vecs = ["u8x16", "i8x16", "u16x8", "i16x8", "u32x4", "i32x4", "f32x4", "u64x2", "i64x2", "f64x2"]
+    vecs = ["u8x64", "u8x32", "u8x16", "i8x64", "i8x32", "i8x16", "u16x32", "u16x16", "u16x8", "i16x32", "i16x16", "i16x8", "u32x16", "u32x8", "u32x4", "i32x16", "i32x8", "i32x4", "f32x16", "f32x8", "f32x4", "u64x8", "u64x4", "u64x2", "i64x8", "i64x4", "i64x2", "f64x8", "f64x4", "f64x2"]
     lens = [int(v.split("x")[1]) for v in vecs]
     els = [v.split("x")[0] for v in vecs]
     elsz = [int(el[1:]) for el in els]
     masks = ["u" + el[1:] for el in els]
-    feats = [{128: "__undefined"}[l * e] for l, e in zip(lens, elsz)]
-    blends = [{128: "__undefined"}[l * e] for l, e in zip(lens, elsz)]
+    feats = ["__undefined" for _ in vecs]
+    blends = ["__undefined" for _ in vecs]
 
     # Generate file
     generate_vec_patterns("unknown", [], els, vecs, lens, feats, blends, elsz, masks)
@@ -0,0 +1,301 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Buffers which are aligned and padded for vectorized loads and stores.
+
+use crate::core::alloc::{alloc, dealloc, Layout};
+use crate::core::fmt;
+use crate::core::mem::size_of;
+use crate::core::ops::{Deref, DerefMut};
+use crate::core::ptr::{self, NonNull};
+use crate::core::slice::{from_raw_parts, from_raw_parts_mut};
+use crate::iters::{SIMDArray, SIMDArrayMut, SIMDObject, SIMDSized, load_tail_right_aligned};
+use crate::intrin::prefetch::Locality;
+use crate::intrin::gather::{Gather, Scatter};
+use crate::intrin::merge::Merge;
+use crate::intrin::swizzle::Shuffle;
+use crate::arch::current::intrin::prelude::prefetch;
+use crate::vecs::{Packable, Packed};
+
+/// The alignment of an `AlignedVec`, in bytes, which is the size of the
+/// widest vector faster supports.
+pub const ALIGNMENT: usize = 64;
+
+/// A fixed-length buffer whose data is aligned to [`ALIGNMENT`] bytes, and
+/// padded to a multiple of [`ALIGNMENT`] bytes.
+///
+/// Every vector loaded by a SIMD iterator over an `AlignedVec` is loaded
+/// with an aligned load, including the last one, which the padding
+/// completes. Like a slice, the iterator only covers the data, so the last
+/// vector may be partially filled with the iterator's default, rather than
+/// the padding. The padding is only visible through [`as_padded_slice`],
+/// and is set with [`fill_padding`].
+///
+/// ```
+/// extern crate faster;
+/// use faster::*;
+///
+/// # fn main() {
+/// let data = AlignedVec::from_slice(&[1.0f32, 2.0, 3.0], 0.0);
+/// assert_eq!(&data[..], &[1.0, 2.0, 3.0]);
+///
+/// let sum = data.simd_iter(f32s(0.0))
+///     .simd_reduce(f32s(0.0), |acc, v| acc + v)
+///     .sum();
+/// assert_eq!(sum, 6.0);
+/// # }
+/// ```
+///
+/// [`ALIGNMENT`]: constant.ALIGNMENT.html
+/// [`as_padded_slice`]: #method.as_padded_slice
+/// [`fill_padding`]: #method.fill_padding
+pub struct AlignedVec<T> where T : Packable {
+    ptr: NonNull<T>,
+    len: usize,
+    padded_len: usize,
+}
+
+unsafe impl<T> Send for AlignedVec<T> where T : Packable + Send {}
+unsafe impl<T> Sync for AlignedVec<T> where T : Packable + Sync {}
+
+impl<T> AlignedVec<T> where T : Packable {
+    #[inline(always)]
+    fn layout(padded_len: usize) -> Layout {
+        Layout::from_size_align(padded_len * size_of::<T>(), ALIGNMENT).unwrap()
+    }
+
+    /// Allocate a buffer of `len` elements plus padding, all of which are
+    /// initialized to `elem`.
+    fn filled(elem: T, len: usize) -> Self {
+        let per_block = ALIGNMENT / size_of::<T>();
+        let padded_len = (len + per_block - 1) / per_block * per_block;
+        let ptr = if padded_len == 0 {
+            NonNull::dangling()
+        } else {
+            unsafe {
+                let ptr = alloc(Self::layout(padded_len)) as *mut T;
+                assert!(!ptr.is_null(), "couldn't allocate an AlignedVec");
+                for i in 0..padded_len {
+                    ptr::write(ptr.add(i), elem);
+                }
+                NonNull::new_unchecked(ptr)
+            }
+        };
+        AlignedVec { ptr, len, padded_len }
+    }
+
+    /// Return a buffer of `len` copies of `elem`, which is also used as the
+    /// padding.
+    pub fn from_elem(elem: T, len: usize) -> Self {
+        Self::filled(elem, len)
+    }
+
+    /// Return a buffer containing a copy of `data`, padded with `pad`.
+    pub fn from_slice(data: &[T], pad: T) -> Self {
+        let mut ret = Self::filled(pad, data.len());
+        ret.copy_from_slice(data);
+        ret
+    }
+
+    /// Return the number of elements in this buffer, including its padding.
+    #[inline(always)]
+    pub fn padded_len(&self) -> usize {
+        self.padded_len
+    }
+
+    /// Return the elements of this buffer, including its padding.
+    #[inline(always)]
+    pub fn as_padded_slice(&self) -> &[T] {
+        unsafe { from_raw_parts(self.ptr.as_ptr(), self.padded_len) }
+    }
+
+    /// Return the elements of this buffer mutably, including its padding.
+    #[inline(always)]
+    pub fn as_padded_mut_slice(&mut self) -> &mut [T] {
+        unsafe { from_raw_parts_mut(self.ptr.as_ptr(), self.padded_len) }
+    }
+
+    /// Set every element of the padding to `pad`.
+    pub fn fill_padding(&mut self, pad: T) {
+        let len = self.len;
+        for x in self.as_padded_mut_slice()[len..].iter_mut() {
+            *x = pad;
+        }
+    }
+}
+
+impl<T> Drop for AlignedVec<T> where T : Packable {
+    fn drop(&mut self) {
+        if self.padded_len > 0 {
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, Self::layout(self.padded_len)) }
+        }
+    }
+}
+
+impl<T> Clone for AlignedVec<T> where T : Packable {
+    fn clone(&self) -> Self {
+        let mut ret = Self::filled(T::from_usize(0), self.len);
+        ret.as_padded_mut_slice().copy_from_slice(self.as_padded_slice());
+        ret
+    }
+}
+
+impl<T> fmt::Debug for AlignedVec<T> where T : Packable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Deref for AlignedVec<T> where T : Packable {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        unsafe { from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for AlignedVec<T> where T : Packable {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+// Offsets which are a multiple of the vector's width are aligned, because
+// the buffer is aligned to the size of the widest vector.
+macro_rules! impl_aligned_array {
+    ($name:ty, ($($genera:tt),*) $($pred:tt)*) => {
+        impl< $($genera),* > SIMDObject for $name $($pred )* {
+            type Vector = V;
+            type Scalar = S;
+        }
+
+        impl< $($genera),* > SIMDArray for $name $($pred )* {
+            #[inline(always)]
+            fn load(&self, offset: usize) -> Self::Vector {
+                self.load_as(offset)
+            }
+
+            #[inline(always)]
+            unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
+                self.load_as_unchecked(offset)
+            }
+
+            #[inline(always)]
+            fn load_scalar(&self, offset: usize) -> Self::Scalar {
+                self[offset]
+            }
+
+            #[inline(always)]
+            unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar {
+                debug_assert!(offset < self.padded_len());
+                *self.as_padded_slice().get_unchecked(offset)
+            }
+
+            #[inline(always)]
+            fn load_as<W>(&self, offset: usize) -> W where W : Packed<Scalar = Self::Scalar> {
+                assert!(offset + W::WIDTH <= self.len());
+                unsafe { self.load_as_unchecked(offset) }
+            }
+
+            #[inline(always)]
+            unsafe fn load_as_unchecked<W>(&self, offset: usize) -> W where W : Packed<Scalar = Self::Scalar> {
+                if offset % W::WIDTH == 0 {
+                    W::load_aligned_unchecked(self.as_padded_slice(), offset)
+                } else {
+                    W::load_unchecked(self.as_padded_slice(), offset)
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn load_tail_unchecked<W>(&self, offset: usize, empty_amt: usize, default: W) -> W
+                where W : Packed<Scalar = Self::Scalar> + Shuffle {
+                if offset % W::WIDTH == 0 {
+                    // The padding completes the last vector, so load it whole
+                    // and move its elements after the default's.
+                    let last = W::load_aligned_unchecked(self.as_padded_slice(), offset);
+                    default.merge_partitioned(last.rotate_lanes(W::WIDTH - empty_amt), empty_amt)
+                } else {
+                    load_tail_right_aligned(self, offset, empty_amt, default)
+                }
+            }
+
+            #[inline(always)]
+            fn prefetch_at(&self, offset: usize, locality: Locality) {
+                if offset < self.padded_len() {
+                    prefetch(unsafe { self.as_padded_slice().as_ptr().add(offset) }, locality);
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn gather_unchecked(&self, indices: <Self::Vector as Gather>::Indices) -> Self::Vector where Self::Vector : Gather {
+                <Self::Vector as Gather>::gather_unchecked(self.as_padded_slice(), indices)
+            }
+
+            #[inline(always)]
+            unsafe fn gather_masked_unchecked(&self, indices: <Self::Vector as Gather>::Indices, mask: <Self::Vector as Gather>::Mask, src: Self::Vector) -> Self::Vector where Self::Vector : Gather {
+                <Self::Vector as Gather>::gather_masked_unchecked(self.as_padded_slice(), indices, mask, src)
+            }
+        }
+
+        impl< $($genera),* > SIMDSized for $name $($pred )* {
+            #[inline(always)]
+            fn scalar_len(&self) -> usize {
+                self.len()
+            }
+        }
+    }
+}
+
+macro_rules! impl_aligned_array_mut {
+    ($name:ty, ($($genera:tt),*) $($pred:tt)*) => {
+        impl< $($genera),* > SIMDArrayMut for $name $($pred )* {
+            #[inline(always)]
+            fn store(&mut self, value: Self::Vector, offset: usize) {
+                assert!(offset + Self::Vector::WIDTH <= self.padded_len());
+                unsafe { self.store_unchecked(value, offset) }
+            }
+
+            #[inline(always)]
+            unsafe fn store_unchecked(&mut self, value: Self::Vector, offset: usize) {
+                if offset % Self::Vector::WIDTH == 0 {
+                    value.store_aligned_unchecked(self.as_padded_mut_slice(), offset)
+                } else {
+                    value.store_unchecked(self.as_padded_mut_slice(), offset)
+                }
+            }
+
+            #[inline(always)]
+            fn store_scalar(&mut self, value: Self::Scalar, offset: usize) {
+                self.as_padded_mut_slice()[offset] = value;
+            }
+
+            #[inline(always)]
+            unsafe fn store_scalar_unchecked(&mut self, value: Self::Scalar, offset: usize) {
+                debug_assert!(offset < self.padded_len());
+                *self.as_padded_mut_slice().get_unchecked_mut(offset) = value;
+            }
+
+            #[inline(always)]
+            unsafe fn scatter_unchecked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector) where Self::Vector : Scatter {
+                <Self::Vector as Scatter>::scatter_unchecked(values, self.as_padded_mut_slice(), indices)
+            }
+
+            #[inline(always)]
+            unsafe fn scatter_masked_unchecked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector, mask: <Self::Vector as Gather>::Mask) where Self::Vector : Scatter {
+                <Self::Vector as Scatter>::scatter_masked_unchecked(values, self.as_padded_mut_slice(), indices, mask)
+            }
+        }
+    }
+}
+
+impl_aligned_array!(AlignedVec<S>, (S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
+impl_aligned_array!(&'a AlignedVec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
+impl_aligned_array!(&'a mut AlignedVec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
+impl_aligned_array_mut!(AlignedVec<S>, (S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
+impl_aligned_array_mut!(&'a mut AlignedVec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
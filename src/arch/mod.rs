@@ -1,11 +1,14 @@
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+// The `force-unknown-arch` feature builds the polyfills on x86 as well, so
+// they can be tested on the same machine as the hardware implementations.
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "force-unknown-arch")))]
 pub mod x86;
 
-#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[cfg(any(not(any(target_arch = "x86", target_arch = "x86_64")), feature = "force-unknown-arch"))]
 pub mod unknown;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "force-unknown-arch")))]
 pub use self::x86 as current;
 
-#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[cfg(any(not(any(target_arch = "x86", target_arch = "x86_64")), feature = "force-unknown-arch"))]
 pub use self::unknown as current;
@@ -0,0 +1,20 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::addsub::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+
+// Will produce fallback implementations only, so we get away with __undefined.
+impl_addsub!(f32x4, "__undefined", __undefined, [0, 1, 2, 3]);
+impl_addsub!(f64x2, "__undefined", __undefined, [0, 1]);
+
+impl_addsub!(f32x8, "__undefined", __undefined, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_addsub!(f64x4, "__undefined", __undefined, [0, 1, 2, 3]);
+
+impl_addsub!(f32x16, "__undefined", __undefined, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_addsub!(f64x8, "__undefined", __undefined, [0, 1, 2, 3, 4, 5, 6, 7]);
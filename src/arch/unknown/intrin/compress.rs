@@ -0,0 +1,57 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::intrin::compress::*;
+use crate::intrin::eq::*;
+use crate::masks::*;
+use crate::vecs::*;
+
+// Will produce fallback implementations only, so we get away with __undefined.
+impl_compress!(u8x16, "__undefined", __undefined);
+impl_compress!(i8x16, "__undefined", __undefined);
+impl_compress!(u16x8, "__undefined", __undefined);
+impl_compress!(i16x8, "__undefined", __undefined);
+impl_compress!(u32x4, "__undefined", __undefined);
+impl_compress!(i32x4, "__undefined", __undefined);
+impl_compress!(f32x4, "__undefined", __undefined);
+impl_compress!(u64x2, "__undefined", __undefined);
+impl_compress!(i64x2, "__undefined", __undefined);
+impl_compress!(f64x2, "__undefined", __undefined);
+
+impl_compress!(u8x32, "__undefined", __undefined);
+impl_compress!(i8x32, "__undefined", __undefined);
+impl_compress!(u16x16, "__undefined", __undefined);
+impl_compress!(i16x16, "__undefined", __undefined);
+impl_compress!(u32x8, "__undefined", __undefined);
+impl_compress!(i32x8, "__undefined", __undefined);
+impl_compress!(f32x8, "__undefined", __undefined);
+impl_compress!(u64x4, "__undefined", __undefined);
+impl_compress!(i64x4, "__undefined", __undefined);
+impl_compress!(f64x4, "__undefined", __undefined);
+
+impl_compress!(u8x64, "__undefined", __undefined);
+impl_compress!(i8x64, "__undefined", __undefined);
+impl_compress!(u16x32, "__undefined", __undefined);
+impl_compress!(i16x32, "__undefined", __undefined);
+impl_compress!(u32x16, "__undefined", __undefined);
+impl_compress!(i32x16, "__undefined", __undefined);
+impl_compress!(f32x16, "__undefined", __undefined);
+impl_compress!(u64x8, "__undefined", __undefined);
+impl_compress!(i64x8, "__undefined", __undefined);
+impl_compress!(f64x8, "__undefined", __undefined);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_compress!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (compress_u8x16, compress_i8x16, compress_u16x8, compress_i16x8, compress_u32x4, compress_i32x4, compress_f32x4, compress_u64x2, compress_i64x2, compress_f64x2, compress_u8x32, compress_i8x32, compress_u16x16, compress_i16x16, compress_u32x8, compress_i32x8, compress_f32x8, compress_u64x4, compress_i64x4, compress_f64x4, compress_u8x64, compress_i8x64, compress_u16x32, compress_i16x32, compress_u32x16, compress_i32x16, compress_f32x16, compress_u64x8, compress_i64x8, compress_f64x8));
+}
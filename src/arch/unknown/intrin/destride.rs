@@ -14,10 +14,20 @@ impl Destride for u8x16 {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14)
     }
 
+    #[inline(always)]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12)
     }
+
+    #[inline(always)]
+    fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+        destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+    }
 }
 
 impl Destride for u8x32 {
@@ -26,10 +36,20 @@ impl Destride for u8x32 {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30)
     }
 
+    #[inline(always)]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12, 16, 20, 24, 28)
     }
+
+    #[inline(always)]
+    fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+        destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+    }
 }
 
 impl Destride for i8x16 {
@@ -38,10 +58,20 @@ impl Destride for i8x16 {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14)
     }
 
+    #[inline(always)]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12)
     }
+
+    #[inline(always)]
+    fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+        destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+    }
 }
 
 impl Destride for i8x32 {
@@ -50,10 +80,20 @@ impl Destride for i8x32 {
         destride_two_polyfill!(self, other, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30)
     }
 
+    #[inline(always)]
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+        destride_three_polyfill!(self, b, c)
+    }
+
     #[inline(always)]
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12, 16, 20, 24, 28)
     }
+
+    #[inline(always)]
+    fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+        destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+    }
 }
 
 macro_rules! impl_destride {
@@ -64,20 +104,48 @@ macro_rules! impl_destride {
                 destride_two_polyfill!(self, other, $($two, $four),*)
             }
 
+            #[inline(always)]
+            fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+                destride_three_polyfill!(self, b, c)
+            }
+
             #[inline(always)]
             fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
                 destride_four_polyfill!(self, b, c, d, $($two),*)
             }
+
+            #[inline(always)]
+            fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+                destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+            }
         }
     }
 }
 
+impl_destride!(u8x64, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+impl_destride!(i8x64, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+
+impl_destride!(u16x32, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
 impl_destride!(u16x16, 0, 2, 4, 6, 8, 10, 12, 14);
 impl_destride!(u16x8, 0, 2, 4, 6);
+impl_destride!(i16x32, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
 impl_destride!(i16x16, 0, 2, 4, 6, 8, 10, 12, 14);
 impl_destride!(i16x8, 0, 2, 4, 6);
 
+impl_destride!(u32x16, 0, 2, 4, 6, 8, 10, 12, 14);
 impl_destride!(u32x8, 0, 2, 4, 6);
 impl_destride!(u32x4, 0, 2);
+impl_destride!(i32x16, 0, 2, 4, 6, 8, 10, 12, 14);
 impl_destride!(i32x8, 0, 2, 4, 6);
 impl_destride!(i32x4, 0, 2);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_destride!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16),
+        (destride_u8x16, destride_i8x16, destride_u16x8, destride_i16x8, destride_u32x4, destride_i32x4, destride_u8x32, destride_i8x32, destride_u16x16, destride_i16x16, destride_u32x8, destride_i32x8, destride_u8x64, destride_i8x64, destride_u16x32, destride_i16x32, destride_u32x16, destride_i32x16));
+}
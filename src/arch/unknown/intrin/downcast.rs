@@ -36,9 +36,9 @@ impl Downcast<u32x4> for u64x2 {
     #[inline(always)]
     fn saturating_downcast(self, other: Self) -> u32x4 {
         u32x4::new(self.extract(0).min(0xFFFFFFFF) as u32,
-                   self.extract(1).min(0x7FFFFFFF) as u32,
-                   other.extract(0).min(0x7FFFFFFF) as u32,
-                   other.extract(1).min(0x7FFFFFFF) as u32)
+                   self.extract(1).min(0xFFFFFFFF) as u32,
+                   other.extract(0).min(0xFFFFFFFF) as u32,
+                   other.extract(1).min(0xFFFFFFFF) as u32)
     }
 }
 
@@ -248,13 +248,13 @@ impl Downcast<u32x8> for u64x4 {
     #[inline(always)]
     fn saturating_downcast(self, other: Self) -> u32x8 {
         u32x8::new(self.extract(0).min(0xFFFFFFFF) as u32,
-                   self.extract(1).min(0x7FFFFFFF) as u32,
+                   self.extract(1).min(0xFFFFFFFF) as u32,
                    self.extract(2).min(0xFFFFFFFF) as u32,
-                   self.extract(3).min(0x7FFFFFFF) as u32,
-                   other.extract(0).min(0x7FFFFFFF) as u32,
-                   other.extract(1).min(0x7FFFFFFF) as u32,
-                   other.extract(2).min(0x7FFFFFFF) as u32,
-                   other.extract(3).min(0x7FFFFFFF) as u32)
+                   self.extract(3).min(0xFFFFFFFF) as u32,
+                   other.extract(0).min(0xFFFFFFFF) as u32,
+                   other.extract(1).min(0xFFFFFFFF) as u32,
+                   other.extract(2).min(0xFFFFFFFF) as u32,
+                   other.extract(3).min(0xFFFFFFFF) as u32)
     }
 }
 
@@ -271,3 +271,285 @@ impl Downcast<f32x8> for f64x4 {
                    other.extract(3) as f32)
     }
 }
+
+impl Downcast<i16x32> for i32x16 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> i16x32 {
+        i16x32::new(self.extract(0).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(1).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(2).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(3).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(4).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(5).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(6).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(7).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(8).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(9).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(10).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(11).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(12).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(13).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(14).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(15).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(0).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(1).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(2).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(3).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(4).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(5).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(6).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(7).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(8).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(9).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(10).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(11).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(12).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(13).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(14).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(15).min(0x00007FFF).max(-0x00008000) as i16)
+    }
+}
+
+impl Downcast<i32x16> for i64x8 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> i32x16 {
+        i32x16::new(self.extract(0).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(1).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(2).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(3).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(4).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(5).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(6).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(7).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(0).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(1).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(2).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(3).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(4).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(5).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(6).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(7).min(0x7FFFFFFF).max(-0x80000000) as i32)
+    }
+}
+
+impl Downcast<u32x16> for u64x8 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> u32x16 {
+        u32x16::new(self.extract(0).min(0xFFFFFFFF) as u32,
+                    self.extract(1).min(0xFFFFFFFF) as u32,
+                    self.extract(2).min(0xFFFFFFFF) as u32,
+                    self.extract(3).min(0xFFFFFFFF) as u32,
+                    self.extract(4).min(0xFFFFFFFF) as u32,
+                    self.extract(5).min(0xFFFFFFFF) as u32,
+                    self.extract(6).min(0xFFFFFFFF) as u32,
+                    self.extract(7).min(0xFFFFFFFF) as u32,
+                    other.extract(0).min(0xFFFFFFFF) as u32,
+                    other.extract(1).min(0xFFFFFFFF) as u32,
+                    other.extract(2).min(0xFFFFFFFF) as u32,
+                    other.extract(3).min(0xFFFFFFFF) as u32,
+                    other.extract(4).min(0xFFFFFFFF) as u32,
+                    other.extract(5).min(0xFFFFFFFF) as u32,
+                    other.extract(6).min(0xFFFFFFFF) as u32,
+                    other.extract(7).min(0xFFFFFFFF) as u32)
+    }
+}
+
+impl Downcast<f32x16> for f64x8 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> f32x16 {
+        f32x16::new(self.extract(0) as f32,
+                    self.extract(1) as f32,
+                    self.extract(2) as f32,
+                    self.extract(3) as f32,
+                    self.extract(4) as f32,
+                    self.extract(5) as f32,
+                    self.extract(6) as f32,
+                    self.extract(7) as f32,
+                    other.extract(0) as f32,
+                    other.extract(1) as f32,
+                    other.extract(2) as f32,
+                    other.extract(3) as f32,
+                    other.extract(4) as f32,
+                    other.extract(5) as f32,
+                    other.extract(6) as f32,
+                    other.extract(7) as f32)
+    }
+}
+
+impl Downcast<i8x64> for i16x32 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> i8x64 {
+        i8x64::new(self.extract(0).min(0x007F).max(-0x0080) as i8,
+                   self.extract(1).min(0x007F).max(-0x0080) as i8,
+                   self.extract(2).min(0x007F).max(-0x0080) as i8,
+                   self.extract(3).min(0x007F).max(-0x0080) as i8,
+                   self.extract(4).min(0x007F).max(-0x0080) as i8,
+                   self.extract(5).min(0x007F).max(-0x0080) as i8,
+                   self.extract(6).min(0x007F).max(-0x0080) as i8,
+                   self.extract(7).min(0x007F).max(-0x0080) as i8,
+                   self.extract(8).min(0x007F).max(-0x0080) as i8,
+                   self.extract(9).min(0x007F).max(-0x0080) as i8,
+                   self.extract(10).min(0x007F).max(-0x0080) as i8,
+                   self.extract(11).min(0x007F).max(-0x0080) as i8,
+                   self.extract(12).min(0x007F).max(-0x0080) as i8,
+                   self.extract(13).min(0x007F).max(-0x0080) as i8,
+                   self.extract(14).min(0x007F).max(-0x0080) as i8,
+                   self.extract(15).min(0x007F).max(-0x0080) as i8,
+                   self.extract(16).min(0x007F).max(-0x0080) as i8,
+                   self.extract(17).min(0x007F).max(-0x0080) as i8,
+                   self.extract(18).min(0x007F).max(-0x0080) as i8,
+                   self.extract(19).min(0x007F).max(-0x0080) as i8,
+                   self.extract(20).min(0x007F).max(-0x0080) as i8,
+                   self.extract(21).min(0x007F).max(-0x0080) as i8,
+                   self.extract(22).min(0x007F).max(-0x0080) as i8,
+                   self.extract(23).min(0x007F).max(-0x0080) as i8,
+                   self.extract(24).min(0x007F).max(-0x0080) as i8,
+                   self.extract(25).min(0x007F).max(-0x0080) as i8,
+                   self.extract(26).min(0x007F).max(-0x0080) as i8,
+                   self.extract(27).min(0x007F).max(-0x0080) as i8,
+                   self.extract(28).min(0x007F).max(-0x0080) as i8,
+                   self.extract(29).min(0x007F).max(-0x0080) as i8,
+                   self.extract(30).min(0x007F).max(-0x0080) as i8,
+                   self.extract(31).min(0x007F).max(-0x0080) as i8,
+                   other.extract(0).min(0x007F).max(-0x0080) as i8,
+                   other.extract(1).min(0x007F).max(-0x0080) as i8,
+                   other.extract(2).min(0x007F).max(-0x0080) as i8,
+                   other.extract(3).min(0x007F).max(-0x0080) as i8,
+                   other.extract(4).min(0x007F).max(-0x0080) as i8,
+                   other.extract(5).min(0x007F).max(-0x0080) as i8,
+                   other.extract(6).min(0x007F).max(-0x0080) as i8,
+                   other.extract(7).min(0x007F).max(-0x0080) as i8,
+                   other.extract(8).min(0x007F).max(-0x0080) as i8,
+                   other.extract(9).min(0x007F).max(-0x0080) as i8,
+                   other.extract(10).min(0x007F).max(-0x0080) as i8,
+                   other.extract(11).min(0x007F).max(-0x0080) as i8,
+                   other.extract(12).min(0x007F).max(-0x0080) as i8,
+                   other.extract(13).min(0x007F).max(-0x0080) as i8,
+                   other.extract(14).min(0x007F).max(-0x0080) as i8,
+                   other.extract(15).min(0x007F).max(-0x0080) as i8,
+                   other.extract(16).min(0x007F).max(-0x0080) as i8,
+                   other.extract(17).min(0x007F).max(-0x0080) as i8,
+                   other.extract(18).min(0x007F).max(-0x0080) as i8,
+                   other.extract(19).min(0x007F).max(-0x0080) as i8,
+                   other.extract(20).min(0x007F).max(-0x0080) as i8,
+                   other.extract(21).min(0x007F).max(-0x0080) as i8,
+                   other.extract(22).min(0x007F).max(-0x0080) as i8,
+                   other.extract(23).min(0x007F).max(-0x0080) as i8,
+                   other.extract(24).min(0x007F).max(-0x0080) as i8,
+                   other.extract(25).min(0x007F).max(-0x0080) as i8,
+                   other.extract(26).min(0x007F).max(-0x0080) as i8,
+                   other.extract(27).min(0x007F).max(-0x0080) as i8,
+                   other.extract(28).min(0x007F).max(-0x0080) as i8,
+                   other.extract(29).min(0x007F).max(-0x0080) as i8,
+                   other.extract(30).min(0x007F).max(-0x0080) as i8,
+                   other.extract(31).min(0x007F).max(-0x0080) as i8)
+    }
+}
+
+impl Downcast<u16x32> for u32x16 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> u16x32 {
+        u16x32::new(self.extract(0).min(0x0000FFFF) as u16,
+                    self.extract(1).min(0x0000FFFF) as u16,
+                    self.extract(2).min(0x0000FFFF) as u16,
+                    self.extract(3).min(0x0000FFFF) as u16,
+                    self.extract(4).min(0x0000FFFF) as u16,
+                    self.extract(5).min(0x0000FFFF) as u16,
+                    self.extract(6).min(0x0000FFFF) as u16,
+                    self.extract(7).min(0x0000FFFF) as u16,
+                    self.extract(8).min(0x0000FFFF) as u16,
+                    self.extract(9).min(0x0000FFFF) as u16,
+                    self.extract(10).min(0x0000FFFF) as u16,
+                    self.extract(11).min(0x0000FFFF) as u16,
+                    self.extract(12).min(0x0000FFFF) as u16,
+                    self.extract(13).min(0x0000FFFF) as u16,
+                    self.extract(14).min(0x0000FFFF) as u16,
+                    self.extract(15).min(0x0000FFFF) as u16,
+                    other.extract(0).min(0x0000FFFF) as u16,
+                    other.extract(1).min(0x0000FFFF) as u16,
+                    other.extract(2).min(0x0000FFFF) as u16,
+                    other.extract(3).min(0x0000FFFF) as u16,
+                    other.extract(4).min(0x0000FFFF) as u16,
+                    other.extract(5).min(0x0000FFFF) as u16,
+                    other.extract(6).min(0x0000FFFF) as u16,
+                    other.extract(7).min(0x0000FFFF) as u16,
+                    other.extract(8).min(0x0000FFFF) as u16,
+                    other.extract(9).min(0x0000FFFF) as u16,
+                    other.extract(10).min(0x0000FFFF) as u16,
+                    other.extract(11).min(0x0000FFFF) as u16,
+                    other.extract(12).min(0x0000FFFF) as u16,
+                    other.extract(13).min(0x0000FFFF) as u16,
+                    other.extract(14).min(0x0000FFFF) as u16,
+                    other.extract(15).min(0x0000FFFF) as u16)
+    }
+}
+
+impl Downcast<u8x64> for u16x32 {
+    #[inline(always)]
+    fn saturating_downcast(self, other: Self) -> u8x64 {
+        u8x64::new(self.extract(0).min(0x00FF) as u8,
+                   self.extract(1).min(0x00FF) as u8,
+                   self.extract(2).min(0x00FF) as u8,
+                   self.extract(3).min(0x00FF) as u8,
+                   self.extract(4).min(0x00FF) as u8,
+                   self.extract(5).min(0x00FF) as u8,
+                   self.extract(6).min(0x00FF) as u8,
+                   self.extract(7).min(0x00FF) as u8,
+                   self.extract(8).min(0x00FF) as u8,
+                   self.extract(9).min(0x00FF) as u8,
+                   self.extract(10).min(0x00FF) as u8,
+                   self.extract(11).min(0x00FF) as u8,
+                   self.extract(12).min(0x00FF) as u8,
+                   self.extract(13).min(0x00FF) as u8,
+                   self.extract(14).min(0x00FF) as u8,
+                   self.extract(15).min(0x00FF) as u8,
+                   self.extract(16).min(0x00FF) as u8,
+                   self.extract(17).min(0x00FF) as u8,
+                   self.extract(18).min(0x00FF) as u8,
+                   self.extract(19).min(0x00FF) as u8,
+                   self.extract(20).min(0x00FF) as u8,
+                   self.extract(21).min(0x00FF) as u8,
+                   self.extract(22).min(0x00FF) as u8,
+                   self.extract(23).min(0x00FF) as u8,
+                   self.extract(24).min(0x00FF) as u8,
+                   self.extract(25).min(0x00FF) as u8,
+                   self.extract(26).min(0x00FF) as u8,
+                   self.extract(27).min(0x00FF) as u8,
+                   self.extract(28).min(0x00FF) as u8,
+                   self.extract(29).min(0x00FF) as u8,
+                   self.extract(30).min(0x00FF) as u8,
+                   self.extract(31).min(0x00FF) as u8,
+                   other.extract(0).min(0x00FF) as u8,
+                   other.extract(1).min(0x00FF) as u8,
+                   other.extract(2).min(0x00FF) as u8,
+                   other.extract(3).min(0x00FF) as u8,
+                   other.extract(4).min(0x00FF) as u8,
+                   other.extract(5).min(0x00FF) as u8,
+                   other.extract(6).min(0x00FF) as u8,
+                   other.extract(7).min(0x00FF) as u8,
+                   other.extract(8).min(0x00FF) as u8,
+                   other.extract(9).min(0x00FF) as u8,
+                   other.extract(10).min(0x00FF) as u8,
+                   other.extract(11).min(0x00FF) as u8,
+                   other.extract(12).min(0x00FF) as u8,
+                   other.extract(13).min(0x00FF) as u8,
+                   other.extract(14).min(0x00FF) as u8,
+                   other.extract(15).min(0x00FF) as u8,
+                   other.extract(16).min(0x00FF) as u8,
+                   other.extract(17).min(0x00FF) as u8,
+                   other.extract(18).min(0x00FF) as u8,
+                   other.extract(19).min(0x00FF) as u8,
+                   other.extract(20).min(0x00FF) as u8,
+                   other.extract(21).min(0x00FF) as u8,
+                   other.extract(22).min(0x00FF) as u8,
+                   other.extract(23).min(0x00FF) as u8,
+                   other.extract(24).min(0x00FF) as u8,
+                   other.extract(25).min(0x00FF) as u8,
+                   other.extract(26).min(0x00FF) as u8,
+                   other.extract(27).min(0x00FF) as u8,
+                   other.extract(28).min(0x00FF) as u8,
+                   other.extract(29).min(0x00FF) as u8,
+                   other.extract(30).min(0x00FF) as u8,
+                   other.extract(31).min(0x00FF) as u8)
+    }
+}
@@ -0,0 +1,29 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::fma::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+
+// Will produce fallback implementations only, so we get away with __undefined.
+impl_fma!(f32x4, "__undefined", (__undefined, __undefined, __undefined, __undefined), [0, 1, 2, 3]);
+impl_fma!(f64x2, "__undefined", (__undefined, __undefined, __undefined, __undefined), [0, 1]);
+
+impl_fma!(f32x8, "__undefined", (__undefined, __undefined, __undefined, __undefined), [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_fma!(f64x4, "__undefined", (__undefined, __undefined, __undefined, __undefined), [0, 1, 2, 3]);
+
+impl_fma!(f32x16, "__undefined", (__undefined, __undefined, __undefined, __undefined), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_fma!(f64x8, "__undefined", (__undefined, __undefined, __undefined, __undefined), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_fma!((f32x4, f64x2, f32x8, f64x4, f32x16, f64x8), (f32, f64, f32, f64, f32, f64), (fma_f32x4, fma_f64x2, fma_f32x8, fma_f64x4, fma_f32x16, fma_f64x8));
+}
@@ -0,0 +1,67 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::arch::current::masks::*;
+use crate::intrin::gather::*;
+use crate::masks::*;
+use crate::vecs::*;
+
+// Will produce fallback implementations only, so we get away with __undefined.
+impl_gather!(u32x4, u32x4, m32x4, 4, "__undefined", __undefined, __undefined);
+impl_gather!(i32x4, u32x4, m32x4, 4, "__undefined", __undefined, __undefined);
+impl_gather!(f32x4, u32x4, m32x4, 4, "__undefined", __undefined, __undefined);
+impl_gather!(u64x2, u64x2, m64x2, 8, "__undefined", __undefined, __undefined);
+impl_gather!(i64x2, u64x2, m64x2, 8, "__undefined", __undefined, __undefined);
+impl_gather!(f64x2, u64x2, m64x2, 8, "__undefined", __undefined, __undefined);
+
+impl_gather!(u32x8, u32x8, m32x8, 4, "__undefined", __undefined, __undefined);
+impl_gather!(i32x8, u32x8, m32x8, 4, "__undefined", __undefined, __undefined);
+impl_gather!(f32x8, u32x8, m32x8, 4, "__undefined", __undefined, __undefined);
+impl_gather!(u64x4, u64x4, m64x4, 8, "__undefined", __undefined, __undefined);
+impl_gather!(i64x4, u64x4, m64x4, 8, "__undefined", __undefined, __undefined);
+impl_gather!(f64x4, u64x4, m64x4, 8, "__undefined", __undefined, __undefined);
+
+impl_gather!(u32x16, u32x16, m32x16, 4, "__undefined", __undefined, __undefined);
+impl_gather!(i32x16, u32x16, m32x16, 4, "__undefined", __undefined, __undefined);
+impl_gather!(f32x16, u32x16, m32x16, 4, "__undefined", __undefined, __undefined);
+impl_gather!(u64x8, u64x8, m64x8, 8, "__undefined", __undefined, __undefined);
+impl_gather!(i64x8, u64x8, m64x8, 8, "__undefined", __undefined, __undefined);
+impl_gather!(f64x8, u64x8, m64x8, 8, "__undefined", __undefined, __undefined);
+
+impl_scatter!(u32x4, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(i32x4, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(f32x4, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(u64x2, 8, "__undefined", __undefined, __undefined);
+impl_scatter!(i64x2, 8, "__undefined", __undefined, __undefined);
+impl_scatter!(f64x2, 8, "__undefined", __undefined, __undefined);
+
+impl_scatter!(u32x8, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(i32x8, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(f32x8, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(u64x4, 8, "__undefined", __undefined, __undefined);
+impl_scatter!(i64x4, 8, "__undefined", __undefined, __undefined);
+impl_scatter!(f64x4, 8, "__undefined", __undefined, __undefined);
+
+impl_scatter!(u32x16, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(i32x16, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(f32x16, 4, "__undefined", __undefined, __undefined);
+impl_scatter!(u64x8, 8, "__undefined", __undefined, __undefined);
+impl_scatter!(i64x8, 8, "__undefined", __undefined, __undefined);
+impl_scatter!(f64x8, 8, "__undefined", __undefined, __undefined);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_gather!(
+        (u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (u32x4, u32x4, u32x4, u64x2, u64x2, u64x2, u32x8, u32x8, u32x8, u64x4, u64x4, u64x4, u32x16, u32x16, u32x16, u64x8, u64x8, u64x8),
+        (gather_u32x4, gather_i32x4, gather_f32x4, gather_u64x2, gather_i64x2, gather_f64x2, gather_u32x8, gather_i32x8, gather_f32x8, gather_u64x4, gather_i64x4, gather_f64x4, gather_u32x16, gather_i32x16, gather_f32x16, gather_u64x8, gather_i64x8, gather_f64x8));
+}
@@ -0,0 +1,54 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::intrin::interleave::*;
+use crate::vecs::*;
+
+impl_interleave_polyfill!(u8x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(i8x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(u16x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(i16x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(u32x4, 2, 0, 1);
+impl_interleave_polyfill!(i32x4, 2, 0, 1);
+impl_interleave_polyfill!(f32x4, 2, 0, 1);
+impl_interleave_polyfill!(u64x2, 1, 0);
+impl_interleave_polyfill!(i64x2, 1, 0);
+impl_interleave_polyfill!(f64x2, 1, 0);
+
+impl_interleave_polyfill!(u8x32, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave_polyfill!(i8x32, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave_polyfill!(u16x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(i16x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(u32x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(i32x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(f32x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(u64x4, 2, 0, 1);
+impl_interleave_polyfill!(i64x4, 2, 0, 1);
+impl_interleave_polyfill!(f64x4, 2, 0, 1);
+
+impl_interleave_polyfill!(u8x64, 32, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+impl_interleave_polyfill!(i8x64, 32, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+impl_interleave_polyfill!(u16x32, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave_polyfill!(i16x32, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave_polyfill!(u32x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(i32x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(f32x16, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave_polyfill!(u64x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(i64x8, 4, 0, 1, 2, 3);
+impl_interleave_polyfill!(f64x8, 4, 0, 1, 2, 3);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_interleave!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (interleave_u8x16, interleave_i8x16, interleave_u16x8, interleave_i16x8, interleave_u32x4, interleave_i32x4, interleave_f32x4, interleave_u64x2, interleave_i64x2, interleave_f64x2, interleave_u8x32, interleave_i8x32, interleave_u16x16, interleave_i16x16, interleave_u32x8, interleave_i32x8, interleave_f32x8, interleave_u64x4, interleave_i64x4, interleave_f64x4, interleave_u8x64, interleave_i8x64, interleave_u16x32, interleave_i16x32, interleave_u32x16, interleave_i32x16, interleave_f32x16, interleave_u64x8, interleave_i64x8, interleave_f64x8));
+}
@@ -6,45 +6,73 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 mod abs;
+mod addsub;
 mod cmp;
+mod compress;
 mod destride;
 mod downcast;
 mod endian;
 mod eq;
+mod fma;
+mod gather;
 mod hadd;
 mod hsub;
+mod interleave;
+mod lookup;
 mod merge;
+mod ord;
+mod popcnt;
+mod prefetch;
+mod prefix_sum;
 mod recip;
 mod round;
 mod rsqrt;
+mod select;
+mod shift;
 mod saturating_add;
 mod saturating_hadd;
 mod saturating_sub;
 mod saturating_hsub;
 mod sum;
 mod sqrt;
+mod stream;
+mod swizzle;
 mod transmute;
 mod upcast;
 
 pub mod prelude {
     pub use super::abs::*;
+    pub use super::addsub::*;
     pub use super::cmp::*;
+    pub use super::compress::*;
     pub use super::destride::*;
     pub use super::downcast::*;
     pub use super::endian::*;
     pub use super::eq::*;
+    pub use super::fma::*;
+    pub use super::gather::*;
     pub use super::hadd::*;
     pub use super::hsub::*;
+    pub use super::interleave::*;
+    pub use super::lookup::*;
     pub use super::merge::*;
+    pub use super::ord::*;
+    pub use super::popcnt::*;
+    pub use super::prefetch::*;
+    pub use super::prefix_sum::*;
     pub use super::recip::*;
     pub use super::round::*;
     pub use super::rsqrt::*;
+    pub use super::select::*;
+    pub use super::shift::*;
     pub use super::saturating_add::*;
     pub use super::saturating_hadd::*;
     pub use super::saturating_hsub::*;
     pub use super::saturating_sub::*;
     pub use super::sum::*;
     pub use super::sqrt::*;
+    pub use super::stream::*;
+    pub use super::swizzle::*;
     pub use super::transmute::*;
     pub use super::upcast::*;
 }
@@ -0,0 +1,253 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::eq::*;
+use crate::intrin::ord::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u8x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i8x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u16x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i16x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u32x4 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i32x4 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for f32x4 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3];
+        lt_mask, lt => __undefined(), [0, 1, 2, 3];
+        ge_mask, ge => __undefined(), [0, 1, 2, 3];
+        le_mask, le => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for f64x2 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1];
+        lt_mask, lt => __undefined(), [0, 1];
+        ge_mask, ge => __undefined(), [0, 1];
+        le_mask, le => __undefined(), [0, 1];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u64x2 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i64x2 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u8x32 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i8x32 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u16x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i16x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u32x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i32x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for f32x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        lt_mask, lt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        ge_mask, ge => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        le_mask, le => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for f64x4 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3];
+        lt_mask, lt => __undefined(), [0, 1, 2, 3];
+        ge_mask, ge => __undefined(), [0, 1, 2, 3];
+        le_mask, le => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u64x4 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i64x4 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u8x64 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i8x64 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u16x32 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i16x32 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u32x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i32x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for f32x16 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        lt_mask, lt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        ge_mask, ge => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        le_mask, le => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for f64x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        lt_mask, lt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        ge_mask, ge => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        le_mask, le => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for u64x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_ord! {
+    impl PartialOrdMask for i64x8 where "__undefined" {
+        gt_mask, gt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+mod tests {
+    #![allow(unused_imports)]
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_ord!(u8x16, u8, u8x16, u8, test_ord_u8x16);
+    test_packed_ord!(i8x16, i8, u8x16, u8, test_ord_i8x16);
+    test_packed_ord!(u16x8, u16, u16x8, u16, test_ord_u16x8);
+    test_packed_ord!(i16x8, i16, u16x8, u16, test_ord_i16x8);
+    test_packed_ord!(u32x4, u32, u32x4, u32, test_ord_u32x4);
+    test_packed_ord!(i32x4, i32, u32x4, u32, test_ord_i32x4);
+    test_packed_ord!(f32x4, f32, u32x4, u32, test_ord_f32x4);
+    test_packed_ord!(f64x2, f64, u64x2, u64, test_ord_f64x2);
+    test_packed_ord!(u64x2, u64, u64x2, u64, test_ord_u64x2);
+    test_packed_ord!(i64x2, i64, u64x2, u64, test_ord_i64x2);
+    test_packed_ord!(u8x32, u8, u8x32, u8, test_ord_u8x32);
+    test_packed_ord!(i8x32, i8, u8x32, u8, test_ord_i8x32);
+    test_packed_ord!(u16x16, u16, u16x16, u16, test_ord_u16x16);
+    test_packed_ord!(i16x16, i16, u16x16, u16, test_ord_i16x16);
+    test_packed_ord!(u32x8, u32, u32x8, u32, test_ord_u32x8);
+    test_packed_ord!(i32x8, i32, u32x8, u32, test_ord_i32x8);
+    test_packed_ord!(f32x8, f32, u32x8, u32, test_ord_f32x8);
+    test_packed_ord!(f64x4, f64, u64x4, u64, test_ord_f64x4);
+    test_packed_ord!(u64x4, u64, u64x4, u64, test_ord_u64x4);
+    test_packed_ord!(i64x4, i64, u64x4, u64, test_ord_i64x4);
+    test_packed_ord!(u8x64, u8, u8x64, u8, test_ord_u8x64);
+    test_packed_ord!(i8x64, i8, u8x64, u8, test_ord_i8x64);
+    test_packed_ord!(u16x32, u16, u16x32, u16, test_ord_u16x32);
+    test_packed_ord!(i16x32, i16, u16x32, u16, test_ord_i16x32);
+    test_packed_ord!(u32x16, u32, u32x16, u32, test_ord_u32x16);
+    test_packed_ord!(i32x16, i32, u32x16, u32, test_ord_i32x16);
+    test_packed_ord!(f32x16, f32, u32x16, u32, test_ord_f32x16);
+    test_packed_ord!(f64x8, f64, u64x8, u64, test_ord_f64x8);
+    test_packed_ord!(u64x8, u64, u64x8, u64, test_ord_u64x8);
+    test_packed_ord!(i64x8, i64, u64x8, u64, test_ord_i64x8);
+
+    test_packed_ord_float!(f32x4, f32, u32x4, u32, test_ord_nan_f32x4);
+    test_packed_ord_float!(f64x2, f64, u64x2, u64, test_ord_nan_f64x2);
+    test_packed_ord_float!(f32x8, f32, u32x8, u32, test_ord_nan_f32x8);
+    test_packed_ord_float!(f64x4, f64, u64x4, u64, test_ord_nan_f64x4);
+    test_packed_ord_float!(f32x16, f32, u32x16, u32, test_ord_nan_f32x16);
+    test_packed_ord_float!(f64x8, f64, u64x8, u64, test_ord_nan_f64x8);
+}
@@ -0,0 +1,44 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::popcnt::*;
+use crate::intrin::transmute::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+
+#[inline(always)]
+fn popcnt128(v: u8x16) -> usize {
+    v.be_u64s().scalar_reduce(0, |acc, s| acc + (s.count_ones() as usize))
+}
+
+#[inline(always)]
+fn popcnt256(v: u8x32) -> usize {
+    v.be_u64s().scalar_reduce(0, |acc, s| acc + (s.count_ones() as usize))
+}
+
+#[inline(always)]
+fn popcnt512(v: u8x64) -> usize {
+    v.be_u64s().scalar_reduce(0, |acc, s| acc + (s.count_ones() as usize))
+}
+
+impl_popcnt!(u8x16, popcnt128, i8x16, popcnt128, u16x8, popcnt128, i16x8, popcnt128,
+             u32x4, popcnt128, i32x4, popcnt128, u64x2, popcnt128, i64x2, popcnt128);
+impl_popcnt!(u8x32, popcnt256, i8x32, popcnt256, u16x16, popcnt256, i16x16, popcnt256,
+             u32x8, popcnt256, i32x8, popcnt256, u64x4, popcnt256, i64x4, popcnt256);
+impl_popcnt!(u8x64, popcnt512, i8x64, popcnt512, u16x32, popcnt512, i16x32, popcnt512,
+             u32x16, popcnt512, i32x16, popcnt512, u64x8, popcnt512, i64x8, popcnt512);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_popcnt!((u8, i8, u16, i16, u32, i32, u64, i64, u8, i8, u16, i16, u32, i32, u64, i64, u8, i8, u16, i16, u32, i32, u64, i64),
+                 (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8),
+                 (popcnt_u8x16, popcnt_i8x16, popcnt_u16x8, popcnt_i16x8, popcnt_u32x4, popcnt_i32x4, popcnt_u64x2, popcnt_i64x2, popcnt_u8x32, popcnt_i8x32, popcnt_u16x16, popcnt_i16x16, popcnt_u32x8, popcnt_i32x8, popcnt_u64x4, popcnt_i64x4, popcnt_u8x64, popcnt_i8x64, popcnt_u16x32, popcnt_i16x32, popcnt_u32x16, popcnt_i32x16, popcnt_u64x8, popcnt_i64x8));
+}
@@ -0,0 +1,15 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::prefetch::*;
+
+/// Hint that the cache line containing `ptr` will be read soon. There's no
+/// portable way to do so, so this does nothing.
+#[inline(always)]
+pub fn prefetch<T>(_ptr: *const T, _locality: Locality) {
+    fallback!();
+}
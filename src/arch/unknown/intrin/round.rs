@@ -44,3 +44,21 @@ rust_fallback_impl! {
         trunc => __undefined(_MM_FROUND_TRUNC), [0, 1, 2, 3];
     }
 }
+
+rust_fallback_impl! {
+    impl Round for f32x16 where "__undefined" {
+        round => __undefined(_MM_FROUND_TO_NEAREST_INT), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        ceil => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        floor => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        trunc => __undefined(_MM_FROUND_TRUNC), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl! {
+    impl Round for f64x8 where "__undefined" {
+        round => __undefined(_MM_FROUND_TO_NEAREST_INT), [0, 1, 2, 3, 4, 5, 6, 7];
+        ceil => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        floor => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+        trunc => __undefined(_MM_FROUND_TRUNC), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
@@ -9,14 +9,6 @@ use crate::intrin::rsqrt::*;
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
 
-// TODO: Guards and non-simd
-//
-//rust_fallback_impl! {
-//    impl Rsqrt for f32x8 where "__undefined" {
-//        rsqrt => _mm256_rsqrt_ps(), [0, 1, 2, 3, 4, 5, 6, 7];
-//    }
-//}
-
 rust_fallback_impl! {
     impl Rsqrt for f32x4 where "__undefined" {
         rsqrt => __undefined(), [0, 1, 2, 3];
@@ -29,6 +21,30 @@ rust_fallback_impl! {
     }
 }
 
+rust_fallback_impl! {
+    impl Rsqrt for f32x8 where "__undefined" {
+        rsqrt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_impl! {
+    impl Rsqrt for f64x4 where "__undefined" {
+        rsqrt => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_impl! {
+    impl Rsqrt for f32x16 where "__undefined" {
+        rsqrt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl! {
+    impl Rsqrt for f64x8 where "__undefined" {
+        rsqrt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
 impl Rsqrt for f32 {
     #[inline(always)]
     fn rsqrt(&self) -> Self {
@@ -56,3 +56,99 @@ rust_fallback_impl_binary! {
         saturating_add => __undefined(), [0, 1];
     }
 }
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u8x32 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i8x32 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u16x16 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i16x16 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u32x8 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i32x8 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u64x4 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i64x4 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u8x64 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i8x64 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u16x32 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i16x32 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u32x16 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i32x16 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for u64x8 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingAdd for i64x8 where "__undefined" {
+        saturating_add => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
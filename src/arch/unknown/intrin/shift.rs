@@ -0,0 +1,51 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::intrin::shift::*;
+use crate::vecs::*;
+use crate::core::mem::transmute;
+
+// Will produce fallback implementations only, so we get away with __undefined.
+impl_shift!(u8x16, u8, u8x16, u8, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i8x16, i8, u8x16, u8, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u16x8, u16, u16x8, u16, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i16x8, i16, u16x8, u16, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u32x4, u32, u32x4, u32, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i32x4, i32, u32x4, u32, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u64x2, u64, u64x2, u64, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i64x2, i64, u64x2, u64, ("__undefined", __undefined), ("__undefined", __undefined));
+
+impl_shift!(u8x32, u8, u8x32, u8, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i8x32, i8, u8x32, u8, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u16x16, u16, u16x16, u16, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i16x16, i16, u16x16, u16, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u32x8, u32, u32x8, u32, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i32x8, i32, u32x8, u32, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u64x4, u64, u64x4, u64, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i64x4, i64, u64x4, u64, ("__undefined", __undefined), ("__undefined", __undefined));
+
+impl_shift!(u8x64, u8, u8x64, u8, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i8x64, i8, u8x64, u8, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u16x32, u16, u16x32, u16, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i16x32, i16, u16x32, u16, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u32x16, u32, u32x16, u32, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i32x16, i32, u32x16, u32, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(u64x8, u64, u64x8, u64, ("__undefined", __undefined), ("__undefined", __undefined));
+impl_shift!(i64x8, i64, u64x8, u64, ("__undefined", __undefined), ("__undefined", __undefined));
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_shift!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8),
+        (u8, i8, u16, i16, u32, i32, u64, i64, u8, i8, u16, i16, u32, i32, u64, i64, u8, i8, u16, i16, u32, i32, u64, i64),
+        (shift_u8x16, shift_i8x16, shift_u16x8, shift_i16x8, shift_u32x4, shift_i32x4, shift_u64x2, shift_i64x2, shift_u8x32, shift_i8x32, shift_u16x16, shift_i16x16, shift_u32x8, shift_i32x8, shift_u64x4, shift_i64x4, shift_u8x64, shift_i8x64, shift_u16x32, shift_i16x32, shift_u32x16, shift_i32x16, shift_u64x8, shift_i64x8));
+}
@@ -20,3 +20,27 @@ rust_fallback_impl! {
         sqrt => __undefined(), [0, 1];
     }
 }
+
+rust_fallback_impl! {
+    impl Sqrt for f32x8 where "__undefined" {
+        sqrt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
+
+rust_fallback_impl! {
+    impl Sqrt for f64x4 where "__undefined" {
+        sqrt => __undefined(), [0, 1, 2, 3];
+    }
+}
+
+rust_fallback_impl! {
+    impl Sqrt for f32x16 where "__undefined" {
+        sqrt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl! {
+    impl Sqrt for f64x8 where "__undefined" {
+        sqrt => __undefined(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
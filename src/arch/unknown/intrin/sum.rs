@@ -11,6 +11,10 @@ use crate::intrin::sum::{Sum,UpcastSum};
 
 impl_packed_sum!(u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2, f32x4, f64x2);
 impl_packed_upcast_sum!(u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2, f32x4, f64x2);
+impl_packed_sum!(u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4, f32x8, f64x4);
+impl_packed_upcast_sum!(u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4, f32x8, f64x4);
+impl_packed_sum!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8, f32x16, f64x8);
+impl_packed_upcast_sum!(u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8, f32x16, f64x8);
 
 mod tests {
     #![allow(unused_imports)]
@@ -26,7 +30,27 @@ mod tests {
     test_packed_sum_int!(i32x4, i32, test_packed_sum_i32x4);
     test_packed_sum_int!(u64x2, u64, test_packed_sum_u64x2);
     test_packed_sum_int!(i64x2, i64, test_packed_sum_i64x2);
+    test_packed_sum_int!(u8x32, u8, test_packed_sum_u8x32);
+    test_packed_sum_int!(i8x32, i8, test_packed_sum_i8x32);
+    test_packed_sum_int!(u16x16, u16, test_packed_sum_u16x16);
+    test_packed_sum_int!(i16x16, i16, test_packed_sum_i16x16);
+    test_packed_sum_int!(u32x8, u32, test_packed_sum_u32x8);
+    test_packed_sum_int!(i32x8, i32, test_packed_sum_i32x8);
+    test_packed_sum_int!(u64x4, u64, test_packed_sum_u64x4);
+    test_packed_sum_int!(i64x4, i64, test_packed_sum_i64x4);
+    test_packed_sum_int!(u8x64, u8, test_packed_sum_u8x64);
+    test_packed_sum_int!(i8x64, i8, test_packed_sum_i8x64);
+    test_packed_sum_int!(u16x32, u16, test_packed_sum_u16x32);
+    test_packed_sum_int!(i16x32, i16, test_packed_sum_i16x32);
+    test_packed_sum_int!(u32x16, u32, test_packed_sum_u32x16);
+    test_packed_sum_int!(i32x16, i32, test_packed_sum_i32x16);
+    test_packed_sum_int!(u64x8, u64, test_packed_sum_u64x8);
+    test_packed_sum_int!(i64x8, i64, test_packed_sum_i64x8);
 
     test_packed_sum!(f32x4, f32, test_packed_sum_f32x4);
     test_packed_sum!(f64x2, f64, test_packed_sum_f64x2);
+    test_packed_sum!(f32x8, f32, test_packed_sum_f32x8);
+    test_packed_sum!(f64x4, f64, test_packed_sum_f64x4);
+    test_packed_sum!(f32x16, f32, test_packed_sum_f32x16);
+    test_packed_sum!(f64x8, f64, test_packed_sum_f64x8);
 }
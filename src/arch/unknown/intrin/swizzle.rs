@@ -0,0 +1,99 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::arch::current::vecs::*;
+use crate::intrin::swizzle::*;
+use crate::vecs::*;
+
+// Will produce fallback implementations only, so we get away with __undefined.
+impl_packed_swizzle!(u8x16, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(i8x16, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(u16x8, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(i16x8, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(u32x4, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3));
+impl_packed_swizzle!(i32x4, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3));
+impl_packed_swizzle!(f32x4, u8x16, "__undefined", __undefined, (), (0, 1, 2, 3));
+impl_packed_swizzle!(u64x2, u8x16, "__undefined", __undefined, (), (0, 1));
+impl_packed_swizzle!(i64x2, u8x16, "__undefined", __undefined, (), (0, 1));
+impl_packed_swizzle!(f64x2, u8x16, "__undefined", __undefined, (), (0, 1));
+
+impl_packed_swizzle!(u8x32, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_packed_swizzle!(i8x32, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_packed_swizzle!(u16x16, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(i16x16, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(u32x8, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(i32x8, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(f32x8, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(u64x4, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3));
+impl_packed_swizzle!(i64x4, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3));
+impl_packed_swizzle!(f64x4, u8x32, "__undefined", __undefined, (), (0, 1, 2, 3));
+
+impl_packed_swizzle!(u8x64, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63));
+impl_packed_swizzle!(i8x64, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63));
+impl_packed_swizzle!(u16x32, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_packed_swizzle!(i16x32, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31));
+impl_packed_swizzle!(u32x16, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(i32x16, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(f32x16, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+impl_packed_swizzle!(u64x8, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(i64x8, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+impl_packed_swizzle!(f64x8, u8x64, "__undefined", __undefined, (), (0, 1, 2, 3, 4, 5, 6, 7));
+
+#[doc(hidden)]
+#[macro_export] macro_rules! __shuffle_impl {
+    ($vec:expr, [$($idx:expr),+]) => ({
+        struct Indices;
+        impl $crate::intrin::swizzle::ShuffleIndices for Indices {
+            const INDICES: &'static [u32] = &[$($idx),+];
+        }
+        $crate::intrin::swizzle::shuffle_polyfill::<_, Indices>($vec)
+    })
+}
+
+impl_packed_shuffle!(u8x16, u8x16, u8, "__undefined", __undefined);
+impl_packed_shuffle!(i8x16, u8x16, u8, "__undefined", __undefined);
+impl_packed_shuffle!(u16x8, u16x8, u16, "__undefined", __undefined);
+impl_packed_shuffle!(i16x8, u16x8, u16, "__undefined", __undefined);
+impl_packed_shuffle!(u32x4, u32x4, u32, "__undefined", __undefined);
+impl_packed_shuffle!(i32x4, u32x4, u32, "__undefined", __undefined);
+impl_packed_shuffle!(f32x4, u32x4, u32, "__undefined", __undefined);
+impl_packed_shuffle!(u64x2, u64x2, u64, "__undefined", __undefined);
+impl_packed_shuffle!(i64x2, u64x2, u64, "__undefined", __undefined);
+impl_packed_shuffle!(f64x2, u64x2, u64, "__undefined", __undefined);
+
+impl_packed_shuffle!(u8x32, u8x32, u8, "__undefined", __undefined);
+impl_packed_shuffle!(i8x32, u8x32, u8, "__undefined", __undefined);
+impl_packed_shuffle!(u16x16, u16x16, u16, "__undefined", __undefined);
+impl_packed_shuffle!(i16x16, u16x16, u16, "__undefined", __undefined);
+impl_packed_shuffle!(u32x8, u32x8, u32, "__undefined", __undefined);
+impl_packed_shuffle!(i32x8, u32x8, u32, "__undefined", __undefined);
+impl_packed_shuffle!(f32x8, u32x8, u32, "__undefined", __undefined);
+impl_packed_shuffle!(u64x4, u64x4, u64, "__undefined", __undefined);
+impl_packed_shuffle!(i64x4, u64x4, u64, "__undefined", __undefined);
+impl_packed_shuffle!(f64x4, u64x4, u64, "__undefined", __undefined);
+
+impl_packed_shuffle!(u8x64, u8x64, u8, "__undefined", __undefined);
+impl_packed_shuffle!(i8x64, u8x64, u8, "__undefined", __undefined);
+impl_packed_shuffle!(u16x32, u16x32, u16, "__undefined", __undefined);
+impl_packed_shuffle!(i16x32, u16x32, u16, "__undefined", __undefined);
+impl_packed_shuffle!(u32x16, u32x16, u32, "__undefined", __undefined);
+impl_packed_shuffle!(i32x16, u32x16, u32, "__undefined", __undefined);
+impl_packed_shuffle!(f32x16, u32x16, u32, "__undefined", __undefined);
+impl_packed_shuffle!(u64x8, u64x8, u64, "__undefined", __undefined);
+impl_packed_shuffle!(i64x8, u64x8, u64, "__undefined", __undefined);
+impl_packed_shuffle!(f64x8, u64x8, u64, "__undefined", __undefined);
+
+mod tests {
+    #![allow(unused_imports)]
+
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_packed_shuffle!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (shuffle_u8x16, shuffle_i8x16, shuffle_u16x8, shuffle_i16x8, shuffle_u32x4, shuffle_i32x4, shuffle_f32x4, shuffle_u64x2, shuffle_i64x2, shuffle_f64x2, shuffle_u8x32, shuffle_i8x32, shuffle_u16x16, shuffle_i16x16, shuffle_u32x8, shuffle_i32x8, shuffle_f32x8, shuffle_u64x4, shuffle_i64x4, shuffle_f64x4, shuffle_u8x64, shuffle_i8x64, shuffle_u16x32, shuffle_i16x32, shuffle_u32x16, shuffle_i32x16, shuffle_f32x16, shuffle_u64x8, shuffle_i64x8, shuffle_f64x8));
+}
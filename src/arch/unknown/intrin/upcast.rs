@@ -384,3 +384,219 @@ impl Upcast<u64x8> for u32x16 {
                     self.extract(15) as u64))
     }
 }
+
+impl Upcast<u16x32> for u8x64 {
+    #[inline(always)]
+    fn upcast(self) -> (u16x32, u16x32) {
+        (u16x32::new(self.extract(0) as u16,
+                     self.extract(1) as u16,
+                     self.extract(2) as u16,
+                     self.extract(3) as u16,
+                     self.extract(4) as u16,
+                     self.extract(5) as u16,
+                     self.extract(6) as u16,
+                     self.extract(7) as u16,
+                     self.extract(8) as u16,
+                     self.extract(9) as u16,
+                     self.extract(10) as u16,
+                     self.extract(11) as u16,
+                     self.extract(12) as u16,
+                     self.extract(13) as u16,
+                     self.extract(14) as u16,
+                     self.extract(15) as u16,
+                     self.extract(16) as u16,
+                     self.extract(17) as u16,
+                     self.extract(18) as u16,
+                     self.extract(19) as u16,
+                     self.extract(20) as u16,
+                     self.extract(21) as u16,
+                     self.extract(22) as u16,
+                     self.extract(23) as u16,
+                     self.extract(24) as u16,
+                     self.extract(25) as u16,
+                     self.extract(26) as u16,
+                     self.extract(27) as u16,
+                     self.extract(28) as u16,
+                     self.extract(29) as u16,
+                     self.extract(30) as u16,
+                     self.extract(31) as u16),
+         u16x32::new(self.extract(32) as u16,
+                     self.extract(33) as u16,
+                     self.extract(34) as u16,
+                     self.extract(35) as u16,
+                     self.extract(36) as u16,
+                     self.extract(37) as u16,
+                     self.extract(38) as u16,
+                     self.extract(39) as u16,
+                     self.extract(40) as u16,
+                     self.extract(41) as u16,
+                     self.extract(42) as u16,
+                     self.extract(43) as u16,
+                     self.extract(44) as u16,
+                     self.extract(45) as u16,
+                     self.extract(46) as u16,
+                     self.extract(47) as u16,
+                     self.extract(48) as u16,
+                     self.extract(49) as u16,
+                     self.extract(50) as u16,
+                     self.extract(51) as u16,
+                     self.extract(52) as u16,
+                     self.extract(53) as u16,
+                     self.extract(54) as u16,
+                     self.extract(55) as u16,
+                     self.extract(56) as u16,
+                     self.extract(57) as u16,
+                     self.extract(58) as u16,
+                     self.extract(59) as u16,
+                     self.extract(60) as u16,
+                     self.extract(61) as u16,
+                     self.extract(62) as u16,
+                     self.extract(63) as u16))
+    }
+}
+
+impl Upcast<i16x32> for i8x64 {
+    #[inline(always)]
+    fn upcast(self) -> (i16x32, i16x32) {
+        (i16x32::new(self.extract(0) as i16,
+                     self.extract(1) as i16,
+                     self.extract(2) as i16,
+                     self.extract(3) as i16,
+                     self.extract(4) as i16,
+                     self.extract(5) as i16,
+                     self.extract(6) as i16,
+                     self.extract(7) as i16,
+                     self.extract(8) as i16,
+                     self.extract(9) as i16,
+                     self.extract(10) as i16,
+                     self.extract(11) as i16,
+                     self.extract(12) as i16,
+                     self.extract(13) as i16,
+                     self.extract(14) as i16,
+                     self.extract(15) as i16,
+                     self.extract(16) as i16,
+                     self.extract(17) as i16,
+                     self.extract(18) as i16,
+                     self.extract(19) as i16,
+                     self.extract(20) as i16,
+                     self.extract(21) as i16,
+                     self.extract(22) as i16,
+                     self.extract(23) as i16,
+                     self.extract(24) as i16,
+                     self.extract(25) as i16,
+                     self.extract(26) as i16,
+                     self.extract(27) as i16,
+                     self.extract(28) as i16,
+                     self.extract(29) as i16,
+                     self.extract(30) as i16,
+                     self.extract(31) as i16),
+         i16x32::new(self.extract(32) as i16,
+                     self.extract(33) as i16,
+                     self.extract(34) as i16,
+                     self.extract(35) as i16,
+                     self.extract(36) as i16,
+                     self.extract(37) as i16,
+                     self.extract(38) as i16,
+                     self.extract(39) as i16,
+                     self.extract(40) as i16,
+                     self.extract(41) as i16,
+                     self.extract(42) as i16,
+                     self.extract(43) as i16,
+                     self.extract(44) as i16,
+                     self.extract(45) as i16,
+                     self.extract(46) as i16,
+                     self.extract(47) as i16,
+                     self.extract(48) as i16,
+                     self.extract(49) as i16,
+                     self.extract(50) as i16,
+                     self.extract(51) as i16,
+                     self.extract(52) as i16,
+                     self.extract(53) as i16,
+                     self.extract(54) as i16,
+                     self.extract(55) as i16,
+                     self.extract(56) as i16,
+                     self.extract(57) as i16,
+                     self.extract(58) as i16,
+                     self.extract(59) as i16,
+                     self.extract(60) as i16,
+                     self.extract(61) as i16,
+                     self.extract(62) as i16,
+                     self.extract(63) as i16))
+    }
+}
+
+impl Upcast<u32x16> for u16x32 {
+    #[inline(always)]
+    fn upcast(self) -> (u32x16, u32x16) {
+        (u32x16::new(self.extract(0) as u32,
+                     self.extract(1) as u32,
+                     self.extract(2) as u32,
+                     self.extract(3) as u32,
+                     self.extract(4) as u32,
+                     self.extract(5) as u32,
+                     self.extract(6) as u32,
+                     self.extract(7) as u32,
+                     self.extract(8) as u32,
+                     self.extract(9) as u32,
+                     self.extract(10) as u32,
+                     self.extract(11) as u32,
+                     self.extract(12) as u32,
+                     self.extract(13) as u32,
+                     self.extract(14) as u32,
+                     self.extract(15) as u32),
+         u32x16::new(self.extract(16) as u32,
+                     self.extract(17) as u32,
+                     self.extract(18) as u32,
+                     self.extract(19) as u32,
+                     self.extract(20) as u32,
+                     self.extract(21) as u32,
+                     self.extract(22) as u32,
+                     self.extract(23) as u32,
+                     self.extract(24) as u32,
+                     self.extract(25) as u32,
+                     self.extract(26) as u32,
+                     self.extract(27) as u32,
+                     self.extract(28) as u32,
+                     self.extract(29) as u32,
+                     self.extract(30) as u32,
+                     self.extract(31) as u32))
+    }
+}
+
+impl Upcast<i32x16> for i16x32 {
+    #[inline(always)]
+    fn upcast(self) -> (i32x16, i32x16) {
+        (i32x16::new(self.extract(0) as i32,
+                     self.extract(1) as i32,
+                     self.extract(2) as i32,
+                     self.extract(3) as i32,
+                     self.extract(4) as i32,
+                     self.extract(5) as i32,
+                     self.extract(6) as i32,
+                     self.extract(7) as i32,
+                     self.extract(8) as i32,
+                     self.extract(9) as i32,
+                     self.extract(10) as i32,
+                     self.extract(11) as i32,
+                     self.extract(12) as i32,
+                     self.extract(13) as i32,
+                     self.extract(14) as i32,
+                     self.extract(15) as i32),
+         i32x16::new(self.extract(16) as i32,
+                     self.extract(17) as i32,
+                     self.extract(18) as i32,
+                     self.extract(19) as i32,
+                     self.extract(20) as i32,
+                     self.extract(21) as i32,
+                     self.extract(22) as i32,
+                     self.extract(23) as i32,
+                     self.extract(24) as i32,
+                     self.extract(25) as i32,
+                     self.extract(26) as i32,
+                     self.extract(27) as i32,
+                     self.extract(28) as i32,
+                     self.extract(29) as i32,
+                     self.extract(30) as i32,
+                     self.extract(31) as i32))
+    }
+}
@@ -0,0 +1,124 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Mask types for machines without SIMD support.
+
+#![allow(unused_imports)]
+
+use crate::core::ops::{BitAnd, BitOr, BitXor, Not};
+use crate::arch::current::vecs::*;
+use crate::masks::*;
+use crate::vecs::*;
+
+#[inline(always)]
+fn bitmask_m8x16(vec: u8x16) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m16x8(vec: u16x8) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m32x4(vec: u32x4) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m64x2(vec: u64x2) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m8x32(vec: u8x32) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m8x64(vec: u8x64) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m16x16(vec: u16x16) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m16x32(vec: u16x32) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m32x8(vec: u32x8) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m32x16(vec: u32x16) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m64x4(vec: u64x4) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m64x8(vec: u64x8) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+impl_mask!(m8x16, u8x16, u8, 16, bitmask_m8x16, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15]);
+impl_mask!(m16x8, u16x8, u16, 8, bitmask_m16x8, [x0, x1, x2, x3, x4, x5, x6, x7]);
+impl_mask!(m32x4, u32x4, u32, 4, bitmask_m32x4, [x0, x1, x2, x3]);
+impl_mask!(m64x2, u64x2, u64, 2, bitmask_m64x2, [x0, x1]);
+impl_mask!(m8x32, u8x32, u8, 32, bitmask_m8x32, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31]);
+impl_mask!(m8x64, u8x64, u8, 64, bitmask_m8x64, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31, x32, x33, x34, x35, x36, x37, x38, x39, x40, x41, x42, x43, x44, x45, x46, x47, x48, x49, x50, x51, x52, x53, x54, x55, x56, x57, x58, x59, x60, x61, x62, x63]);
+impl_mask!(m16x16, u16x16, u16, 16, bitmask_m16x16, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15]);
+impl_mask!(m16x32, u16x32, u16, 32, bitmask_m16x32, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31]);
+impl_mask!(m32x8, u32x8, u32, 8, bitmask_m32x8, [x0, x1, x2, x3, x4, x5, x6, x7]);
+impl_mask!(m32x16, u32x16, u32, 16, bitmask_m32x16, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15]);
+impl_mask!(m64x4, u64x4, u64, 4, bitmask_m64x4, [x0, x1, x2, x3]);
+impl_mask!(m64x8, u64x8, u64, 8, bitmask_m64x8, [x0, x1, x2, x3, x4, x5, x6, x7]);
+
+impl_mask_alias!(m8s, m8x16, [], ["undefined"]);
+impl_mask_alias!(m16s, m16x8, [], ["undefined"]);
+impl_mask_alias!(m32s, m32x4, [], ["undefined"]);
+impl_mask_alias!(m64s, m64x2, [], ["undefined"]);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    test_mask!(m8x16, u8x16, u8, test_mask_m8x16);
+    test_mask!(m16x8, u16x8, u16, test_mask_m16x8);
+    test_mask!(m32x4, u32x4, u32, test_mask_m32x4);
+    test_mask!(m64x2, u64x2, u64, test_mask_m64x2);
+    test_mask!(m8x32, u8x32, u8, test_mask_m8x32);
+    test_mask!(m8x64, u8x64, u8, test_mask_m8x64);
+    test_mask!(m16x16, u16x16, u16, test_mask_m16x16);
+    test_mask!(m16x32, u16x32, u16, test_mask_m16x32);
+    test_mask!(m32x8, u32x8, u32, test_mask_m32x8);
+    test_mask!(m32x16, u32x16, u32, test_mask_m32x16);
+    test_mask!(m64x4, u64x4, u64, test_mask_m64x4);
+    test_mask!(m64x8, u64x8, u64, test_mask_m64x8);
+}
@@ -34,7 +34,237 @@ const PART_MASK: [u8; 128] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                               0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
                               0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
 
+impl Pattern for u8x64 {
+    type Array = [u8; 64];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            17 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            18 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            19 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            20 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            21 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            22 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            23 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            24 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            25 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            26 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            27 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            28 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            29 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            30 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            31 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            32 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            33 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            34 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            35 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            36 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            37 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            38 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            39 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            40 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            41 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            42 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            43 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            44 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            45 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            46 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            47 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            48 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            49 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            50 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            51 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            52 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            53 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            54 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            55 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            56 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            57 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            58 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            59 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            60 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            61 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            62 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            63 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            64 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFu8) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00u8) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)), start.wrapping_add(step.wrapping_mul(32)), start.wrapping_add(step.wrapping_mul(33)), start.wrapping_add(step.wrapping_mul(34)), start.wrapping_add(step.wrapping_mul(35)), start.wrapping_add(step.wrapping_mul(36)), start.wrapping_add(step.wrapping_mul(37)), start.wrapping_add(step.wrapping_mul(38)), start.wrapping_add(step.wrapping_mul(39)), start.wrapping_add(step.wrapping_mul(40)), start.wrapping_add(step.wrapping_mul(41)), start.wrapping_add(step.wrapping_mul(42)), start.wrapping_add(step.wrapping_mul(43)), start.wrapping_add(step.wrapping_mul(44)), start.wrapping_add(step.wrapping_mul(45)), start.wrapping_add(step.wrapping_mul(46)), start.wrapping_add(step.wrapping_mul(47)), start.wrapping_add(step.wrapping_mul(48)), start.wrapping_add(step.wrapping_mul(49)), start.wrapping_add(step.wrapping_mul(50)), start.wrapping_add(step.wrapping_mul(51)), start.wrapping_add(step.wrapping_mul(52)), start.wrapping_add(step.wrapping_mul(53)), start.wrapping_add(step.wrapping_mul(54)), start.wrapping_add(step.wrapping_mul(55)), start.wrapping_add(step.wrapping_mul(56)), start.wrapping_add(step.wrapping_mul(57)), start.wrapping_add(step.wrapping_mul(58)), start.wrapping_add(step.wrapping_mul(59)), start.wrapping_add(step.wrapping_mul(60)), start.wrapping_add(step.wrapping_mul(61)), start.wrapping_add(step.wrapping_mul(62)), start.wrapping_add(step.wrapping_mul(63)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15), f(16), f(17), f(18), f(19), f(20), f(21), f(22), f(23), f(24), f(25), f(26), f(27), f(28), f(29), f(30), f(31), f(32), f(33), f(34), f(35), f(36), f(37), f(38), f(39), f(40), f(41), f(42), f(43), f(44), f(45), f(46), f(47), f(48), f(49), f(50), f(51), f(52), f(53), f(54), f(55), f(56), f(57), f(58), f(59), f(60), f(61), f(62), f(63))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15], data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23], data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31], data[32], data[33], data[34], data[35], data[36], data[37], data[38], data[39], data[40], data[41], data[42], data[43], data[44], data[45], data[46], data[47], data[48], data[49], data[50], data[51], data[52], data[53], data[54], data[55], data[56], data[57], data[58], data[59], data[60], data[61], data[62], data[63])
+    }
+}
+
+impl Pattern for u8x32 {
+    type Array = [u8; 32];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            17 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            18 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            19 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            20 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            21 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            22 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            23 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            24 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            25 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            26 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            27 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            28 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            29 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            30 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            31 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            32 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFu8) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00u8) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15), f(16), f(17), f(18), f(19), f(20), f(21), f(22), f(23), f(24), f(25), f(26), f(27), f(28), f(29), f(30), f(31))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15], data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23], data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31])
+    }
+}
+
 impl Pattern for u8x16 {
+    type Array = [u8; 16];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
         Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
@@ -97,17 +327,1530 @@ impl Pattern for u8x16 {
             fn zeroes() -> Self {
                 Self::splat(unsafe { transmute(0x00u8) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for i8x64 {
+    type Array = [i8; 64];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            17 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            18 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            19 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            20 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            21 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            22 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            23 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            24 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            25 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            26 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            27 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            28 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            29 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            30 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            31 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            32 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            33 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            34 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            35 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            36 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            37 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            38 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            39 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            40 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            41 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            42 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            43 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            44 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            45 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            46 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            47 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            48 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            49 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            50 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            51 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            52 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            53 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            54 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            55 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            56 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            57 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            58 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            59 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            60 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            61 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            62 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            63 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            64 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFu8) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00u8) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)), start.wrapping_add(step.wrapping_mul(32)), start.wrapping_add(step.wrapping_mul(33)), start.wrapping_add(step.wrapping_mul(34)), start.wrapping_add(step.wrapping_mul(35)), start.wrapping_add(step.wrapping_mul(36)), start.wrapping_add(step.wrapping_mul(37)), start.wrapping_add(step.wrapping_mul(38)), start.wrapping_add(step.wrapping_mul(39)), start.wrapping_add(step.wrapping_mul(40)), start.wrapping_add(step.wrapping_mul(41)), start.wrapping_add(step.wrapping_mul(42)), start.wrapping_add(step.wrapping_mul(43)), start.wrapping_add(step.wrapping_mul(44)), start.wrapping_add(step.wrapping_mul(45)), start.wrapping_add(step.wrapping_mul(46)), start.wrapping_add(step.wrapping_mul(47)), start.wrapping_add(step.wrapping_mul(48)), start.wrapping_add(step.wrapping_mul(49)), start.wrapping_add(step.wrapping_mul(50)), start.wrapping_add(step.wrapping_mul(51)), start.wrapping_add(step.wrapping_mul(52)), start.wrapping_add(step.wrapping_mul(53)), start.wrapping_add(step.wrapping_mul(54)), start.wrapping_add(step.wrapping_mul(55)), start.wrapping_add(step.wrapping_mul(56)), start.wrapping_add(step.wrapping_mul(57)), start.wrapping_add(step.wrapping_mul(58)), start.wrapping_add(step.wrapping_mul(59)), start.wrapping_add(step.wrapping_mul(60)), start.wrapping_add(step.wrapping_mul(61)), start.wrapping_add(step.wrapping_mul(62)), start.wrapping_add(step.wrapping_mul(63)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15), f(16), f(17), f(18), f(19), f(20), f(21), f(22), f(23), f(24), f(25), f(26), f(27), f(28), f(29), f(30), f(31), f(32), f(33), f(34), f(35), f(36), f(37), f(38), f(39), f(40), f(41), f(42), f(43), f(44), f(45), f(46), f(47), f(48), f(49), f(50), f(51), f(52), f(53), f(54), f(55), f(56), f(57), f(58), f(59), f(60), f(61), f(62), f(63))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15], data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23], data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31], data[32], data[33], data[34], data[35], data[36], data[37], data[38], data[39], data[40], data[41], data[42], data[43], data[44], data[45], data[46], data[47], data[48], data[49], data[50], data[51], data[52], data[53], data[54], data[55], data[56], data[57], data[58], data[59], data[60], data[61], data[62], data[63])
+    }
+}
+
+impl Pattern for i8x32 {
+    type Array = [i8; 32];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            17 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            18 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            19 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            20 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            21 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            22 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            23 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            24 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            25 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            26 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            27 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            28 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            29 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            30 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            31 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            32 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFu8) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00u8) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15), f(16), f(17), f(18), f(19), f(20), f(21), f(22), f(23), f(24), f(25), f(26), f(27), f(28), f(29), f(30), f(31))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15], data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23], data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31])
+    }
+}
+
+impl Pattern for i8x16 {
+    type Array = [i8; 16];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFu8) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00u8) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for u16x32 {
+    type Array = [u16; 32];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            17 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            18 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            19 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            20 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            21 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            22 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            23 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            24 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            25 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            26 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            27 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            28 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            29 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            30 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            31 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            32 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFu16) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x0000u16) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15), f(16), f(17), f(18), f(19), f(20), f(21), f(22), f(23), f(24), f(25), f(26), f(27), f(28), f(29), f(30), f(31))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15], data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23], data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31])
+    }
+}
+
+impl Pattern for u16x16 {
+    type Array = [u16; 16];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFu16) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x0000u16) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for u16x8 {
+    type Array = [u16; 8];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFu16) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x0000u16) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
+}
+
+impl Pattern for i16x32 {
+    type Array = [i16; 32];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            17 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            18 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            19 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            20 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            21 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            22 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            23 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            24 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            25 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            26 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            27 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            28 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            29 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            30 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            31 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            32 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFu16) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x0000u16) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)), start.wrapping_add(step.wrapping_mul(16)), start.wrapping_add(step.wrapping_mul(17)), start.wrapping_add(step.wrapping_mul(18)), start.wrapping_add(step.wrapping_mul(19)), start.wrapping_add(step.wrapping_mul(20)), start.wrapping_add(step.wrapping_mul(21)), start.wrapping_add(step.wrapping_mul(22)), start.wrapping_add(step.wrapping_mul(23)), start.wrapping_add(step.wrapping_mul(24)), start.wrapping_add(step.wrapping_mul(25)), start.wrapping_add(step.wrapping_mul(26)), start.wrapping_add(step.wrapping_mul(27)), start.wrapping_add(step.wrapping_mul(28)), start.wrapping_add(step.wrapping_mul(29)), start.wrapping_add(step.wrapping_mul(30)), start.wrapping_add(step.wrapping_mul(31)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15), f(16), f(17), f(18), f(19), f(20), f(21), f(22), f(23), f(24), f(25), f(26), f(27), f(28), f(29), f(30), f(31))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15], data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23], data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31])
+    }
+}
+
+impl Pattern for i16x16 {
+    type Array = [i16; 16];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFu16) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x0000u16) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for i16x8 {
+    type Array = [i16; 8];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFu16) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x0000u16) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
+}
+
+impl Pattern for u32x16 {
+    type Array = [u32; 16];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for u32x8 {
+    type Array = [u32; 8];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
+}
+
+impl Pattern for u32x4 {
+    type Array = [u32; 4];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo),
+            3 => Self::new(hi, hi, hi, lo),
+            4 => Self::new(hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3])
+    }
+}
+
+impl Pattern for i32x16 {
+    type Array = [i32; 16];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)), start.wrapping_add(step.wrapping_mul(8)), start.wrapping_add(step.wrapping_mul(9)), start.wrapping_add(step.wrapping_mul(10)), start.wrapping_add(step.wrapping_mul(11)), start.wrapping_add(step.wrapping_mul(12)), start.wrapping_add(step.wrapping_mul(13)), start.wrapping_add(step.wrapping_mul(14)), start.wrapping_add(step.wrapping_mul(15)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for i32x8 {
+    type Array = [i32; 8];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
+}
+
+impl Pattern for i32x4 {
+    type Array = [i32; 4];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo),
+            3 => Self::new(hi, hi, hi, lo),
+            4 => Self::new(hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3])
+    }
+}
+
+impl Pattern for f32x16 {
+    type Array = [f32; 16];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
+            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
+            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
+            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
+            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
+            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
+            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
+            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
+            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step, start + step * 2.0, start + step * 3.0, start + step * 4.0, start + step * 5.0, start + step * 6.0, start + step * 7.0, start + step * 8.0, start + step * 9.0, start + step * 10.0, start + step * 11.0, start + step * 12.0, start + step * 13.0, start + step * 14.0, start + step * 15.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7), f(8), f(9), f(10), f(11), f(12), f(13), f(14), f(15))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7], data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15])
+    }
+}
+
+impl Pattern for f32x8 {
+    type Array = [f32; 8];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step, start + step * 2.0, start + step * 3.0, start + step * 4.0, start + step * 5.0, start + step * 6.0, start + step * 7.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
+}
+
+impl Pattern for f32x4 {
+    type Array = [f32; 4];
+
+    #[inline(always)]
+    fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, hi, lo, lo)
+    }
+
+    #[inline(always)]
+    fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
+        Self::new(hi, lo, hi, lo)
+    }
+
+            #[inline(always)]
+            fn partition_mask(off: usize) -> Self {
+                debug_assert!(off <= Self::WIDTH);
+                debug_assert!(off * Self::Scalar::SIZE <= 64);
+                Self::load(unsafe { transmute(&PART_MASK[..]) }, 64 / Self::Scalar::SIZE - off)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = "__undefined")]
+            fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+                optimized!();
+                unsafe { transmute(__undefined(transmute(Self::splat(hi)), transmute(Self::splat(lo)), transmute(Self::partition_mask(off)))) }
+            }
+            
+    #[inline(always)]
+    #[cfg(not(target_feature = "__undefined"))]
+    fn partition(hi: Self::Scalar, lo: Self::Scalar, off: usize) -> Self {
+        assert!(off <= Self::WIDTH);
+        fallback!();
+        match off {
+            0 => Self::new(lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo),
+            3 => Self::new(hi, hi, hi, lo),
+            4 => Self::new(hi, hi, hi, hi),
+            _ => unreachable!()
+        }
+    }
+
+            /// Return a vector made entirely of ones.
+            #[inline(always)]
+            fn ones() -> Self {
+                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+            }
+
+            /// Return a vector made entirely of zeroes.
+            #[inline(always)]
+            fn zeroes() -> Self {
+                Self::splat(unsafe { transmute(0x00000000u32) })
+            }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step, start + step * 2.0, start + step * 3.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3])
+    }
 }
 
-impl Pattern for i8x16 {
+impl Pattern for u64x8 {
+    type Array = [u64; 8];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo)
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo, hi, lo)
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
             #[inline(always)]
@@ -130,23 +1873,15 @@ impl Pattern for i8x16 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo, lo),
-            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo, lo),
-            9 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo, lo),
-            10 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo, lo),
-            11 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo, lo),
-            12 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo, lo),
-            13 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo, lo),
-            14 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo, lo),
-            15 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, lo),
-            16 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi, hi),
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
             _ => unreachable!()
         }
     }
@@ -154,25 +1889,42 @@ impl Pattern for i8x16 {
             /// Return a vector made entirely of ones.
             #[inline(always)]
             fn ones() -> Self {
-                Self::splat(unsafe { transmute(0xFFu8) })
+                Self::splat(unsafe { transmute(0xFFFFFFFFFFFFFFFFu64) })
             }
 
             /// Return a vector made entirely of zeroes.
             #[inline(always)]
             fn zeroes() -> Self {
-                Self::splat(unsafe { transmute(0x00u8) })
+                Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
 }
 
-impl Pattern for u16x8 {
+impl Pattern for u64x4 {
+    type Array = [u64; 4];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+        Self::new(hi, hi, lo, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+        Self::new(hi, lo, hi, lo)
     }
 
             #[inline(always)]
@@ -195,15 +1947,11 @@ impl Pattern for u16x8 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
-            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
-            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
-            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
-            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
-            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
-            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
-            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
-            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            0 => Self::new(lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo),
+            3 => Self::new(hi, hi, hi, lo),
+            4 => Self::new(hi, hi, hi, hi),
             _ => unreachable!()
         }
     }
@@ -211,25 +1959,42 @@ impl Pattern for u16x8 {
             /// Return a vector made entirely of ones.
             #[inline(always)]
             fn ones() -> Self {
-                Self::splat(unsafe { transmute(0xFFFFu16) })
+                Self::splat(unsafe { transmute(0xFFFFFFFFFFFFFFFFu64) })
             }
 
             /// Return a vector made entirely of zeroes.
             #[inline(always)]
             fn zeroes() -> Self {
-                Self::splat(unsafe { transmute(0x0000u16) })
+                Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3])
+    }
 }
 
-impl Pattern for i16x8 {
+impl Pattern for u64x2 {
+    type Array = [u64; 2];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
+        Self::new(hi, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
+        Self::new(hi, lo)
     }
 
             #[inline(always)]
@@ -252,15 +2017,9 @@ impl Pattern for i16x8 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
-            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
-            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
-            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
-            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
-            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
-            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
-            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
-            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
+            0 => Self::new(lo, lo),
+            1 => Self::new(hi, lo),
+            2 => Self::new(hi, hi),
             _ => unreachable!()
         }
     }
@@ -268,25 +2027,42 @@ impl Pattern for i16x8 {
             /// Return a vector made entirely of ones.
             #[inline(always)]
             fn ones() -> Self {
-                Self::splat(unsafe { transmute(0xFFFFu16) })
+                Self::splat(unsafe { transmute(0xFFFFFFFFFFFFFFFFu64) })
             }
 
             /// Return a vector made entirely of zeroes.
             #[inline(always)]
             fn zeroes() -> Self {
-                Self::splat(unsafe { transmute(0x0000u16) })
+                Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1])
+    }
 }
 
-impl Pattern for u32x4 {
+impl Pattern for i64x8 {
+    type Array = [i64; 8];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, hi, lo, lo)
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo, hi, lo)
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
             #[inline(always)]
@@ -309,11 +2085,15 @@ impl Pattern for u32x4 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo, lo, lo),
-            1 => Self::new(hi, lo, lo, lo),
-            2 => Self::new(hi, hi, lo, lo),
-            3 => Self::new(hi, hi, hi, lo),
-            4 => Self::new(hi, hi, hi, hi),
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
             _ => unreachable!()
         }
     }
@@ -321,17 +2101,34 @@ impl Pattern for u32x4 {
             /// Return a vector made entirely of ones.
             #[inline(always)]
             fn ones() -> Self {
-                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+                Self::splat(unsafe { transmute(0xFFFFFFFFFFFFFFFFu64) })
             }
 
             /// Return a vector made entirely of zeroes.
             #[inline(always)]
             fn zeroes() -> Self {
-                Self::splat(unsafe { transmute(0x00000000u32) })
+                Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)), start.wrapping_add(step.wrapping_mul(4)), start.wrapping_add(step.wrapping_mul(5)), start.wrapping_add(step.wrapping_mul(6)), start.wrapping_add(step.wrapping_mul(7)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
 }
 
-impl Pattern for i32x4 {
+impl Pattern for i64x4 {
+    type Array = [i64; 4];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
         Self::new(hi, hi, lo, lo)
@@ -374,25 +2171,42 @@ impl Pattern for i32x4 {
             /// Return a vector made entirely of ones.
             #[inline(always)]
             fn ones() -> Self {
-                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+                Self::splat(unsafe { transmute(0xFFFFFFFFFFFFFFFFu64) })
             }
 
             /// Return a vector made entirely of zeroes.
             #[inline(always)]
             fn zeroes() -> Self {
-                Self::splat(unsafe { transmute(0x00000000u32) })
+                Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step), start.wrapping_add(step.wrapping_mul(2)), start.wrapping_add(step.wrapping_mul(3)))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3])
+    }
 }
 
-impl Pattern for f32x4 {
+impl Pattern for i64x2 {
+    type Array = [i64; 2];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, hi, lo, lo)
+        Self::new(hi, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo, hi, lo)
+        Self::new(hi, lo)
     }
 
             #[inline(always)]
@@ -415,11 +2229,9 @@ impl Pattern for f32x4 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo, lo, lo),
-            1 => Self::new(hi, lo, lo, lo),
-            2 => Self::new(hi, hi, lo, lo),
-            3 => Self::new(hi, hi, hi, lo),
-            4 => Self::new(hi, hi, hi, hi),
+            0 => Self::new(lo, lo),
+            1 => Self::new(hi, lo),
+            2 => Self::new(hi, hi),
             _ => unreachable!()
         }
     }
@@ -427,25 +2239,42 @@ impl Pattern for f32x4 {
             /// Return a vector made entirely of ones.
             #[inline(always)]
             fn ones() -> Self {
-                Self::splat(unsafe { transmute(0xFFFFFFFFu32) })
+                Self::splat(unsafe { transmute(0xFFFFFFFFFFFFFFFFu64) })
             }
 
             /// Return a vector made entirely of zeroes.
             #[inline(always)]
             fn zeroes() -> Self {
-                Self::splat(unsafe { transmute(0x00000000u32) })
+                Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start.wrapping_add(step))
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1])
+    }
 }
 
-impl Pattern for u64x2 {
+impl Pattern for f64x8 {
+    type Array = [f64; 8];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo)
+        Self::new(hi, hi, hi, hi, lo, lo, lo, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo)
+        Self::new(hi, lo, hi, lo, hi, lo, hi, lo)
     }
 
             #[inline(always)]
@@ -468,9 +2297,15 @@ impl Pattern for u64x2 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo),
-            1 => Self::new(hi, lo),
-            2 => Self::new(hi, hi),
+            0 => Self::new(lo, lo, lo, lo, lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo, lo, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo, lo, lo, lo, lo),
+            3 => Self::new(hi, hi, hi, lo, lo, lo, lo, lo),
+            4 => Self::new(hi, hi, hi, hi, lo, lo, lo, lo),
+            5 => Self::new(hi, hi, hi, hi, hi, lo, lo, lo),
+            6 => Self::new(hi, hi, hi, hi, hi, hi, lo, lo),
+            7 => Self::new(hi, hi, hi, hi, hi, hi, hi, lo),
+            8 => Self::new(hi, hi, hi, hi, hi, hi, hi, hi),
             _ => unreachable!()
         }
     }
@@ -486,17 +2321,34 @@ impl Pattern for u64x2 {
             fn zeroes() -> Self {
                 Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step, start + step * 2.0, start + step * 3.0, start + step * 4.0, start + step * 5.0, start + step * 6.0, start + step * 7.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3), f(4), f(5), f(6), f(7))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7])
+    }
 }
 
-impl Pattern for i64x2 {
+impl Pattern for f64x4 {
+    type Array = [f64; 4];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo)
+        Self::new(hi, hi, lo, lo)
     }
 
     #[inline(always)]
     fn interleave(hi: Self::Scalar, lo: Self::Scalar) -> Self {
-        Self::new(hi, lo)
+        Self::new(hi, lo, hi, lo)
     }
 
             #[inline(always)]
@@ -519,9 +2371,11 @@ impl Pattern for i64x2 {
         assert!(off <= Self::WIDTH);
         fallback!();
         match off {
-            0 => Self::new(lo, lo),
-            1 => Self::new(hi, lo),
-            2 => Self::new(hi, hi),
+            0 => Self::new(lo, lo, lo, lo),
+            1 => Self::new(hi, lo, lo, lo),
+            2 => Self::new(hi, hi, lo, lo),
+            3 => Self::new(hi, hi, hi, lo),
+            4 => Self::new(hi, hi, hi, hi),
             _ => unreachable!()
         }
     }
@@ -537,9 +2391,26 @@ impl Pattern for i64x2 {
             fn zeroes() -> Self {
                 Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step, start + step * 2.0, start + step * 3.0)
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1], data[2], data[3])
+    }
 }
 
 impl Pattern for f64x2 {
+    type Array = [f64; 2];
+
     #[inline(always)]
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self {
         Self::new(hi, lo)
@@ -588,5 +2459,20 @@ impl Pattern for f64x2 {
             fn zeroes() -> Self {
                 Self::splat(unsafe { transmute(0x0000000000000000u64) })
             }
+
+    #[inline(always)]
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self {
+        Self::new(start, start + step)
+    }
+
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(mut f: F) -> Self {
+        Self::new(f(0), f(1))
+    }
+
+    #[inline(always)]
+    fn from_array(data: Self::Array) -> Self {
+        Self::new(data[0], data[1])
+    }
 }
 
@@ -162,6 +162,38 @@ macro_rules! impl_packed_type {
                     size_of::<$vec>());
                 x
             }
+
+            // The names packed_simd uses, which `impl_packed` relies upon
+
+            #[inline(always)]
+            pub fn from_slice_unaligned(slice: &[$el]) -> $vec {
+                $vec::load_unaligned(slice)
+            }
+
+            #[inline(always)]
+            pub unsafe fn from_slice_unaligned_unchecked(slice: &[$el]) -> $vec {
+                $vec::load_unaligned_unchecked(slice)
+            }
+
+            #[inline(always)]
+            pub unsafe fn from_slice_aligned_unchecked(slice: &[$el]) -> $vec {
+                $vec::load_aligned_unchecked(slice)
+            }
+
+            #[inline(always)]
+            pub fn write_to_slice_unaligned(self, slice: &mut [$el]) {
+                self.store_unaligned(slice)
+            }
+
+            #[inline(always)]
+            pub unsafe fn write_to_slice_unaligned_unchecked(self, slice: &mut [$el]) {
+                self.store_unaligned_unchecked(slice)
+            }
+
+            #[inline(always)]
+            pub unsafe fn write_to_slice_aligned_unchecked(self, slice: &mut [$el]) {
+                self.store_aligned_unchecked(slice)
+            }
         }
     }
 }
@@ -239,6 +271,28 @@ impl_packed!(u64, u64s, u64x2, 8, 2, [], ["undefined"]);
 impl_packed!(i64, i64s, i64x2, 8, 2, [], ["undefined"]);
 impl_packed!(f64, f64s, f64x2, 8, 2, [], ["undefined"]);
 
+// The wider types are never the native vectors, but may be used explicitly.
+impl_packed!(u8, u8s, u8x32, 1, 32, ["undefined"], ["undefined"]);
+impl_packed!(u8, u8s, u8x64, 1, 64, ["undefined"], ["undefined"]);
+impl_packed!(i8, i8s, i8x32, 1, 32, ["undefined"], ["undefined"]);
+impl_packed!(i8, i8s, i8x64, 1, 64, ["undefined"], ["undefined"]);
+impl_packed!(u16, u16s, u16x16, 2, 16, ["undefined"], ["undefined"]);
+impl_packed!(u16, u16s, u16x32, 2, 32, ["undefined"], ["undefined"]);
+impl_packed!(i16, i16s, i16x16, 2, 16, ["undefined"], ["undefined"]);
+impl_packed!(i16, i16s, i16x32, 2, 32, ["undefined"], ["undefined"]);
+impl_packed!(u32, u32s, u32x8, 4, 8, ["undefined"], ["undefined"]);
+impl_packed!(u32, u32s, u32x16, 4, 16, ["undefined"], ["undefined"]);
+impl_packed!(i32, i32s, i32x8, 4, 8, ["undefined"], ["undefined"]);
+impl_packed!(i32, i32s, i32x16, 4, 16, ["undefined"], ["undefined"]);
+impl_packed!(f32, f32s, f32x8, 4, 8, ["undefined"], ["undefined"]);
+impl_packed!(f32, f32s, f32x16, 4, 16, ["undefined"], ["undefined"]);
+impl_packed!(u64, u64s, u64x4, 8, 4, ["undefined"], ["undefined"]);
+impl_packed!(u64, u64s, u64x8, 8, 8, ["undefined"], ["undefined"]);
+impl_packed!(i64, i64s, i64x4, 8, 4, ["undefined"], ["undefined"]);
+impl_packed!(i64, i64s, i64x8, 8, 8, ["undefined"], ["undefined"]);
+impl_packed!(f64, f64s, f64x4, 8, 4, ["undefined"], ["undefined"]);
+impl_packed!(f64, f64s, f64x8, 8, 8, ["undefined"], ["undefined"]);
+
 impl_packed_type!(f64, f64s, f64x2, 2, [x0, x1]);
 impl_packed_type!(f64, f64s, f64x4, 4, [x0, x1, x2, x3]);
 impl_packed_type!(f64, f64s, f64x8, 8, [x0, x1, x2, x3, x4, x5, x6, x7]);
@@ -5,32 +5,14 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use crate::core::arch::x86_64::*;
+use crate::intrin::addsub::*;
 use crate::arch::current::vecs::*;
+use crate::vecs::*;
 
-// impl AddSub for f32x4 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm_addsub_ps(*self, other) }
-//     }
-// }
-
-// impl AddSub for f64x2 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm_addsub_pd(*self, other) }
-//     }
-// }
-
-// impl AddSub for f32x8 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm256_addsub_ps(*self, other) }
-//     }
-// }
-
-// impl AddSub for f64x4 {
-//     #[inline(always)]
-//     fn addsub(&self, other: Self) -> Self {
-//         unsafe { _mm256_addsub_pd(*self, other) }
-//     }
-// }
+impl_addsub!(f32x4, "sse3", _mm_addsub_ps, [0, 1, 2, 3]);
+impl_addsub!(f64x2, "sse3", _mm_addsub_pd, [0, 1]);
+impl_addsub!(f32x8, "avx", _mm256_addsub_ps, [0, 1, 2, 3, 4, 5, 6, 7]);
+impl_addsub!(f64x4, "avx", _mm256_addsub_pd, [0, 1, 2, 3]);
+impl_addsub!(f32x16, "avx512-butnotyet", __undefined, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+impl_addsub!(f64x8, "avx512-butnotyet", __undefined, [0, 1, 2, 3, 4, 5, 6, 7]);
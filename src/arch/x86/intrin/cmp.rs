@@ -7,6 +7,10 @@
 
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::core::arch::x86_64::{_mm512_min_epu8, _mm512_min_epi8, _mm512_min_epu16, _mm512_min_epi16};
+use crate::core::arch::x86_64::{_mm512_max_epu8, _mm512_max_epi8, _mm512_max_epu16, _mm512_max_epi16};
+use crate::core::arch::x86_64::{_mm512_min_epu32, _mm512_min_epi32, _mm512_min_ps, _mm512_min_pd};
+use crate::core::arch::x86_64::{_mm512_max_epu32, _mm512_max_epi32, _mm512_max_ps, _mm512_max_pd};
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
 use crate::intrin::cmp::*;
@@ -126,3 +130,75 @@ rust_fallback_impl_binary! {
         max => _mm256_max_pd(), [0, 1, 2, 3];
     }
 }
+
+rust_fallback_impl_binary! {
+    impl Cmp for u8x64 where "avx512bw" {
+        min => _mm512_min_epu8(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+                                   19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34,
+                                   35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+                                   51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+        max => _mm512_max_epu8(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+                                   19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34,
+                                   35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+                                   51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for i8x64 where "avx512bw" {
+        min => _mm512_min_epi8(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+                                   19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34,
+                                   35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+                                   51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+        max => _mm512_max_epi8(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+                                   19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34,
+                                   35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+                                   51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for u16x32 where "avx512bw" {
+        min => _mm512_min_epu16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                                    18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+        max => _mm512_max_epu16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                                    18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for i16x32 where "avx512bw" {
+        min => _mm512_min_epi16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                                    18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+        max => _mm512_max_epi16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                                    18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for u32x16 where "avx512f" {
+        min => _mm512_min_epu32(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        max => _mm512_max_epu32(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for i32x16 where "avx512f" {
+        min => _mm512_min_epi32(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        max => _mm512_max_epi32(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for f32x16 where "avx512f" {
+        min => _mm512_min_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        max => _mm512_max_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl Cmp for f64x8 where "avx512f" {
+        min => _mm512_min_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+        max => _mm512_max_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
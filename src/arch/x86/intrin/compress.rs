@@ -0,0 +1,645 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![allow(dead_code)]
+
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::arch::current::vecs::*;
+use crate::arch::current::masks::*;
+use crate::intrin::compress::*;
+use crate::intrin::eq::*;
+use crate::masks::*;
+use crate::vecs::*;
+use crate::core::mem::transmute;
+use crate::core::arch::x86_64 as core_arch;
+
+// Each table is indexed by the bitmask of the elements to keep, and holds the
+// shuffle which moves those elements to the front of the vector. Shuffle
+// indices of 0x80 zero the remaining bytes of the result.
+
+const COMPRESS_32X4: [[u8; 16]; 16] = [
+    [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80],
+    [0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+];
+
+const COMPRESS_64X2: [[u8; 16]; 4] = [
+    [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+];
+
+// Indexed by eight bits of a byte mask, and used by both halves of a 16-byte
+// shuffle; see compress_ctrl_8x16.
+const COMPRESS_8X8: [[u8; 8]; 256] = [
+    [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x80, 0x80, 0x80, 0x80],
+    [0x03, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x80, 0x80, 0x80],
+    [0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x05, 0x80, 0x80, 0x80, 0x80],
+    [0x03, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x05, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x05, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x05, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x05, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x05, 0x80, 0x80, 0x80],
+    [0x03, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x80, 0x80],
+    [0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x03, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x06, 0x80, 0x80, 0x80],
+    [0x04, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x06, 0x80, 0x80, 0x80],
+    [0x03, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x06, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x06, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x06, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x06, 0x80, 0x80],
+    [0x05, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x05, 0x06, 0x80, 0x80, 0x80],
+    [0x03, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x05, 0x06, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x05, 0x06, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x05, 0x06, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x05, 0x06, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x05, 0x06, 0x80, 0x80],
+    [0x03, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x05, 0x06, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x05, 0x06, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x80],
+    [0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x03, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x07, 0x80, 0x80, 0x80],
+    [0x04, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x07, 0x80, 0x80, 0x80],
+    [0x03, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x07, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x07, 0x80, 0x80],
+    [0x05, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x05, 0x07, 0x80, 0x80, 0x80],
+    [0x03, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x05, 0x07, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x05, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x05, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x05, 0x07, 0x80, 0x80],
+    [0x04, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x05, 0x07, 0x80, 0x80],
+    [0x03, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x05, 0x07, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x07, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x05, 0x07, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x05, 0x07, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x07, 0x80],
+    [0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x03, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x80, 0x80],
+    [0x04, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x02, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x06, 0x07, 0x80, 0x80],
+    [0x03, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x06, 0x07, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x06, 0x07, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x06, 0x07, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x06, 0x07, 0x80],
+    [0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80], [0x00, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80],
+    [0x01, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x01, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x02, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x02, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x02, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x02, 0x05, 0x06, 0x07, 0x80, 0x80],
+    [0x03, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x03, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x03, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x03, 0x05, 0x06, 0x07, 0x80, 0x80],
+    [0x02, 0x03, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x02, 0x03, 0x05, 0x06, 0x07, 0x80, 0x80],
+    [0x01, 0x02, 0x03, 0x05, 0x06, 0x07, 0x80, 0x80], [0x00, 0x01, 0x02, 0x03, 0x05, 0x06, 0x07, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80], [0x00, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80],
+    [0x01, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80],
+    [0x02, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x02, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80],
+    [0x01, 0x02, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80], [0x00, 0x01, 0x02, 0x04, 0x05, 0x06, 0x07, 0x80],
+    [0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80], [0x00, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80],
+    [0x01, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80], [0x00, 0x01, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80], [0x00, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80],
+    [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80], [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07],
+];
+
+const COMPRESS_16X8: [[u8; 16]; 256] = [
+    [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x80, 0x80, 0x80, 0x80],
+    [0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80],
+    [0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x80, 0x80],
+    [0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0E, 0x0F, 0x80, 0x80],
+    [0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80],
+    [0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80],
+    [0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80],
+    [0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80],
+    [0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80, 0x80, 0x80],
+    [0x00, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80],
+    [0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x80, 0x80],
+    [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+];
+
+// Loading 16 bytes at `16 - n` gives a shuffle which moves every byte up by
+// `n`, and loading at `32 - n` gives one which moves the `n` bytes shifted out
+// of the top down to the bottom.
+const SHIFT_BYTES: [u8; 48] = [
+    0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+];
+
+// These tables are used with _mm256_permutevar8x32_epi32, which can't zero
+// elements, so the unused elements are masked off separately.
+
+const COMPRESS_32X8: [[u32; 8]; 256] = [
+    [0, 0, 0, 0, 0, 0, 0, 0], [0, 0, 0, 0, 0, 0, 0, 0], [1, 0, 0, 0, 0, 0, 0, 0], [0, 1, 0, 0, 0, 0, 0, 0],
+    [2, 0, 0, 0, 0, 0, 0, 0], [0, 2, 0, 0, 0, 0, 0, 0], [1, 2, 0, 0, 0, 0, 0, 0], [0, 1, 2, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0], [0, 3, 0, 0, 0, 0, 0, 0], [1, 3, 0, 0, 0, 0, 0, 0], [0, 1, 3, 0, 0, 0, 0, 0],
+    [2, 3, 0, 0, 0, 0, 0, 0], [0, 2, 3, 0, 0, 0, 0, 0], [1, 2, 3, 0, 0, 0, 0, 0], [0, 1, 2, 3, 0, 0, 0, 0],
+    [4, 0, 0, 0, 0, 0, 0, 0], [0, 4, 0, 0, 0, 0, 0, 0], [1, 4, 0, 0, 0, 0, 0, 0], [0, 1, 4, 0, 0, 0, 0, 0],
+    [2, 4, 0, 0, 0, 0, 0, 0], [0, 2, 4, 0, 0, 0, 0, 0], [1, 2, 4, 0, 0, 0, 0, 0], [0, 1, 2, 4, 0, 0, 0, 0],
+    [3, 4, 0, 0, 0, 0, 0, 0], [0, 3, 4, 0, 0, 0, 0, 0], [1, 3, 4, 0, 0, 0, 0, 0], [0, 1, 3, 4, 0, 0, 0, 0],
+    [2, 3, 4, 0, 0, 0, 0, 0], [0, 2, 3, 4, 0, 0, 0, 0], [1, 2, 3, 4, 0, 0, 0, 0], [0, 1, 2, 3, 4, 0, 0, 0],
+    [5, 0, 0, 0, 0, 0, 0, 0], [0, 5, 0, 0, 0, 0, 0, 0], [1, 5, 0, 0, 0, 0, 0, 0], [0, 1, 5, 0, 0, 0, 0, 0],
+    [2, 5, 0, 0, 0, 0, 0, 0], [0, 2, 5, 0, 0, 0, 0, 0], [1, 2, 5, 0, 0, 0, 0, 0], [0, 1, 2, 5, 0, 0, 0, 0],
+    [3, 5, 0, 0, 0, 0, 0, 0], [0, 3, 5, 0, 0, 0, 0, 0], [1, 3, 5, 0, 0, 0, 0, 0], [0, 1, 3, 5, 0, 0, 0, 0],
+    [2, 3, 5, 0, 0, 0, 0, 0], [0, 2, 3, 5, 0, 0, 0, 0], [1, 2, 3, 5, 0, 0, 0, 0], [0, 1, 2, 3, 5, 0, 0, 0],
+    [4, 5, 0, 0, 0, 0, 0, 0], [0, 4, 5, 0, 0, 0, 0, 0], [1, 4, 5, 0, 0, 0, 0, 0], [0, 1, 4, 5, 0, 0, 0, 0],
+    [2, 4, 5, 0, 0, 0, 0, 0], [0, 2, 4, 5, 0, 0, 0, 0], [1, 2, 4, 5, 0, 0, 0, 0], [0, 1, 2, 4, 5, 0, 0, 0],
+    [3, 4, 5, 0, 0, 0, 0, 0], [0, 3, 4, 5, 0, 0, 0, 0], [1, 3, 4, 5, 0, 0, 0, 0], [0, 1, 3, 4, 5, 0, 0, 0],
+    [2, 3, 4, 5, 0, 0, 0, 0], [0, 2, 3, 4, 5, 0, 0, 0], [1, 2, 3, 4, 5, 0, 0, 0], [0, 1, 2, 3, 4, 5, 0, 0],
+    [6, 0, 0, 0, 0, 0, 0, 0], [0, 6, 0, 0, 0, 0, 0, 0], [1, 6, 0, 0, 0, 0, 0, 0], [0, 1, 6, 0, 0, 0, 0, 0],
+    [2, 6, 0, 0, 0, 0, 0, 0], [0, 2, 6, 0, 0, 0, 0, 0], [1, 2, 6, 0, 0, 0, 0, 0], [0, 1, 2, 6, 0, 0, 0, 0],
+    [3, 6, 0, 0, 0, 0, 0, 0], [0, 3, 6, 0, 0, 0, 0, 0], [1, 3, 6, 0, 0, 0, 0, 0], [0, 1, 3, 6, 0, 0, 0, 0],
+    [2, 3, 6, 0, 0, 0, 0, 0], [0, 2, 3, 6, 0, 0, 0, 0], [1, 2, 3, 6, 0, 0, 0, 0], [0, 1, 2, 3, 6, 0, 0, 0],
+    [4, 6, 0, 0, 0, 0, 0, 0], [0, 4, 6, 0, 0, 0, 0, 0], [1, 4, 6, 0, 0, 0, 0, 0], [0, 1, 4, 6, 0, 0, 0, 0],
+    [2, 4, 6, 0, 0, 0, 0, 0], [0, 2, 4, 6, 0, 0, 0, 0], [1, 2, 4, 6, 0, 0, 0, 0], [0, 1, 2, 4, 6, 0, 0, 0],
+    [3, 4, 6, 0, 0, 0, 0, 0], [0, 3, 4, 6, 0, 0, 0, 0], [1, 3, 4, 6, 0, 0, 0, 0], [0, 1, 3, 4, 6, 0, 0, 0],
+    [2, 3, 4, 6, 0, 0, 0, 0], [0, 2, 3, 4, 6, 0, 0, 0], [1, 2, 3, 4, 6, 0, 0, 0], [0, 1, 2, 3, 4, 6, 0, 0],
+    [5, 6, 0, 0, 0, 0, 0, 0], [0, 5, 6, 0, 0, 0, 0, 0], [1, 5, 6, 0, 0, 0, 0, 0], [0, 1, 5, 6, 0, 0, 0, 0],
+    [2, 5, 6, 0, 0, 0, 0, 0], [0, 2, 5, 6, 0, 0, 0, 0], [1, 2, 5, 6, 0, 0, 0, 0], [0, 1, 2, 5, 6, 0, 0, 0],
+    [3, 5, 6, 0, 0, 0, 0, 0], [0, 3, 5, 6, 0, 0, 0, 0], [1, 3, 5, 6, 0, 0, 0, 0], [0, 1, 3, 5, 6, 0, 0, 0],
+    [2, 3, 5, 6, 0, 0, 0, 0], [0, 2, 3, 5, 6, 0, 0, 0], [1, 2, 3, 5, 6, 0, 0, 0], [0, 1, 2, 3, 5, 6, 0, 0],
+    [4, 5, 6, 0, 0, 0, 0, 0], [0, 4, 5, 6, 0, 0, 0, 0], [1, 4, 5, 6, 0, 0, 0, 0], [0, 1, 4, 5, 6, 0, 0, 0],
+    [2, 4, 5, 6, 0, 0, 0, 0], [0, 2, 4, 5, 6, 0, 0, 0], [1, 2, 4, 5, 6, 0, 0, 0], [0, 1, 2, 4, 5, 6, 0, 0],
+    [3, 4, 5, 6, 0, 0, 0, 0], [0, 3, 4, 5, 6, 0, 0, 0], [1, 3, 4, 5, 6, 0, 0, 0], [0, 1, 3, 4, 5, 6, 0, 0],
+    [2, 3, 4, 5, 6, 0, 0, 0], [0, 2, 3, 4, 5, 6, 0, 0], [1, 2, 3, 4, 5, 6, 0, 0], [0, 1, 2, 3, 4, 5, 6, 0],
+    [7, 0, 0, 0, 0, 0, 0, 0], [0, 7, 0, 0, 0, 0, 0, 0], [1, 7, 0, 0, 0, 0, 0, 0], [0, 1, 7, 0, 0, 0, 0, 0],
+    [2, 7, 0, 0, 0, 0, 0, 0], [0, 2, 7, 0, 0, 0, 0, 0], [1, 2, 7, 0, 0, 0, 0, 0], [0, 1, 2, 7, 0, 0, 0, 0],
+    [3, 7, 0, 0, 0, 0, 0, 0], [0, 3, 7, 0, 0, 0, 0, 0], [1, 3, 7, 0, 0, 0, 0, 0], [0, 1, 3, 7, 0, 0, 0, 0],
+    [2, 3, 7, 0, 0, 0, 0, 0], [0, 2, 3, 7, 0, 0, 0, 0], [1, 2, 3, 7, 0, 0, 0, 0], [0, 1, 2, 3, 7, 0, 0, 0],
+    [4, 7, 0, 0, 0, 0, 0, 0], [0, 4, 7, 0, 0, 0, 0, 0], [1, 4, 7, 0, 0, 0, 0, 0], [0, 1, 4, 7, 0, 0, 0, 0],
+    [2, 4, 7, 0, 0, 0, 0, 0], [0, 2, 4, 7, 0, 0, 0, 0], [1, 2, 4, 7, 0, 0, 0, 0], [0, 1, 2, 4, 7, 0, 0, 0],
+    [3, 4, 7, 0, 0, 0, 0, 0], [0, 3, 4, 7, 0, 0, 0, 0], [1, 3, 4, 7, 0, 0, 0, 0], [0, 1, 3, 4, 7, 0, 0, 0],
+    [2, 3, 4, 7, 0, 0, 0, 0], [0, 2, 3, 4, 7, 0, 0, 0], [1, 2, 3, 4, 7, 0, 0, 0], [0, 1, 2, 3, 4, 7, 0, 0],
+    [5, 7, 0, 0, 0, 0, 0, 0], [0, 5, 7, 0, 0, 0, 0, 0], [1, 5, 7, 0, 0, 0, 0, 0], [0, 1, 5, 7, 0, 0, 0, 0],
+    [2, 5, 7, 0, 0, 0, 0, 0], [0, 2, 5, 7, 0, 0, 0, 0], [1, 2, 5, 7, 0, 0, 0, 0], [0, 1, 2, 5, 7, 0, 0, 0],
+    [3, 5, 7, 0, 0, 0, 0, 0], [0, 3, 5, 7, 0, 0, 0, 0], [1, 3, 5, 7, 0, 0, 0, 0], [0, 1, 3, 5, 7, 0, 0, 0],
+    [2, 3, 5, 7, 0, 0, 0, 0], [0, 2, 3, 5, 7, 0, 0, 0], [1, 2, 3, 5, 7, 0, 0, 0], [0, 1, 2, 3, 5, 7, 0, 0],
+    [4, 5, 7, 0, 0, 0, 0, 0], [0, 4, 5, 7, 0, 0, 0, 0], [1, 4, 5, 7, 0, 0, 0, 0], [0, 1, 4, 5, 7, 0, 0, 0],
+    [2, 4, 5, 7, 0, 0, 0, 0], [0, 2, 4, 5, 7, 0, 0, 0], [1, 2, 4, 5, 7, 0, 0, 0], [0, 1, 2, 4, 5, 7, 0, 0],
+    [3, 4, 5, 7, 0, 0, 0, 0], [0, 3, 4, 5, 7, 0, 0, 0], [1, 3, 4, 5, 7, 0, 0, 0], [0, 1, 3, 4, 5, 7, 0, 0],
+    [2, 3, 4, 5, 7, 0, 0, 0], [0, 2, 3, 4, 5, 7, 0, 0], [1, 2, 3, 4, 5, 7, 0, 0], [0, 1, 2, 3, 4, 5, 7, 0],
+    [6, 7, 0, 0, 0, 0, 0, 0], [0, 6, 7, 0, 0, 0, 0, 0], [1, 6, 7, 0, 0, 0, 0, 0], [0, 1, 6, 7, 0, 0, 0, 0],
+    [2, 6, 7, 0, 0, 0, 0, 0], [0, 2, 6, 7, 0, 0, 0, 0], [1, 2, 6, 7, 0, 0, 0, 0], [0, 1, 2, 6, 7, 0, 0, 0],
+    [3, 6, 7, 0, 0, 0, 0, 0], [0, 3, 6, 7, 0, 0, 0, 0], [1, 3, 6, 7, 0, 0, 0, 0], [0, 1, 3, 6, 7, 0, 0, 0],
+    [2, 3, 6, 7, 0, 0, 0, 0], [0, 2, 3, 6, 7, 0, 0, 0], [1, 2, 3, 6, 7, 0, 0, 0], [0, 1, 2, 3, 6, 7, 0, 0],
+    [4, 6, 7, 0, 0, 0, 0, 0], [0, 4, 6, 7, 0, 0, 0, 0], [1, 4, 6, 7, 0, 0, 0, 0], [0, 1, 4, 6, 7, 0, 0, 0],
+    [2, 4, 6, 7, 0, 0, 0, 0], [0, 2, 4, 6, 7, 0, 0, 0], [1, 2, 4, 6, 7, 0, 0, 0], [0, 1, 2, 4, 6, 7, 0, 0],
+    [3, 4, 6, 7, 0, 0, 0, 0], [0, 3, 4, 6, 7, 0, 0, 0], [1, 3, 4, 6, 7, 0, 0, 0], [0, 1, 3, 4, 6, 7, 0, 0],
+    [2, 3, 4, 6, 7, 0, 0, 0], [0, 2, 3, 4, 6, 7, 0, 0], [1, 2, 3, 4, 6, 7, 0, 0], [0, 1, 2, 3, 4, 6, 7, 0],
+    [5, 6, 7, 0, 0, 0, 0, 0], [0, 5, 6, 7, 0, 0, 0, 0], [1, 5, 6, 7, 0, 0, 0, 0], [0, 1, 5, 6, 7, 0, 0, 0],
+    [2, 5, 6, 7, 0, 0, 0, 0], [0, 2, 5, 6, 7, 0, 0, 0], [1, 2, 5, 6, 7, 0, 0, 0], [0, 1, 2, 5, 6, 7, 0, 0],
+    [3, 5, 6, 7, 0, 0, 0, 0], [0, 3, 5, 6, 7, 0, 0, 0], [1, 3, 5, 6, 7, 0, 0, 0], [0, 1, 3, 5, 6, 7, 0, 0],
+    [2, 3, 5, 6, 7, 0, 0, 0], [0, 2, 3, 5, 6, 7, 0, 0], [1, 2, 3, 5, 6, 7, 0, 0], [0, 1, 2, 3, 5, 6, 7, 0],
+    [4, 5, 6, 7, 0, 0, 0, 0], [0, 4, 5, 6, 7, 0, 0, 0], [1, 4, 5, 6, 7, 0, 0, 0], [0, 1, 4, 5, 6, 7, 0, 0],
+    [2, 4, 5, 6, 7, 0, 0, 0], [0, 2, 4, 5, 6, 7, 0, 0], [1, 2, 4, 5, 6, 7, 0, 0], [0, 1, 2, 4, 5, 6, 7, 0],
+    [3, 4, 5, 6, 7, 0, 0, 0], [0, 3, 4, 5, 6, 7, 0, 0], [1, 3, 4, 5, 6, 7, 0, 0], [0, 1, 3, 4, 5, 6, 7, 0],
+    [2, 3, 4, 5, 6, 7, 0, 0], [0, 2, 3, 4, 5, 6, 7, 0], [1, 2, 3, 4, 5, 6, 7, 0], [0, 1, 2, 3, 4, 5, 6, 7],
+];
+
+const COMPRESS_64X4: [[u32; 8]; 16] = [
+    [0, 0, 0, 0, 0, 0, 0, 0], [0, 1, 0, 0, 0, 0, 0, 0],
+    [2, 3, 0, 0, 0, 0, 0, 0], [0, 1, 2, 3, 0, 0, 0, 0],
+    [4, 5, 0, 0, 0, 0, 0, 0], [0, 1, 4, 5, 0, 0, 0, 0],
+    [2, 3, 4, 5, 0, 0, 0, 0], [0, 1, 2, 3, 4, 5, 0, 0],
+    [6, 7, 0, 0, 0, 0, 0, 0], [0, 1, 6, 7, 0, 0, 0, 0],
+    [2, 3, 6, 7, 0, 0, 0, 0], [0, 1, 2, 3, 6, 7, 0, 0],
+    [4, 5, 6, 7, 0, 0, 0, 0], [0, 1, 4, 5, 6, 7, 0, 0],
+    [2, 3, 4, 5, 6, 7, 0, 0], [0, 1, 2, 3, 4, 5, 6, 7],
+];
+
+/// Return the shuffle which packs the bytes of a 16-byte vector selected by the
+/// low 16 bits of `mask` to its front. Each half is packed with COMPRESS_8X8,
+/// and the high half's indices are moved up to follow the low half's.
+#[inline(always)]
+fn compress_ctrl_8x16(mask: u64) -> u128 {
+    let lo = (mask & 0xFF) as usize;
+    let hi = ((mask >> 8) & 0xFF) as usize;
+    let kept = 8 * lo.count_ones();
+    let lo_ctrl = u64::from_le_bytes(COMPRESS_8X8[lo]) as u128;
+    // Adding 8 to an index of 0x80 leaves its high bit set
+    let hi_ctrl = (u64::from_le_bytes(COMPRESS_8X8[hi]) + 0x0808080808080808) as u128
+        | (0x8080808080808080u128 << 64);
+    (lo_ctrl & ((1u128 << kept) - 1)) | (hi_ctrl << kept)
+}
+
+/// Move the `kept` packed bytes at the front of the high 128-bit lane of
+/// `packed` to follow the `kept` bytes at the front of the low lane.
+#[inline(always)]
+unsafe fn compress_merge_lanes(packed: core_arch::__m256i, kept: usize) -> core_arch::__m256i {
+    let lanes: [core_arch::__m128i; 2] = transmute(packed);
+    let up = core_arch::_mm_loadu_si128(SHIFT_BYTES.as_ptr().add(16 - kept) as *const _);
+    let down = core_arch::_mm_loadu_si128(SHIFT_BYTES.as_ptr().add(32 - kept) as *const _);
+    transmute([core_arch::_mm_or_si128(lanes[0], core_arch::_mm_shuffle_epi8(lanes[1], up)),
+               core_arch::_mm_shuffle_epi8(lanes[1], down)])
+}
+
+#[inline(always)]
+unsafe fn compress_8x16(vec: core_arch::__m128i, mask: u64) -> core_arch::__m128i {
+    core_arch::_mm_shuffle_epi8(vec, transmute(compress_ctrl_8x16(mask)))
+}
+
+#[inline(always)]
+unsafe fn compress_16x8(vec: core_arch::__m128i, mask: u64) -> core_arch::__m128i {
+    core_arch::_mm_shuffle_epi8(vec, transmute(COMPRESS_16X8[mask as usize]))
+}
+
+#[inline(always)]
+unsafe fn compress_8x32(vec: core_arch::__m256i, mask: u64) -> core_arch::__m256i {
+    let ctrl = [compress_ctrl_8x16(mask & 0xFFFF), compress_ctrl_8x16((mask >> 16) & 0xFFFF)];
+    let packed = core_arch::_mm256_shuffle_epi8(vec, transmute(ctrl));
+    compress_merge_lanes(packed, (mask & 0xFFFF).count_ones() as usize)
+}
+
+#[inline(always)]
+unsafe fn compress_16x16(vec: core_arch::__m256i, mask: u64) -> core_arch::__m256i {
+    let ctrl = [COMPRESS_16X8[(mask & 0xFF) as usize], COMPRESS_16X8[((mask >> 8) & 0xFF) as usize]];
+    let packed = core_arch::_mm256_shuffle_epi8(vec, transmute(ctrl));
+    compress_merge_lanes(packed, 2 * (mask & 0xFF).count_ones() as usize)
+}
+
+#[inline(always)]
+unsafe fn compress_32x4(vec: i8x16, mask: u64) -> i8x16 {
+    _mm_shuffle_epi8(vec, transmute(COMPRESS_32X4[mask as usize]))
+}
+
+#[inline(always)]
+unsafe fn compress_64x2(vec: i8x16, mask: u64) -> i8x16 {
+    _mm_shuffle_epi8(vec, transmute(COMPRESS_64X2[mask as usize]))
+}
+
+#[inline(always)]
+unsafe fn compress_32x8(vec: i32x8, mask: u64) -> i32x8 {
+    let kept = !u32x8::partition_mask(mask.count_ones() as usize);
+    _mm256_permutevar8x32_epi32(vec, transmute(COMPRESS_32X8[mask as usize])) & transmute::<_, i32x8>(kept)
+}
+
+#[inline(always)]
+unsafe fn compress_64x4(vec: i32x8, mask: u64) -> i32x8 {
+    let kept = !u64x4::partition_mask(mask.count_ones() as usize);
+    _mm256_permutevar8x32_epi32(vec, transmute(COMPRESS_64X4[mask as usize])) & transmute::<_, i32x8>(kept)
+}
+
+impl_compress!(u8x16, "ssse3", compress_8x16);
+impl_compress!(i8x16, "ssse3", compress_8x16);
+impl_compress!(u16x8, "ssse3", compress_16x8);
+impl_compress!(i16x8, "ssse3", compress_16x8);
+impl_compress!(u32x4, "ssse3", compress_32x4);
+impl_compress!(i32x4, "ssse3", compress_32x4);
+impl_compress!(f32x4, "ssse3", compress_32x4);
+impl_compress!(u64x2, "ssse3", compress_64x2);
+impl_compress!(i64x2, "ssse3", compress_64x2);
+impl_compress!(f64x2, "ssse3", compress_64x2);
+
+impl_compress!(u8x32, "avx2", compress_8x32);
+impl_compress!(i8x32, "avx2", compress_8x32);
+impl_compress!(u16x16, "avx2", compress_16x16);
+impl_compress!(i16x16, "avx2", compress_16x16);
+impl_compress!(u32x8, "avx2", compress_32x8);
+impl_compress!(i32x8, "avx2", compress_32x8);
+impl_compress!(f32x8, "avx2", compress_32x8);
+impl_compress!(u64x4, "avx2", compress_64x4);
+impl_compress!(i64x4, "avx2", compress_64x4);
+impl_compress!(f64x4, "avx2", compress_64x4);
+
+impl_compress!(u8x64, "avx512-butnotyet", __undefined);
+impl_compress!(i8x64, "avx512-butnotyet", __undefined);
+impl_compress!(u16x32, "avx512-butnotyet", __undefined);
+impl_compress!(i16x32, "avx512-butnotyet", __undefined);
+impl_compress!(u32x16, "avx512-butnotyet", __undefined);
+impl_compress!(i32x16, "avx512-butnotyet", __undefined);
+impl_compress!(f32x16, "avx512-butnotyet", __undefined);
+impl_compress!(u64x8, "avx512-butnotyet", __undefined);
+impl_compress!(i64x8, "avx512-butnotyet", __undefined);
+impl_compress!(f64x8, "avx512-butnotyet", __undefined);
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_compress!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (compress_u8x16, compress_i8x16, compress_u16x8, compress_i16x8, compress_u32x4, compress_i32x4, compress_f32x4, compress_u64x2, compress_i64x2, compress_f64x2, compress_u8x32, compress_i8x32, compress_u16x16, compress_i16x16, compress_u32x8, compress_i32x8, compress_f32x8, compress_u64x4, compress_i64x4, compress_f64x4, compress_u8x64, compress_i8x64, compress_u16x32, compress_i16x32, compress_u32x16, compress_i32x16, compress_f32x16, compress_u64x8, compress_i64x8, compress_f64x8));
+}
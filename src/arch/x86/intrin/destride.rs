@@ -6,6 +6,215 @@ use crate::intrin::merge::*;
 use crate::intrin::transmute::*;
 use crate::intrin::destride::*;
 use crate::core::mem::transmute;
+use crate::core::arch::x86_64 as core_arch;
+
+// The byte shuffle which moves the elements of channel `k` held by the `v`th
+// of `n` vectors of records of `n` elements of `size` bytes into place,
+// repeated in each 128-bit lane of the mask. Bytes with the high bit set are
+// zeroed by the shuffle.
+#[cfg(target_feature = "ssse3")]
+const fn channel_mask<const L: usize>(n: usize, k: usize, v: usize, size: usize) -> [u8; L] {
+    let width = 16 / size;
+    let mut mask = [0x80; L];
+    let mut byte = 0;
+    while byte < L {
+        let i = n * (byte % 16 / size) + k;
+        if i / width == v {
+            mask[byte] = ((i % width) * size + byte % size) as u8;
+        }
+        byte += 1;
+    }
+    mask
+}
+
+#[cfg(target_feature = "ssse3")]
+const fn channel_masks<const N: usize, const L: usize>(k: usize, size: usize) -> [[u8; L]; N] {
+    let mut masks = [[0x80; L]; N];
+    let mut v = 0;
+    while v < N {
+        masks[v] = channel_mask(N, k, v, size);
+        v += 1;
+    }
+    masks
+}
+
+#[cfg(target_feature = "ssse3")]
+const fn channel_sources<const N: usize>(masks: &[[u8; 16]; N]) -> [bool; N] {
+    let mut used = [false; N];
+    let mut v = 0;
+    while v < N {
+        let mut byte = 0;
+        while byte < 16 {
+            used[v] |= masks[v][byte] & 0x80 == 0;
+            byte += 1;
+        }
+        v += 1;
+    }
+    used
+}
+
+#[cfg(target_feature = "ssse3")]
+struct Channel<const N: usize, const K: usize, const SIZE: usize>;
+
+#[cfg(target_feature = "ssse3")]
+impl<const N: usize, const K: usize, const SIZE: usize> Channel<N, K, SIZE> {
+    const MASKS: [[u8; 16]; N] = channel_masks(K, SIZE);
+    #[cfg(target_feature = "avx2")]
+    const MASKS_256: [[u8; 32]; N] = channel_masks(K, SIZE);
+    // Whether any element of the channel lies in each vector
+    const SOURCES: [bool; N] = channel_sources(&Self::MASKS);
+}
+
+// Gather channel `K` of the records of `N` elements of `SIZE` bytes in the
+// concatenation of `vecs`, by shuffling the bytes of each vector holding some
+// of the channel into place and combining them.
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn shuffle_channel<const N: usize, const K: usize, const SIZE: usize>(vecs: &[core_arch::__m128i; N]) -> core_arch::__m128i {
+    let mut ret = core_arch::_mm_setzero_si128();
+    for v in 0..N {
+        if Channel::<N, K, SIZE>::SOURCES[v] {
+            let mask = transmute(Channel::<N, K, SIZE>::MASKS[v]);
+            ret = core_arch::_mm_or_si128(ret, core_arch::_mm_shuffle_epi8(vecs[v], mask));
+        }
+    }
+    ret
+}
+
+// Like `shuffle_channel`, but shuffling within each 128-bit lane. The low lane
+// of each vector of `lanes` must hold the `v`th 128 bits of the first half of
+// the records, and the high lane the `v`th 128 bits of the second half.
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn shuffle_channel_256<const N: usize, const K: usize, const SIZE: usize>(lanes: &[core_arch::__m256i; N]) -> core_arch::__m256i {
+    let mut ret = core_arch::_mm256_setzero_si256();
+    for v in 0..N {
+        if Channel::<N, K, SIZE>::SOURCES[v] {
+            let mask = transmute(Channel::<N, K, SIZE>::MASKS_256[v]);
+            ret = core_arch::_mm256_or_si256(ret, core_arch::_mm256_shuffle_epi8(lanes[v], mask));
+        }
+    }
+    ret
+}
+
+macro_rules! impl_destride_odd {
+    (ssse3, $size:expr) => {
+        #[inline(always)]
+        #[cfg(target_feature = "ssse3")]
+        fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+            optimized!();
+            unsafe {
+                let vecs: [core_arch::__m128i; 3] = [transmute(self), transmute(b), transmute(c)];
+                (transmute(shuffle_channel::<3, 0, $size>(&vecs)),
+                 transmute(shuffle_channel::<3, 1, $size>(&vecs)),
+                 transmute(shuffle_channel::<3, 2, $size>(&vecs)))
+            }
+        }
+
+        #[inline(always)]
+        #[cfg(not(target_feature = "ssse3"))]
+        fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+            fallback!();
+            destride_three_polyfill!(self, b, c)
+        }
+
+        #[inline(always)]
+        #[cfg(target_feature = "ssse3")]
+        fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+            optimized!();
+            unsafe {
+                let vecs: [core_arch::__m128i; 8] = [transmute(self), transmute(b), transmute(c), transmute(d),
+                                                    transmute(e), transmute(f), transmute(g), transmute(h)];
+                (transmute(shuffle_channel::<8, 0, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 1, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 2, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 3, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 4, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 5, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 6, $size>(&vecs)),
+                 transmute(shuffle_channel::<8, 7, $size>(&vecs)))
+            }
+        }
+
+        #[inline(always)]
+        #[cfg(not(target_feature = "ssse3"))]
+        fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+            fallback!();
+            destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+        }
+    };
+    (avx2, $size:expr) => {
+        #[inline(always)]
+        #[cfg(target_feature = "avx2")]
+        fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+            optimized!();
+            unsafe {
+                let (a, b, c): (core_arch::__m256i, core_arch::__m256i, core_arch::__m256i) = (transmute(self), transmute(b), transmute(c));
+                // Pair the nth 128 bits of each half of the records
+                let lanes = [core_arch::_mm256_blend_epi32(a, b, 0xF0),
+                             core_arch::_mm256_permute2x128_si256(a, c, 0x21),
+                             core_arch::_mm256_blend_epi32(b, c, 0xF0)];
+                (transmute(shuffle_channel_256::<3, 0, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<3, 1, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<3, 2, $size>(&lanes)))
+            }
+        }
+
+        #[inline(always)]
+        #[cfg(not(target_feature = "avx2"))]
+        fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+            fallback!();
+            destride_three_polyfill!(self, b, c)
+        }
+
+        #[inline(always)]
+        #[cfg(target_feature = "avx2")]
+        fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+            optimized!();
+            unsafe {
+                let vecs: [core_arch::__m256i; 8] = [transmute(self), transmute(b), transmute(c), transmute(d),
+                                                    transmute(e), transmute(f), transmute(g), transmute(h)];
+                // Pair the nth 128 bits of each half of the records
+                let lanes = [core_arch::_mm256_permute2x128_si256(vecs[0], vecs[4], 0x20),
+                             core_arch::_mm256_permute2x128_si256(vecs[0], vecs[4], 0x31),
+                             core_arch::_mm256_permute2x128_si256(vecs[1], vecs[5], 0x20),
+                             core_arch::_mm256_permute2x128_si256(vecs[1], vecs[5], 0x31),
+                             core_arch::_mm256_permute2x128_si256(vecs[2], vecs[6], 0x20),
+                             core_arch::_mm256_permute2x128_si256(vecs[2], vecs[6], 0x31),
+                             core_arch::_mm256_permute2x128_si256(vecs[3], vecs[7], 0x20),
+                             core_arch::_mm256_permute2x128_si256(vecs[3], vecs[7], 0x31)];
+                (transmute(shuffle_channel_256::<8, 0, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 1, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 2, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 3, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 4, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 5, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 6, $size>(&lanes)),
+                 transmute(shuffle_channel_256::<8, 7, $size>(&lanes)))
+            }
+        }
+
+        #[inline(always)]
+        #[cfg(not(target_feature = "avx2"))]
+        fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+            fallback!();
+            destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+        }
+    };
+    (polyfill) => {
+        #[inline(always)]
+        fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self) {
+            fallback!();
+            destride_three_polyfill!(self, b, c)
+        }
+
+        #[inline(always)]
+        fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self) {
+            fallback!();
+            destride_eight_polyfill!(self, b, c, d, e, f, g, h)
+        }
+    };
+}
 
 impl Destride for u8x16 {
     #[inline(always)]
@@ -33,6 +242,8 @@ impl Destride for u8x16 {
         fallback!();
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12)
     }
+
+    impl_destride_odd!(ssse3, 1);
 }
 
 impl Destride for u8x32 {
@@ -65,6 +276,8 @@ impl Destride for u8x32 {
         fallback!();
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12, 16, 20, 24, 28)
     }
+
+    impl_destride_odd!(avx2, 1);
 }
 
 impl Destride for i8x16 {
@@ -93,6 +306,8 @@ impl Destride for i8x16 {
         fallback!();
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12)
     }
+
+    impl_destride_odd!(ssse3, 1);
 }
 
 impl Destride for i8x32 {
@@ -125,10 +340,12 @@ impl Destride for i8x32 {
         fallback!();
         destride_four_polyfill!(self, b, c, d, 0, 4, 8, 12, 16, 20, 24, 28)
     }
+
+    impl_destride_odd!(avx2, 1);
 }
 
 macro_rules! impl_destride {
-    ($t:ty, $($two:expr, $four:expr),*) => {
+    ($t:ty, ($($odd:tt)*), $($two:expr, $four:expr),*) => {
         impl Destride for $t {
             #[inline(always)]
             fn destride_two(self, other: Self) -> (Self, Self) {
@@ -141,16 +358,34 @@ macro_rules! impl_destride {
                 fallback!();
                 destride_four_polyfill!(self, b, c, d, $($two),*)
             }
+
+            impl_destride_odd!($($odd)*);
         }
     }
 }
 
-impl_destride!(u16x16, 0, 2, 4, 6, 8, 10, 12, 14);
-impl_destride!(u16x8, 0, 2, 4, 6);
-impl_destride!(i16x16, 0, 2, 4, 6, 8, 10, 12, 14);
-impl_destride!(i16x8, 0, 2, 4, 6);
+impl_destride!(u8x64, (polyfill), 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+impl_destride!(i8x64, (polyfill), 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, 32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+
+impl_destride!(u16x32, (polyfill), 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+impl_destride!(u16x16, (avx2, 2), 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(u16x8, (ssse3, 2), 0, 2, 4, 6);
+impl_destride!(i16x32, (polyfill), 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+impl_destride!(i16x16, (avx2, 2), 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(i16x8, (ssse3, 2), 0, 2, 4, 6);
 
-impl_destride!(u32x8, 0, 2, 4, 6);
-impl_destride!(u32x4, 0, 2);
-impl_destride!(i32x8, 0, 2, 4, 6);
-impl_destride!(i32x4, 0, 2);
+impl_destride!(u32x16, (polyfill), 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(u32x8, (avx2, 4), 0, 2, 4, 6);
+impl_destride!(u32x4, (ssse3, 4), 0, 2);
+impl_destride!(i32x16, (polyfill), 0, 2, 4, 6, 8, 10, 12, 14);
+impl_destride!(i32x8, (avx2, 4), 0, 2, 4, 6);
+impl_destride!(i32x4, (ssse3, 4), 0, 2);
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_destride!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16),
+        (destride_u8x16, destride_i8x16, destride_u16x8, destride_i16x8, destride_u32x4, destride_i32x4, destride_u8x32, destride_i8x32, destride_u16x16, destride_i16x16, destride_u32x8, destride_i32x8, destride_u8x64, destride_i8x64, destride_u16x32, destride_i16x32, destride_u32x16, destride_i32x16));
+}
@@ -9,6 +9,9 @@ use crate::arch::current::vecs::*;
 use crate::vecs::*;
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::core::arch::x86_64::{_mm512_cvtsepi16_epi8, _mm512_cvtsepi32_epi16, _mm512_cvtsepi64_epi32};
+use crate::core::arch::x86_64::{_mm512_cvtusepi16_epi8, _mm512_cvtusepi32_epi16, _mm512_cvtusepi64_epi32};
+use crate::core::arch::x86_64::_mm512_cvtpd_ps;
 use crate::intrin::downcast::*;
 use crate::intrin::transmute::*;
 use crate::core::mem::transmute;
@@ -52,9 +55,9 @@ impl Downcast<u32x4> for u64x2 {
     fn saturating_downcast(self, other: Self) -> u32x4 {
         fallback!();
         u32x4::new(self.extract(0).min(0xFFFFFFFF) as u32,
-                   self.extract(1).min(0x7FFFFFFF) as u32,
-                   other.extract(0).min(0x7FFFFFFF) as u32,
-                   other.extract(1).min(0x7FFFFFFF) as u32)
+                   self.extract(1).min(0xFFFFFFFF) as u32,
+                   other.extract(0).min(0xFFFFFFFF) as u32,
+                   other.extract(1).min(0xFFFFFFFF) as u32)
     }
 }
 
@@ -105,7 +108,12 @@ impl Downcast<u16x8> for u32x4 {
     #[cfg(target_feature = "sse4.1")]
     fn saturating_downcast(self, other: Self) -> u16x8 {
         optimized!();
-        unsafe { transmute(_mm_packus_epi32(transmute(self), transmute(other))) }
+        // packus treats its inputs as signed, so clamp them first.
+        unsafe {
+            let max = u32x4::splat(0xFFFF);
+            transmute(_mm_packus_epi32(transmute(_mm_min_epu32(transmute(self), transmute(max))),
+                                       transmute(_mm_min_epu32(transmute(other), transmute(max)))))
+        }
     }
 
     #[inline(always)]
@@ -128,7 +136,14 @@ impl Downcast<u8x16> for u16x8 {
     #[cfg(target_feature = "sse2")]
     fn saturating_downcast(self, other: Self) -> u8x16 {
         optimized!();
-        unsafe { _mm_packus_epi16(self.be_i16s(), other.be_i16s()).be_u8s() }
+        // packus treats its inputs as signed, so clamp them first. There is no
+        // unsigned 16-bit min in SSE2, but x - sat(x - max) == min(x, max).
+        unsafe {
+            let max = u16x8::splat(0xFF);
+            let a: u16x8 = self - transmute::<_, u16x8>(_mm_subs_epu16(transmute(self), transmute(max)));
+            let b: u16x8 = other - transmute::<_, u16x8>(_mm_subs_epu16(transmute(other), transmute(max)));
+            _mm_packus_epi16(a.be_i16s(), b.be_i16s()).be_u8s()
+        }
     }
 
     #[inline(always)]
@@ -159,7 +174,8 @@ impl Downcast<i16x16> for i32x8 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> i16x16 {
         optimized!();
-        unsafe { _mm256_packs_epi32(self, other) }
+        // pack works within 128-bit lanes, so put the lanes back in order.
+        unsafe { transmute(_mm256_permute4x64_epi64(transmute(_mm256_packs_epi32(self, other)), 0xD8)) }
     }
 
     #[inline(always)]
@@ -190,7 +206,8 @@ impl Downcast<i8x32> for i16x16 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> i8x32 {
         optimized!();
-        unsafe { _mm256_packs_epi16(self.be_i16s(), other) }
+        // pack works within 128-bit lanes, so put the lanes back in order.
+        unsafe { transmute(_mm256_permute4x64_epi64(transmute(_mm256_packs_epi16(self.be_i16s(), other)), 0xD8)) }
     }
 
     #[inline(always)]
@@ -237,7 +254,14 @@ impl Downcast<u16x16> for u32x8 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> u16x16 {
         optimized!();
-        unsafe { transmute(_mm256_packus_epi32(transmute(self), transmute(other))) }
+        // packus treats its inputs as signed, so clamp them first. It also
+        // works within 128-bit lanes, so put the lanes back in order.
+        unsafe {
+            let max = u32x8::splat(0xFFFF);
+            let packed = _mm256_packus_epi32(transmute(_mm256_min_epu32(transmute(self), transmute(max))),
+                                             transmute(_mm256_min_epu32(transmute(other), transmute(max))));
+            transmute(_mm256_permute4x64_epi64(transmute(packed), 0xD8))
+        }
     }
 
     #[inline(always)]
@@ -268,7 +292,15 @@ impl Downcast<u8x32> for u16x16 {
     #[cfg(target_feature = "avx2")]
     fn saturating_downcast(self, other: Self) -> u8x32 {
         optimized!();
-        unsafe { _mm256_packus_epi16(self.be_i16s(), other.be_i16s()).be_u8s() }
+        // packus treats its inputs as signed, so clamp them first. It also
+        // works within 128-bit lanes, so put the lanes back in order.
+        unsafe {
+            let max = u16x16::splat(0xFF);
+            let a: u16x16 = transmute(_mm256_min_epu16(transmute(self), transmute(max)));
+            let b: u16x16 = transmute(_mm256_min_epu16(transmute(other), transmute(max)));
+            let packed = _mm256_packus_epi16(a.be_i16s(), b.be_i16s());
+            transmute(_mm256_permute4x64_epi64(transmute(packed), 0xD8))
+        }
     }
 
     #[inline(always)]
@@ -330,13 +362,13 @@ impl Downcast<u32x8> for u64x4 {
     fn saturating_downcast(self, other: Self) -> u32x8 {
         fallback!();
         u32x8::new(self.extract(0).min(0xFFFFFFFF) as u32,
-                   self.extract(1).min(0x7FFFFFFF) as u32,
+                   self.extract(1).min(0xFFFFFFFF) as u32,
                    self.extract(2).min(0xFFFFFFFF) as u32,
-                   self.extract(3).min(0x7FFFFFFF) as u32,
-                   other.extract(0).min(0x7FFFFFFF) as u32,
-                   other.extract(1).min(0x7FFFFFFF) as u32,
-                   other.extract(2).min(0x7FFFFFFF) as u32,
-                   other.extract(3).min(0x7FFFFFFF) as u32)
+                   self.extract(3).min(0xFFFFFFFF) as u32,
+                   other.extract(0).min(0xFFFFFFFF) as u32,
+                   other.extract(1).min(0xFFFFFFFF) as u32,
+                   other.extract(2).min(0xFFFFFFFF) as u32,
+                   other.extract(3).min(0xFFFFFFFF) as u32)
     }
 }
 
@@ -354,3 +386,348 @@ impl Downcast<f32x8> for f64x4 {
                    other.extract(3) as f32)
     }
 }
+
+impl Downcast<i16x32> for i32x16 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512f")]
+    fn saturating_downcast(self, other: Self) -> i16x32 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtsepi32_epi16(transmute(self)), _mm512_cvtsepi32_epi16(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512f"))]
+    fn saturating_downcast(self, other: Self) -> i16x32 {
+        fallback!();
+        i16x32::new(self.extract(0).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(1).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(2).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(3).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(4).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(5).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(6).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(7).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(8).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(9).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(10).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(11).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(12).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(13).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(14).min(0x00007FFF).max(-0x00008000) as i16,
+                    self.extract(15).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(0).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(1).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(2).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(3).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(4).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(5).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(6).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(7).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(8).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(9).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(10).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(11).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(12).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(13).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(14).min(0x00007FFF).max(-0x00008000) as i16,
+                    other.extract(15).min(0x00007FFF).max(-0x00008000) as i16)
+    }
+}
+
+impl Downcast<i32x16> for i64x8 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512f")]
+    fn saturating_downcast(self, other: Self) -> i32x16 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtsepi64_epi32(transmute(self)), _mm512_cvtsepi64_epi32(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512f"))]
+    fn saturating_downcast(self, other: Self) -> i32x16 {
+        fallback!();
+        i32x16::new(self.extract(0).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(1).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(2).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(3).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(4).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(5).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(6).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    self.extract(7).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(0).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(1).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(2).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(3).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(4).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(5).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(6).min(0x7FFFFFFF).max(-0x80000000) as i32,
+                    other.extract(7).min(0x7FFFFFFF).max(-0x80000000) as i32)
+    }
+}
+
+impl Downcast<u32x16> for u64x8 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512f")]
+    fn saturating_downcast(self, other: Self) -> u32x16 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtusepi64_epi32(transmute(self)), _mm512_cvtusepi64_epi32(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512f"))]
+    fn saturating_downcast(self, other: Self) -> u32x16 {
+        fallback!();
+        u32x16::new(self.extract(0).min(0xFFFFFFFF) as u32,
+                    self.extract(1).min(0xFFFFFFFF) as u32,
+                    self.extract(2).min(0xFFFFFFFF) as u32,
+                    self.extract(3).min(0xFFFFFFFF) as u32,
+                    self.extract(4).min(0xFFFFFFFF) as u32,
+                    self.extract(5).min(0xFFFFFFFF) as u32,
+                    self.extract(6).min(0xFFFFFFFF) as u32,
+                    self.extract(7).min(0xFFFFFFFF) as u32,
+                    other.extract(0).min(0xFFFFFFFF) as u32,
+                    other.extract(1).min(0xFFFFFFFF) as u32,
+                    other.extract(2).min(0xFFFFFFFF) as u32,
+                    other.extract(3).min(0xFFFFFFFF) as u32,
+                    other.extract(4).min(0xFFFFFFFF) as u32,
+                    other.extract(5).min(0xFFFFFFFF) as u32,
+                    other.extract(6).min(0xFFFFFFFF) as u32,
+                    other.extract(7).min(0xFFFFFFFF) as u32)
+    }
+}
+
+impl Downcast<f32x16> for f64x8 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512f")]
+    fn saturating_downcast(self, other: Self) -> f32x16 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtpd_ps(transmute(self)), _mm512_cvtpd_ps(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512f"))]
+    fn saturating_downcast(self, other: Self) -> f32x16 {
+        fallback!();
+        f32x16::new(self.extract(0) as f32,
+                    self.extract(1) as f32,
+                    self.extract(2) as f32,
+                    self.extract(3) as f32,
+                    self.extract(4) as f32,
+                    self.extract(5) as f32,
+                    self.extract(6) as f32,
+                    self.extract(7) as f32,
+                    other.extract(0) as f32,
+                    other.extract(1) as f32,
+                    other.extract(2) as f32,
+                    other.extract(3) as f32,
+                    other.extract(4) as f32,
+                    other.extract(5) as f32,
+                    other.extract(6) as f32,
+                    other.extract(7) as f32)
+    }
+}
+
+impl Downcast<i8x64> for i16x32 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512bw")]
+    fn saturating_downcast(self, other: Self) -> i8x64 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtsepi16_epi8(transmute(self)), _mm512_cvtsepi16_epi8(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512bw"))]
+    fn saturating_downcast(self, other: Self) -> i8x64 {
+        fallback!();
+        i8x64::new(self.extract(0).min(0x007F).max(-0x0080) as i8,
+                   self.extract(1).min(0x007F).max(-0x0080) as i8,
+                   self.extract(2).min(0x007F).max(-0x0080) as i8,
+                   self.extract(3).min(0x007F).max(-0x0080) as i8,
+                   self.extract(4).min(0x007F).max(-0x0080) as i8,
+                   self.extract(5).min(0x007F).max(-0x0080) as i8,
+                   self.extract(6).min(0x007F).max(-0x0080) as i8,
+                   self.extract(7).min(0x007F).max(-0x0080) as i8,
+                   self.extract(8).min(0x007F).max(-0x0080) as i8,
+                   self.extract(9).min(0x007F).max(-0x0080) as i8,
+                   self.extract(10).min(0x007F).max(-0x0080) as i8,
+                   self.extract(11).min(0x007F).max(-0x0080) as i8,
+                   self.extract(12).min(0x007F).max(-0x0080) as i8,
+                   self.extract(13).min(0x007F).max(-0x0080) as i8,
+                   self.extract(14).min(0x007F).max(-0x0080) as i8,
+                   self.extract(15).min(0x007F).max(-0x0080) as i8,
+                   self.extract(16).min(0x007F).max(-0x0080) as i8,
+                   self.extract(17).min(0x007F).max(-0x0080) as i8,
+                   self.extract(18).min(0x007F).max(-0x0080) as i8,
+                   self.extract(19).min(0x007F).max(-0x0080) as i8,
+                   self.extract(20).min(0x007F).max(-0x0080) as i8,
+                   self.extract(21).min(0x007F).max(-0x0080) as i8,
+                   self.extract(22).min(0x007F).max(-0x0080) as i8,
+                   self.extract(23).min(0x007F).max(-0x0080) as i8,
+                   self.extract(24).min(0x007F).max(-0x0080) as i8,
+                   self.extract(25).min(0x007F).max(-0x0080) as i8,
+                   self.extract(26).min(0x007F).max(-0x0080) as i8,
+                   self.extract(27).min(0x007F).max(-0x0080) as i8,
+                   self.extract(28).min(0x007F).max(-0x0080) as i8,
+                   self.extract(29).min(0x007F).max(-0x0080) as i8,
+                   self.extract(30).min(0x007F).max(-0x0080) as i8,
+                   self.extract(31).min(0x007F).max(-0x0080) as i8,
+                   other.extract(0).min(0x007F).max(-0x0080) as i8,
+                   other.extract(1).min(0x007F).max(-0x0080) as i8,
+                   other.extract(2).min(0x007F).max(-0x0080) as i8,
+                   other.extract(3).min(0x007F).max(-0x0080) as i8,
+                   other.extract(4).min(0x007F).max(-0x0080) as i8,
+                   other.extract(5).min(0x007F).max(-0x0080) as i8,
+                   other.extract(6).min(0x007F).max(-0x0080) as i8,
+                   other.extract(7).min(0x007F).max(-0x0080) as i8,
+                   other.extract(8).min(0x007F).max(-0x0080) as i8,
+                   other.extract(9).min(0x007F).max(-0x0080) as i8,
+                   other.extract(10).min(0x007F).max(-0x0080) as i8,
+                   other.extract(11).min(0x007F).max(-0x0080) as i8,
+                   other.extract(12).min(0x007F).max(-0x0080) as i8,
+                   other.extract(13).min(0x007F).max(-0x0080) as i8,
+                   other.extract(14).min(0x007F).max(-0x0080) as i8,
+                   other.extract(15).min(0x007F).max(-0x0080) as i8,
+                   other.extract(16).min(0x007F).max(-0x0080) as i8,
+                   other.extract(17).min(0x007F).max(-0x0080) as i8,
+                   other.extract(18).min(0x007F).max(-0x0080) as i8,
+                   other.extract(19).min(0x007F).max(-0x0080) as i8,
+                   other.extract(20).min(0x007F).max(-0x0080) as i8,
+                   other.extract(21).min(0x007F).max(-0x0080) as i8,
+                   other.extract(22).min(0x007F).max(-0x0080) as i8,
+                   other.extract(23).min(0x007F).max(-0x0080) as i8,
+                   other.extract(24).min(0x007F).max(-0x0080) as i8,
+                   other.extract(25).min(0x007F).max(-0x0080) as i8,
+                   other.extract(26).min(0x007F).max(-0x0080) as i8,
+                   other.extract(27).min(0x007F).max(-0x0080) as i8,
+                   other.extract(28).min(0x007F).max(-0x0080) as i8,
+                   other.extract(29).min(0x007F).max(-0x0080) as i8,
+                   other.extract(30).min(0x007F).max(-0x0080) as i8,
+                   other.extract(31).min(0x007F).max(-0x0080) as i8)
+    }
+}
+
+impl Downcast<u16x32> for u32x16 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512f")]
+    fn saturating_downcast(self, other: Self) -> u16x32 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtusepi32_epi16(transmute(self)), _mm512_cvtusepi32_epi16(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512f"))]
+    fn saturating_downcast(self, other: Self) -> u16x32 {
+        fallback!();
+        u16x32::new(self.extract(0).min(0x0000FFFF) as u16,
+                    self.extract(1).min(0x0000FFFF) as u16,
+                    self.extract(2).min(0x0000FFFF) as u16,
+                    self.extract(3).min(0x0000FFFF) as u16,
+                    self.extract(4).min(0x0000FFFF) as u16,
+                    self.extract(5).min(0x0000FFFF) as u16,
+                    self.extract(6).min(0x0000FFFF) as u16,
+                    self.extract(7).min(0x0000FFFF) as u16,
+                    self.extract(8).min(0x0000FFFF) as u16,
+                    self.extract(9).min(0x0000FFFF) as u16,
+                    self.extract(10).min(0x0000FFFF) as u16,
+                    self.extract(11).min(0x0000FFFF) as u16,
+                    self.extract(12).min(0x0000FFFF) as u16,
+                    self.extract(13).min(0x0000FFFF) as u16,
+                    self.extract(14).min(0x0000FFFF) as u16,
+                    self.extract(15).min(0x0000FFFF) as u16,
+                    other.extract(0).min(0x0000FFFF) as u16,
+                    other.extract(1).min(0x0000FFFF) as u16,
+                    other.extract(2).min(0x0000FFFF) as u16,
+                    other.extract(3).min(0x0000FFFF) as u16,
+                    other.extract(4).min(0x0000FFFF) as u16,
+                    other.extract(5).min(0x0000FFFF) as u16,
+                    other.extract(6).min(0x0000FFFF) as u16,
+                    other.extract(7).min(0x0000FFFF) as u16,
+                    other.extract(8).min(0x0000FFFF) as u16,
+                    other.extract(9).min(0x0000FFFF) as u16,
+                    other.extract(10).min(0x0000FFFF) as u16,
+                    other.extract(11).min(0x0000FFFF) as u16,
+                    other.extract(12).min(0x0000FFFF) as u16,
+                    other.extract(13).min(0x0000FFFF) as u16,
+                    other.extract(14).min(0x0000FFFF) as u16,
+                    other.extract(15).min(0x0000FFFF) as u16)
+    }
+}
+
+impl Downcast<u8x64> for u16x32 {
+    #[inline(always)]
+    #[cfg(target_feature = "avx512bw")]
+    fn saturating_downcast(self, other: Self) -> u8x64 {
+        optimized!();
+        unsafe { transmute([_mm512_cvtusepi16_epi8(transmute(self)), _mm512_cvtusepi16_epi8(transmute(other))]) }
+    }
+
+    #[inline(always)]
+    #[cfg(not(target_feature = "avx512bw"))]
+    fn saturating_downcast(self, other: Self) -> u8x64 {
+        fallback!();
+        u8x64::new(self.extract(0).min(0x00FF) as u8,
+                   self.extract(1).min(0x00FF) as u8,
+                   self.extract(2).min(0x00FF) as u8,
+                   self.extract(3).min(0x00FF) as u8,
+                   self.extract(4).min(0x00FF) as u8,
+                   self.extract(5).min(0x00FF) as u8,
+                   self.extract(6).min(0x00FF) as u8,
+                   self.extract(7).min(0x00FF) as u8,
+                   self.extract(8).min(0x00FF) as u8,
+                   self.extract(9).min(0x00FF) as u8,
+                   self.extract(10).min(0x00FF) as u8,
+                   self.extract(11).min(0x00FF) as u8,
+                   self.extract(12).min(0x00FF) as u8,
+                   self.extract(13).min(0x00FF) as u8,
+                   self.extract(14).min(0x00FF) as u8,
+                   self.extract(15).min(0x00FF) as u8,
+                   self.extract(16).min(0x00FF) as u8,
+                   self.extract(17).min(0x00FF) as u8,
+                   self.extract(18).min(0x00FF) as u8,
+                   self.extract(19).min(0x00FF) as u8,
+                   self.extract(20).min(0x00FF) as u8,
+                   self.extract(21).min(0x00FF) as u8,
+                   self.extract(22).min(0x00FF) as u8,
+                   self.extract(23).min(0x00FF) as u8,
+                   self.extract(24).min(0x00FF) as u8,
+                   self.extract(25).min(0x00FF) as u8,
+                   self.extract(26).min(0x00FF) as u8,
+                   self.extract(27).min(0x00FF) as u8,
+                   self.extract(28).min(0x00FF) as u8,
+                   self.extract(29).min(0x00FF) as u8,
+                   self.extract(30).min(0x00FF) as u8,
+                   self.extract(31).min(0x00FF) as u8,
+                   other.extract(0).min(0x00FF) as u8,
+                   other.extract(1).min(0x00FF) as u8,
+                   other.extract(2).min(0x00FF) as u8,
+                   other.extract(3).min(0x00FF) as u8,
+                   other.extract(4).min(0x00FF) as u8,
+                   other.extract(5).min(0x00FF) as u8,
+                   other.extract(6).min(0x00FF) as u8,
+                   other.extract(7).min(0x00FF) as u8,
+                   other.extract(8).min(0x00FF) as u8,
+                   other.extract(9).min(0x00FF) as u8,
+                   other.extract(10).min(0x00FF) as u8,
+                   other.extract(11).min(0x00FF) as u8,
+                   other.extract(12).min(0x00FF) as u8,
+                   other.extract(13).min(0x00FF) as u8,
+                   other.extract(14).min(0x00FF) as u8,
+                   other.extract(15).min(0x00FF) as u8,
+                   other.extract(16).min(0x00FF) as u8,
+                   other.extract(17).min(0x00FF) as u8,
+                   other.extract(18).min(0x00FF) as u8,
+                   other.extract(19).min(0x00FF) as u8,
+                   other.extract(20).min(0x00FF) as u8,
+                   other.extract(21).min(0x00FF) as u8,
+                   other.extract(22).min(0x00FF) as u8,
+                   other.extract(23).min(0x00FF) as u8,
+                   other.extract(24).min(0x00FF) as u8,
+                   other.extract(25).min(0x00FF) as u8,
+                   other.extract(26).min(0x00FF) as u8,
+                   other.extract(27).min(0x00FF) as u8,
+                   other.extract(28).min(0x00FF) as u8,
+                   other.extract(29).min(0x00FF) as u8,
+                   other.extract(30).min(0x00FF) as u8,
+                   other.extract(31).min(0x00FF) as u8)
+    }
+}
@@ -0,0 +1,66 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::core::arch::x86_64::*;
+use crate::arch::current::vecs::*;
+use crate::arch::current::masks::*;
+use crate::intrin::gather::*;
+use crate::masks::*;
+use crate::vecs::*;
+
+impl_gather!(u32x4, u32x4, m32x4, 4, "avx2", _mm_i32gather_epi32, _mm_mask_i32gather_epi32);
+impl_gather!(i32x4, u32x4, m32x4, 4, "avx2", _mm_i32gather_epi32, _mm_mask_i32gather_epi32);
+impl_gather!(f32x4, u32x4, m32x4, 4, "avx2", _mm_i32gather_ps, _mm_mask_i32gather_ps);
+impl_gather!(u64x2, u64x2, m64x2, 8, "avx2", _mm_i64gather_epi64, _mm_mask_i64gather_epi64);
+impl_gather!(i64x2, u64x2, m64x2, 8, "avx2", _mm_i64gather_epi64, _mm_mask_i64gather_epi64);
+impl_gather!(f64x2, u64x2, m64x2, 8, "avx2", _mm_i64gather_pd, _mm_mask_i64gather_pd);
+
+impl_gather!(u32x8, u32x8, m32x8, 4, "avx2", _mm256_i32gather_epi32, _mm256_mask_i32gather_epi32);
+impl_gather!(i32x8, u32x8, m32x8, 4, "avx2", _mm256_i32gather_epi32, _mm256_mask_i32gather_epi32);
+impl_gather!(f32x8, u32x8, m32x8, 4, "avx2", _mm256_i32gather_ps, _mm256_mask_i32gather_ps);
+impl_gather!(u64x4, u64x4, m64x4, 8, "avx2", _mm256_i64gather_epi64, _mm256_mask_i64gather_epi64);
+impl_gather!(i64x4, u64x4, m64x4, 8, "avx2", _mm256_i64gather_epi64, _mm256_mask_i64gather_epi64);
+impl_gather!(f64x4, u64x4, m64x4, 8, "avx2", _mm256_i64gather_pd, _mm256_mask_i64gather_pd);
+
+impl_gather_512!(u32x16, u32x16, m32x16, 4, "avx512f", _mm512_i32gather_epi32, _mm512_mask_i32gather_epi32);
+impl_gather_512!(i32x16, u32x16, m32x16, 4, "avx512f", _mm512_i32gather_epi32, _mm512_mask_i32gather_epi32);
+impl_gather_512!(f32x16, u32x16, m32x16, 4, "avx512f", _mm512_i32gather_ps, _mm512_mask_i32gather_ps);
+impl_gather_512!(u64x8, u64x8, m64x8, 8, "avx512f", _mm512_i64gather_epi64, _mm512_mask_i64gather_epi64);
+impl_gather_512!(i64x8, u64x8, m64x8, 8, "avx512f", _mm512_i64gather_epi64, _mm512_mask_i64gather_epi64);
+impl_gather_512!(f64x8, u64x8, m64x8, 8, "avx512f", _mm512_i64gather_pd, _mm512_mask_i64gather_pd);
+
+// Scatters of 128- and 256-bit vectors need AVX-512VL, which implies AVX-512F.
+impl_scatter!(u32x4, 4, "avx512vl", _mm_i32scatter_epi32, _mm_mask_i32scatter_epi32);
+impl_scatter!(i32x4, 4, "avx512vl", _mm_i32scatter_epi32, _mm_mask_i32scatter_epi32);
+impl_scatter!(f32x4, 4, "avx512vl", _mm_i32scatter_ps, _mm_mask_i32scatter_ps);
+impl_scatter!(u64x2, 8, "avx512vl", _mm_i64scatter_epi64, _mm_mask_i64scatter_epi64);
+impl_scatter!(i64x2, 8, "avx512vl", _mm_i64scatter_epi64, _mm_mask_i64scatter_epi64);
+impl_scatter!(f64x2, 8, "avx512vl", _mm_i64scatter_pd, _mm_mask_i64scatter_pd);
+
+impl_scatter!(u32x8, 4, "avx512vl", _mm256_i32scatter_epi32, _mm256_mask_i32scatter_epi32);
+impl_scatter!(i32x8, 4, "avx512vl", _mm256_i32scatter_epi32, _mm256_mask_i32scatter_epi32);
+impl_scatter!(f32x8, 4, "avx512vl", _mm256_i32scatter_ps, _mm256_mask_i32scatter_ps);
+impl_scatter!(u64x4, 8, "avx512vl", _mm256_i64scatter_epi64, _mm256_mask_i64scatter_epi64);
+impl_scatter!(i64x4, 8, "avx512vl", _mm256_i64scatter_epi64, _mm256_mask_i64scatter_epi64);
+impl_scatter!(f64x4, 8, "avx512vl", _mm256_i64scatter_pd, _mm256_mask_i64scatter_pd);
+
+impl_scatter!(u32x16, 4, "avx512f", _mm512_i32scatter_epi32, _mm512_mask_i32scatter_epi32);
+impl_scatter!(i32x16, 4, "avx512f", _mm512_i32scatter_epi32, _mm512_mask_i32scatter_epi32);
+impl_scatter!(f32x16, 4, "avx512f", _mm512_i32scatter_ps, _mm512_mask_i32scatter_ps);
+impl_scatter!(u64x8, 8, "avx512f", _mm512_i64scatter_epi64, _mm512_mask_i64scatter_epi64);
+impl_scatter!(i64x8, 8, "avx512f", _mm512_i64scatter_epi64, _mm512_mask_i64scatter_epi64);
+impl_scatter!(f64x8, 8, "avx512f", _mm512_i64scatter_pd, _mm512_mask_i64scatter_pd);
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_gather!(
+        (u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (u32x4, u32x4, u32x4, u64x2, u64x2, u64x2, u32x8, u32x8, u32x8, u64x4, u64x4, u64x4, u32x16, u32x16, u32x16, u64x8, u64x8, u64x8),
+        (gather_u32x4, gather_i32x4, gather_f32x4, gather_u64x2, gather_i64x2, gather_f64x2, gather_u32x8, gather_i32x8, gather_f32x8, gather_u64x4, gather_i64x4, gather_f64x4, gather_u32x16, gather_i32x16, gather_f32x16, gather_u64x8, gather_i64x8, gather_f64x8));
+}
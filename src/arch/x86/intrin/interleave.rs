@@ -0,0 +1,124 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::core::arch::x86_64::*;
+use crate::core::mem::transmute;
+use crate::intrin::interleave::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+
+macro_rules! impl_interleave {
+    // The 512-bit arm matches its permute by name, so it must come first
+    ($vec:tt, $feat:expr, $lo:ident, $hi:ident, _mm512_permutex2var_epi64, $half:expr, $($n:expr),*) => {
+        impl Interleave for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                optimized!();
+                unsafe {
+                    // The unpacks interleave each 128-bit lane separately
+                    let lo = transmute($lo(transmute(self), transmute(other)));
+                    let hi = transmute($hi(transmute(self), transmute(other)));
+                    (transmute(_mm512_permutex2var_epi64(lo, transmute(u64x8::new(0, 1, 8, 9, 2, 3, 10, 11)), hi)),
+                     transmute(_mm512_permutex2var_epi64(lo, transmute(u64x8::new(4, 5, 12, 13, 6, 7, 14, 15)), hi)))
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                fallback!();
+                interleave_two_polyfill!(self, other, $half, $($n),*)
+            }
+        }
+    };
+    // The 256-bit arm must come first, as $perm would match $half
+    ($vec:tt, $feat:expr, $lo:ident, $hi:ident, $perm:ident, $half:expr, $($n:expr),*) => {
+        impl Interleave for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                optimized!();
+                unsafe {
+                    // The unpacks interleave each 128-bit lane separately
+                    let lo = $lo(transmute(self), transmute(other));
+                    let hi = $hi(transmute(self), transmute(other));
+                    (transmute($perm(lo, hi, 0x20)), transmute($perm(lo, hi, 0x31)))
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                fallback!();
+                interleave_two_polyfill!(self, other, $half, $($n),*)
+            }
+        }
+    };
+    ($vec:tt, $feat:expr, $lo:ident, $hi:ident, $half:expr, $($n:expr),*) => {
+        impl Interleave for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                optimized!();
+                unsafe {
+                    (transmute($lo(transmute(self), transmute(other))),
+                     transmute($hi(transmute(self), transmute(other))))
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                fallback!();
+                interleave_two_polyfill!(self, other, $half, $($n),*)
+            }
+        }
+    };
+}
+
+impl_interleave!(u8x16, "sse2", _mm_unpacklo_epi8, _mm_unpackhi_epi8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(i8x16, "sse2", _mm_unpacklo_epi8, _mm_unpackhi_epi8, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(u16x8, "sse2", _mm_unpacklo_epi16, _mm_unpackhi_epi16, 4, 0, 1, 2, 3);
+impl_interleave!(i16x8, "sse2", _mm_unpacklo_epi16, _mm_unpackhi_epi16, 4, 0, 1, 2, 3);
+impl_interleave!(u32x4, "sse2", _mm_unpacklo_epi32, _mm_unpackhi_epi32, 2, 0, 1);
+impl_interleave!(i32x4, "sse2", _mm_unpacklo_epi32, _mm_unpackhi_epi32, 2, 0, 1);
+impl_interleave!(f32x4, "sse", _mm_unpacklo_ps, _mm_unpackhi_ps, 2, 0, 1);
+impl_interleave!(u64x2, "sse2", _mm_unpacklo_epi64, _mm_unpackhi_epi64, 1, 0);
+impl_interleave!(i64x2, "sse2", _mm_unpacklo_epi64, _mm_unpackhi_epi64, 1, 0);
+impl_interleave!(f64x2, "sse2", _mm_unpacklo_pd, _mm_unpackhi_pd, 1, 0);
+
+impl_interleave!(u8x32, "avx2", _mm256_unpacklo_epi8, _mm256_unpackhi_epi8, _mm256_permute2x128_si256, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave!(i8x32, "avx2", _mm256_unpacklo_epi8, _mm256_unpackhi_epi8, _mm256_permute2x128_si256, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave!(u16x16, "avx2", _mm256_unpacklo_epi16, _mm256_unpackhi_epi16, _mm256_permute2x128_si256, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(i16x16, "avx2", _mm256_unpacklo_epi16, _mm256_unpackhi_epi16, _mm256_permute2x128_si256, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(u32x8, "avx2", _mm256_unpacklo_epi32, _mm256_unpackhi_epi32, _mm256_permute2x128_si256, 4, 0, 1, 2, 3);
+impl_interleave!(i32x8, "avx2", _mm256_unpacklo_epi32, _mm256_unpackhi_epi32, _mm256_permute2x128_si256, 4, 0, 1, 2, 3);
+impl_interleave!(f32x8, "avx", _mm256_unpacklo_ps, _mm256_unpackhi_ps, _mm256_permute2f128_ps, 4, 0, 1, 2, 3);
+impl_interleave!(u64x4, "avx2", _mm256_unpacklo_epi64, _mm256_unpackhi_epi64, _mm256_permute2x128_si256, 2, 0, 1);
+impl_interleave!(i64x4, "avx2", _mm256_unpacklo_epi64, _mm256_unpackhi_epi64, _mm256_permute2x128_si256, 2, 0, 1);
+impl_interleave!(f64x4, "avx", _mm256_unpacklo_pd, _mm256_unpackhi_pd, _mm256_permute2f128_pd, 2, 0, 1);
+
+impl_interleave!(u8x64, "avx512bw", _mm512_unpacklo_epi8, _mm512_unpackhi_epi8, _mm512_permutex2var_epi64, 32, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+impl_interleave!(i8x64, "avx512bw", _mm512_unpacklo_epi8, _mm512_unpackhi_epi8, _mm512_permutex2var_epi64, 32, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+impl_interleave!(u16x32, "avx512bw", _mm512_unpacklo_epi16, _mm512_unpackhi_epi16, _mm512_permutex2var_epi64, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave!(i16x32, "avx512bw", _mm512_unpacklo_epi16, _mm512_unpackhi_epi16, _mm512_permutex2var_epi64, 16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+impl_interleave!(u32x16, "avx512f", _mm512_unpacklo_epi32, _mm512_unpackhi_epi32, _mm512_permutex2var_epi64, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(i32x16, "avx512f", _mm512_unpacklo_epi32, _mm512_unpackhi_epi32, _mm512_permutex2var_epi64, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(f32x16, "avx512f", _mm512_unpacklo_ps, _mm512_unpackhi_ps, _mm512_permutex2var_epi64, 8, 0, 1, 2, 3, 4, 5, 6, 7);
+impl_interleave!(u64x8, "avx512f", _mm512_unpacklo_epi64, _mm512_unpackhi_epi64, _mm512_permutex2var_epi64, 4, 0, 1, 2, 3);
+impl_interleave!(i64x8, "avx512f", _mm512_unpacklo_epi64, _mm512_unpackhi_epi64, _mm512_permutex2var_epi64, 4, 0, 1, 2, 3);
+impl_interleave!(f64x8, "avx512f", _mm512_unpacklo_pd, _mm512_unpackhi_pd, _mm512_permutex2var_epi64, 4, 0, 1, 2, 3);
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_interleave!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (interleave_u8x16, interleave_i8x16, interleave_u16x8, interleave_i16x8, interleave_u32x4, interleave_i32x4, interleave_f32x4, interleave_u64x2, interleave_i64x2, interleave_f64x2, interleave_u8x32, interleave_i8x32, interleave_u16x16, interleave_i16x16, interleave_u32x8, interleave_i32x8, interleave_f32x8, interleave_u64x4, interleave_i64x4, interleave_f64x4, interleave_u8x64, interleave_i8x64, interleave_u16x32, interleave_i16x32, interleave_u32x16, interleave_i32x16, interleave_f32x16, interleave_u64x8, interleave_i64x8, interleave_f64x8));
+}
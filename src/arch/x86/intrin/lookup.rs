@@ -0,0 +1,79 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::intrin::lookup::*;
+use crate::intrin::transmute::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+use crate::core::mem::transmute;
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn lookup16_128(v: u8x16, table: &[u8]) -> u8x16 {
+    let table = u8x16::from_slice_unaligned_unchecked(table);
+    _mm_shuffle_epi8(table.be_i8s(), (v & 0x0f).be_i8s()).be_u8s()
+}
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn lookup_bytes128(v: u8x16, table: &[u8]) -> u8x16 {
+    // Look each index up in every 16-entry chunk of the table, and keep the
+    // result from the chunk which the high nibble of the index selects.
+    let hi = v & 0xf0;
+    let lo = (v & 0x0f).be_i8s();
+    let mut ret = u8x16::splat(0);
+    for (k, chunk) in table.chunks(16).enumerate() {
+        let chunk = u8x16::from_slice_unaligned_unchecked(chunk);
+        let hit = _mm_cmpeq_epi8(hi.be_i8s(), u8x16::splat((k << 4) as u8).be_i8s()).be_u8s();
+        ret |= hit & _mm_shuffle_epi8(chunk.be_i8s(), lo).be_u8s();
+    }
+    ret
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn lookup16_256(v: u8x32, table: &[u8]) -> u8x32 {
+    // vpshufb looks up each 128-bit lane separately, so give each lane a copy
+    let table = u8x16::from_slice_unaligned_unchecked(table);
+    let table: u8x32 = transmute([table, table]);
+    _mm256_shuffle_epi8(table.be_i8s(), (v & 0x0f).be_i8s()).be_u8s()
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn lookup_bytes256(v: u8x32, table: &[u8]) -> u8x32 {
+    let hi = v & 0xf0;
+    let lo = (v & 0x0f).be_i8s();
+    let mut ret = u8x32::splat(0);
+    for (k, chunk) in table.chunks(16).enumerate() {
+        let chunk = u8x16::from_slice_unaligned_unchecked(chunk);
+        let chunk: u8x32 = transmute([chunk, chunk]);
+        let hit = _mm256_cmpeq_epi8(hi.be_i8s(), u8x32::splat((k << 4) as u8).be_i8s()).be_u8s();
+        ret |= hit & _mm256_shuffle_epi8(chunk.be_i8s(), lo).be_u8s();
+    }
+    ret
+}
+
+impl_lookup!(u8x16, u8, "ssse3", (lookup16_128, lookup_bytes128));
+impl_lookup!(i8x16, i8, "ssse3", (lookup16_128, lookup_bytes128));
+impl_lookup!(u8x32, u8, "avx2", (lookup16_256, lookup_bytes256));
+impl_lookup!(i8x32, i8, "avx2", (lookup16_256, lookup_bytes256));
+// TODO: AVX-512VBMI has vpermb and vpermi2b, which look up 64 and 128 entries
+// at a time.
+impl_lookup!(u8x64, u8, "avx512-butnotyet", (__undefined, __undefined));
+impl_lookup!(i8x64, i8, "avx512-butnotyet", (__undefined, __undefined));
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_lookup!((u8x16, i8x16, u8x32, i8x32, u8x64, i8x64),
+                 (u8, i8, u8, i8, u8, i8),
+                 (lookup_u8x16, lookup_i8x16, lookup_u8x32, lookup_i8x32, lookup_u8x64, lookup_i8x64));
+}
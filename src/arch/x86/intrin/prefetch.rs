@@ -0,0 +1,24 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0, _MM_HINT_T1, _MM_HINT_T2, _MM_HINT_NTA};
+use crate::intrin::prefetch::*;
+
+/// Hint that the cache line containing `ptr` will be read soon. This never
+/// faults, so `ptr` may be invalid.
+#[inline(always)]
+pub fn prefetch<T>(ptr: *const T, locality: Locality) {
+    optimized!();
+    unsafe {
+        match locality {
+            Locality::L1 => _mm_prefetch(ptr as *const i8, _MM_HINT_T0),
+            Locality::L2 => _mm_prefetch(ptr as *const i8, _MM_HINT_T1),
+            Locality::L3 => _mm_prefetch(ptr as *const i8, _MM_HINT_T2),
+            Locality::NonTemporal => _mm_prefetch(ptr as *const i8, _MM_HINT_NTA),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::arch::current::vecs::*;
+use crate::intrin::prefix_sum::*;
+use crate::vecs::*;
+use crate::core::mem::transmute;
+use crate::core::arch::x86_64 as core_arch;
+
+// Shift the whole vector up by `n` bytes, shifting in zeroes. These are
+// macros because the shift amount must be a constant.
+macro_rules! mm_slli_bytes {
+    ($a:expr, $n:expr) => (transmute(_mm_slli_si128(transmute($a), $n)))
+}
+
+// Unlike `_mm256_slli_si256`, this shifts bytes across the 128-bit lanes, by
+// lining the vector up against a copy of its low half moved into its high
+// half. `n` must not exceed 16.
+macro_rules! mm256_slli_bytes {
+    ($a:expr, $n:expr) => ({
+        let a: i8x32 = transmute($a);
+        let lo: i8x32 = transmute(_mm256_permute2x128_si256(transmute(a), transmute(a), 0x08));
+        transmute(_mm256_alignr_epi8(a, lo, 16 - $n))
+    })
+}
+
+impl_prefix_sum!(u8x16, "sse2", mm_slli_bytes, 1, [1, 2, 4, 8]);
+impl_prefix_sum!(i8x16, "sse2", mm_slli_bytes, 1, [1, 2, 4, 8]);
+impl_prefix_sum!(u16x8, "sse2", mm_slli_bytes, 2, [2, 4, 8]);
+impl_prefix_sum!(i16x8, "sse2", mm_slli_bytes, 2, [2, 4, 8]);
+impl_prefix_sum!(u32x4, "sse2", mm_slli_bytes, 4, [4, 8]);
+impl_prefix_sum!(i32x4, "sse2", mm_slli_bytes, 4, [4, 8]);
+impl_prefix_sum!(f32x4, "sse2", mm_slli_bytes, 4, [4, 8]);
+impl_prefix_sum!(u64x2, "sse2", mm_slli_bytes, 8, [8]);
+impl_prefix_sum!(i64x2, "sse2", mm_slli_bytes, 8, [8]);
+impl_prefix_sum!(f64x2, "sse2", mm_slli_bytes, 8, [8]);
+
+// AVX-512 can't shift bytes across 128-bit lanes either, but it can shift
+// dwords across the whole vector. `n` must be a multiple of 4.
+macro_rules! mm512_slli_dwords {
+    ($a:expr, $n:expr) => (transmute(core_arch::_mm512_alignr_epi32(transmute($a), core_arch::_mm512_setzero_si512(), 16 - $n / 4)))
+}
+
+// Shifts of bytes line each lane up against its lower neighbour, like
+// `mm256_slli_bytes`. `n` must not exceed 16, or must be 32.
+macro_rules! mm512_slli_bytes {
+    ($a:expr, $n:expr) => ({
+        let a: core_arch::__m512i = transmute($a);
+        let zero = core_arch::_mm512_setzero_si512();
+        if $n == 32 {
+            transmute(core_arch::_mm512_alignr_epi32(a, zero, 8))
+        } else {
+            let lo = core_arch::_mm512_alignr_epi32(a, zero, 12);
+            transmute(core_arch::_mm512_alignr_epi8(a, lo, 16 - $n % 32))
+        }
+    })
+}
+
+impl_prefix_sum!(u8x32, "avx2", mm256_slli_bytes, 1, [1, 2, 4, 8, 16]);
+impl_prefix_sum!(i8x32, "avx2", mm256_slli_bytes, 1, [1, 2, 4, 8, 16]);
+impl_prefix_sum!(u16x16, "avx2", mm256_slli_bytes, 2, [2, 4, 8, 16]);
+impl_prefix_sum!(i16x16, "avx2", mm256_slli_bytes, 2, [2, 4, 8, 16]);
+impl_prefix_sum!(u32x8, "avx2", mm256_slli_bytes, 4, [4, 8, 16]);
+impl_prefix_sum!(i32x8, "avx2", mm256_slli_bytes, 4, [4, 8, 16]);
+impl_prefix_sum!(f32x8, "avx2", mm256_slli_bytes, 4, [4, 8, 16]);
+impl_prefix_sum!(u64x4, "avx2", mm256_slli_bytes, 8, [8, 16]);
+impl_prefix_sum!(i64x4, "avx2", mm256_slli_bytes, 8, [8, 16]);
+impl_prefix_sum!(f64x4, "avx2", mm256_slli_bytes, 8, [8, 16]);
+
+impl_prefix_sum!(u8x64, "avx512bw", mm512_slli_bytes, 1, [1, 2, 4, 8, 16, 32]);
+impl_prefix_sum!(i8x64, "avx512bw", mm512_slli_bytes, 1, [1, 2, 4, 8, 16, 32]);
+impl_prefix_sum!(u16x32, "avx512bw", mm512_slli_bytes, 2, [2, 4, 8, 16, 32]);
+impl_prefix_sum!(i16x32, "avx512bw", mm512_slli_bytes, 2, [2, 4, 8, 16, 32]);
+impl_prefix_sum!(u32x16, "avx512f", mm512_slli_dwords, 4, [4, 8, 16, 32]);
+impl_prefix_sum!(i32x16, "avx512f", mm512_slli_dwords, 4, [4, 8, 16, 32]);
+impl_prefix_sum!(f32x16, "avx512f", mm512_slli_dwords, 4, [4, 8, 16, 32]);
+impl_prefix_sum!(u64x8, "avx512f", mm512_slli_dwords, 8, [8, 16, 32]);
+impl_prefix_sum!(i64x8, "avx512f", mm512_slli_dwords, 8, [8, 16, 32]);
+impl_prefix_sum!(f64x8, "avx512f", mm512_slli_dwords, 8, [8, 16, 32]);
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_prefix_sum!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (prefix_sum_u8x16, prefix_sum_i8x16, prefix_sum_u16x8, prefix_sum_i16x8, prefix_sum_u32x4, prefix_sum_i32x4, prefix_sum_f32x4, prefix_sum_u64x2, prefix_sum_i64x2, prefix_sum_f64x2, prefix_sum_u8x32, prefix_sum_i8x32, prefix_sum_u16x16, prefix_sum_i16x16, prefix_sum_u32x8, prefix_sum_i32x8, prefix_sum_f32x8, prefix_sum_u64x4, prefix_sum_i64x4, prefix_sum_f64x4, prefix_sum_u8x64, prefix_sum_i8x64, prefix_sum_u16x32, prefix_sum_i16x32, prefix_sum_u32x16, prefix_sum_i32x16, prefix_sum_f32x16, prefix_sum_u64x8, prefix_sum_i64x8, prefix_sum_f64x8));
+}
@@ -7,6 +7,7 @@
 
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::core::arch::x86_64::_mm512_rcp14_ps;
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
 use crate::intrin::recip::Recip;
@@ -23,3 +24,8 @@ rust_fallback_impl! {
     }
 }
 
+rust_fallback_impl! {
+    impl Recip for f32x16 where "avx512f" {
+        recip => _mm512_rcp14_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
@@ -9,6 +9,8 @@ use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
 use crate::intrin::round::Round;
 use crate::core::arch::x86_64::{_MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TRUNC};
+use crate::core::arch::x86_64::{_MM_FROUND_TO_NEG_INF, _MM_FROUND_TO_POS_INF};
+use crate::core::arch::x86_64::{__m512, __m512d, _mm512_roundscale_ps, _mm512_roundscale_pd};
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
 
@@ -47,3 +49,42 @@ rust_fallback_impl! {
         trunc => _mm256_round_pd(_MM_FROUND_TRUNC), [0, 1, 2, 3];
     }
 }
+
+// AVX-512 folds every rounding mode into `vrndscale`, whose mode is a const
+// generic; these give each mode a name the fallback macro can call.
+macro_rules! roundscale {
+    ($name:ident, $intr:ident, $vec:ty, $mode:expr) => {
+        #[inline(always)]
+        #[cfg(target_feature = "avx512f")]
+        unsafe fn $name(a: $vec) -> $vec {
+            $intr::<{ $mode }>(a)
+        }
+    }
+}
+
+roundscale!(_mm512_round_ps, _mm512_roundscale_ps, __m512, _MM_FROUND_TO_NEAREST_INT);
+roundscale!(_mm512_ceil_ps, _mm512_roundscale_ps, __m512, _MM_FROUND_TO_POS_INF);
+roundscale!(_mm512_floor_ps, _mm512_roundscale_ps, __m512, _MM_FROUND_TO_NEG_INF);
+roundscale!(_mm512_trunc_ps, _mm512_roundscale_ps, __m512, _MM_FROUND_TRUNC);
+roundscale!(_mm512_round_pd, _mm512_roundscale_pd, __m512d, _MM_FROUND_TO_NEAREST_INT);
+roundscale!(_mm512_ceil_pd, _mm512_roundscale_pd, __m512d, _MM_FROUND_TO_POS_INF);
+roundscale!(_mm512_floor_pd, _mm512_roundscale_pd, __m512d, _MM_FROUND_TO_NEG_INF);
+roundscale!(_mm512_trunc_pd, _mm512_roundscale_pd, __m512d, _MM_FROUND_TRUNC);
+
+rust_fallback_impl! {
+    impl Round for f32x16 where "avx512f" {
+        round => _mm512_round_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        ceil => _mm512_ceil_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        floor => _mm512_floor_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        trunc => _mm512_trunc_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl! {
+    impl Round for f64x8 where "avx512f" {
+        round => _mm512_round_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+        ceil => _mm512_ceil_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+        floor => _mm512_floor_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+        trunc => _mm512_trunc_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
@@ -7,6 +7,7 @@
 
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::core::arch::x86_64::_mm512_rsqrt14_ps;
 use crate::intrin::rsqrt::*;
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
@@ -25,6 +26,12 @@ rust_fallback_impl! {
     }
 }
 
+rust_fallback_impl! {
+    impl Rsqrt for f32x16 where "avx512f" {
+        rsqrt => _mm512_rsqrt14_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
 impl Rsqrt for f32 {
     #[inline(always)]
     fn rsqrt(&self) -> Self {
@@ -7,6 +7,7 @@
 
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::core::arch::x86_64::{_mm512_subs_epu8, _mm512_subs_epi8, _mm512_subs_epu16, _mm512_subs_epi16};
 use crate::intrin::saturating_sub::*;
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
@@ -60,3 +61,39 @@ rust_fallback_impl_binary! {
         saturating_sub => _mm256_subs_epi16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
     }
 }
+
+rust_fallback_impl_binary! {
+    impl SaturatingSub for u8x64 where "avx512bw" {
+        saturating_sub => _mm512_subs_epu8(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+                                               16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+                                               29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41,
+                                               42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+                                               55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingSub for i8x64 where "avx512bw" {
+        saturating_sub => _mm512_subs_epi8(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+                                               16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+                                               29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41,
+                                               42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+                                               55, 56, 57, 58, 59, 60, 61, 62, 63];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingSub for u16x32 where "avx512bw" {
+        saturating_sub => _mm512_subs_epu16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+                                                15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+                                                28, 29, 30, 31];
+    }
+}
+
+rust_fallback_impl_binary! {
+    impl SaturatingSub for i16x32 where "avx512bw" {
+        saturating_sub => _mm512_subs_epi16(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+                                                15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+                                                28, 29, 30, 31];
+    }
+}
@@ -0,0 +1,69 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::arch::current::vecs::*;
+use crate::arch::current::masks::*;
+use crate::intrin::select::*;
+use crate::masks::*;
+use crate::vecs::*;
+use crate::core::mem::transmute;
+
+impl_select!(m8x16, u8x16, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+impl_select!(m8x16, i8x16, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+
+impl_select!(m16x8, u16x8, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+impl_select!(m16x8, i16x8, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+
+impl_select!(m32x4, u32x4, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+impl_select!(m32x4, i32x4, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+impl_select!(m32x4, f32x4, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+
+impl_select!(m64x2, u64x2, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+impl_select!(m64x2, i64x2, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+impl_select!(m64x2, f64x2, "sse4.1", _mm_blendv_epi8, "sse2", (_mm_and_si128, _mm_andnot_si128, _mm_or_si128));
+
+impl_select!(m8x32, u8x32, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+impl_select!(m8x32, i8x32, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+
+impl_select!(m16x16, u16x16, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+impl_select!(m16x16, i16x16, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+
+impl_select!(m32x8, u32x8, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+impl_select!(m32x8, i32x8, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+impl_select!(m32x8, f32x8, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+
+impl_select!(m64x4, u64x4, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+impl_select!(m64x4, i64x4, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+impl_select!(m64x4, f64x4, "avx2", _mm256_blendv_epi8, "avx", (_mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps));
+
+// AVX-512 blends take a bitmask rather than a vector mask, so these use the polyfill.
+impl_select!(m8x64, u8x64, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+impl_select!(m8x64, i8x64, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+
+impl_select!(m16x32, u16x32, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+impl_select!(m16x32, i16x32, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+
+impl_select!(m32x16, u32x16, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+impl_select!(m32x16, i32x16, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+impl_select!(m32x16, f32x16, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+
+impl_select!(m64x8, u64x8, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+impl_select!(m64x8, i64x8, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+impl_select!(m64x8, f64x8, "avx512-butnotyet", _mm512_mask_mov_epi8, "avx512-butnotyet", (_mm512_and_si512, _mm512_andnot_si512, _mm512_or_si512));
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+    use crate::arch::current::masks::*;
+
+    test_select!(
+        (m8x16, m8x16, m16x8, m16x8, m32x4, m32x4, m32x4, m64x2, m64x2, m64x2, m8x32, m8x32, m16x16, m16x16, m32x8, m32x8, m32x8, m64x4, m64x4, m64x4, m8x64, m8x64, m16x32, m16x32, m32x16, m32x16, m32x16, m64x8, m64x8, m64x8),
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (select_u8x16, select_i8x16, select_u16x8, select_i16x8, select_u32x4, select_i32x4, select_f32x4, select_u64x2, select_i64x2, select_f64x2, select_u8x32, select_i8x32, select_u16x16, select_i16x16, select_u32x8, select_i32x8, select_f32x8, select_u64x4, select_i64x4, select_f64x4, select_u8x64, select_i8x64, select_u16x32, select_i16x32, select_u32x16, select_i32x16, select_f32x16, select_u64x8, select_i64x8, select_f64x8));
+}
@@ -0,0 +1,121 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::arch::current::vecs::*;
+use crate::intrin::shift::*;
+use crate::vecs::*;
+use crate::core::mem::transmute;
+use crate::core::arch::x86_64::{_mm512_sllv_epi16, _mm512_srlv_epi16, _mm512_srav_epi16};
+use crate::core::arch::x86_64::{_mm512_sllv_epi32, _mm512_srlv_epi32, _mm512_srav_epi32};
+use crate::core::arch::x86_64::{_mm512_sllv_epi64, _mm512_srlv_epi64, _mm512_srav_epi64};
+
+// x86 has no 8-bit shifts, so we shift 16-bit elements by 4, 2 and 1 wherever
+// that bit of the amount is set, masking off the bits which cross into the
+// neighbouring byte.
+macro_rules! impl_shift_epi8 {
+    ($vec:tt, $feat:expr, ($sll:ident, $srl:ident, $sra:ident), ($slli:tt, $srli:tt, $cmpeq:tt, $min:tt)) => {
+        /// Return `shifted` in the elements where `bit` is set in `amounts`,
+        /// and `a` elsewhere.
+        #[inline(always)]
+        #[cfg(target_feature = $feat)]
+        unsafe fn blend_bit(amounts: $vec, bit: u8, shifted: $vec, a: $vec) -> $vec {
+            let mask: $vec = transmute($cmpeq(transmute(amounts & $vec::splat(bit)), transmute($vec::splat(bit))));
+            (shifted & mask) | (a & !mask)
+        }
+
+        #[inline(always)]
+        #[cfg(target_feature = $feat)]
+        unsafe fn $sll(a: $vec, amounts: $vec) -> $vec {
+            let mut ret = a;
+            ret = blend_bit(amounts, 4, transmute::<_, $vec>($slli(transmute(ret), 4)) & $vec::splat(0xF0), ret);
+            ret = blend_bit(amounts, 2, transmute::<_, $vec>($slli(transmute(ret), 2)) & $vec::splat(0xFC), ret);
+            ret = blend_bit(amounts, 1, transmute::<_, $vec>($slli(transmute(ret), 1)) & $vec::splat(0xFE), ret);
+            // Amounts of 8 or more shift out every bit
+            let big: $vec = transmute($cmpeq(transmute($min(amounts, $vec::splat(8))), transmute($vec::splat(8))));
+            ret & !big
+        }
+
+        #[inline(always)]
+        #[cfg(target_feature = $feat)]
+        unsafe fn $srl(a: $vec, amounts: $vec) -> $vec {
+            let mut ret = a;
+            ret = blend_bit(amounts, 4, transmute::<_, $vec>($srli(transmute(ret), 4)) & $vec::splat(0x0F), ret);
+            ret = blend_bit(amounts, 2, transmute::<_, $vec>($srli(transmute(ret), 2)) & $vec::splat(0x3F), ret);
+            ret = blend_bit(amounts, 1, transmute::<_, $vec>($srli(transmute(ret), 1)) & $vec::splat(0x7F), ret);
+            let big: $vec = transmute($cmpeq(transmute($min(amounts, $vec::splat(8))), transmute($vec::splat(8))));
+            ret & !big
+        }
+
+        #[inline(always)]
+        #[cfg(target_feature = $feat)]
+        unsafe fn $sra(a: $vec, amounts: $vec) -> $vec {
+            // ((a ^ 0x80) >> k) - (0x80 >> k) sign-extends a logical shift,
+            // and shifting by 7 already fills the element with its sign.
+            let amounts = $min(amounts, $vec::splat(7));
+            $srl(a ^ $vec::splat(0x80), amounts) - $srl($vec::splat(0x80), amounts)
+        }
+    }
+}
+
+#[cfg(target_feature = "sse2")]
+mod sse2 {
+    use super::*;
+    impl_shift_epi8!(u8x16, "sse2", (sllv_epi8, srlv_epi8, srav_epi8), (_mm_slli_epi16, _mm_srli_epi16, _mm_cmpeq_epi8, _mm_min_epu8));
+}
+
+#[cfg(target_feature = "avx2")]
+mod avx2 {
+    use super::*;
+    impl_shift_epi8!(u8x32, "avx2", (mm256_sllv_epi8, mm256_srlv_epi8, mm256_srav_epi8), (_mm256_slli_epi16, _mm256_srli_epi16, _mm256_cmpeq_epi8, _mm256_min_epu8));
+}
+
+#[cfg(target_feature = "sse2")]
+use self::sse2::{sllv_epi8, srlv_epi8, srav_epi8};
+#[cfg(target_feature = "avx2")]
+use self::avx2::{mm256_sllv_epi8, mm256_srlv_epi8, mm256_srav_epi8};
+
+impl_shift!(u8x16, u8, u8x16, u8, ("sse2", sllv_epi8), ("sse2", srlv_epi8));
+impl_shift!(i8x16, i8, u8x16, u8, ("sse2", sllv_epi8), ("sse2", srav_epi8));
+// TODO: AVX-512BW has _mm_sllv_epi16 and friends
+impl_shift!(u16x8, u16, u16x8, u16, ("avx512-butnotyet", __undefined), ("avx512-butnotyet", __undefined));
+impl_shift!(i16x8, i16, u16x8, u16, ("avx512-butnotyet", __undefined), ("avx512-butnotyet", __undefined));
+impl_shift!(u32x4, u32, u32x4, u32, ("avx2", _mm_sllv_epi32), ("avx2", _mm_srlv_epi32));
+impl_shift!(i32x4, i32, u32x4, u32, ("avx2", _mm_sllv_epi32), ("avx2", _mm_srav_epi32));
+impl_shift!(u64x2, u64, u64x2, u64, ("avx2", _mm_sllv_epi64), ("avx2", _mm_srlv_epi64));
+// AVX2 has no 64-bit arithmetic shift
+impl_shift!(i64x2, i64, u64x2, u64, ("avx2", _mm_sllv_epi64), ("avx512-butnotyet", __undefined));
+
+impl_shift!(u8x32, u8, u8x32, u8, ("avx2", mm256_sllv_epi8), ("avx2", mm256_srlv_epi8));
+impl_shift!(i8x32, i8, u8x32, u8, ("avx2", mm256_sllv_epi8), ("avx2", mm256_srav_epi8));
+impl_shift!(u16x16, u16, u16x16, u16, ("avx512-butnotyet", __undefined), ("avx512-butnotyet", __undefined));
+impl_shift!(i16x16, i16, u16x16, u16, ("avx512-butnotyet", __undefined), ("avx512-butnotyet", __undefined));
+impl_shift!(u32x8, u32, u32x8, u32, ("avx2", _mm256_sllv_epi32), ("avx2", _mm256_srlv_epi32));
+impl_shift!(i32x8, i32, u32x8, u32, ("avx2", _mm256_sllv_epi32), ("avx2", _mm256_srav_epi32));
+impl_shift!(u64x4, u64, u64x4, u64, ("avx2", _mm256_sllv_epi64), ("avx2", _mm256_srlv_epi64));
+impl_shift!(i64x4, i64, u64x4, u64, ("avx2", _mm256_sllv_epi64), ("avx512-butnotyet", __undefined));
+
+// AVX-512 compares produce bitmasks, so the 8-bit shifts above don't carry over
+impl_shift!(u8x64, u8, u8x64, u8, ("avx512-butnotyet", __undefined), ("avx512-butnotyet", __undefined));
+impl_shift!(i8x64, i8, u8x64, u8, ("avx512-butnotyet", __undefined), ("avx512-butnotyet", __undefined));
+impl_shift!(u16x32, u16, u16x32, u16, ("avx512bw", _mm512_sllv_epi16), ("avx512bw", _mm512_srlv_epi16));
+impl_shift!(i16x32, i16, u16x32, u16, ("avx512bw", _mm512_sllv_epi16), ("avx512bw", _mm512_srav_epi16));
+impl_shift!(u32x16, u32, u32x16, u32, ("avx512f", _mm512_sllv_epi32), ("avx512f", _mm512_srlv_epi32));
+impl_shift!(i32x16, i32, u32x16, u32, ("avx512f", _mm512_sllv_epi32), ("avx512f", _mm512_srav_epi32));
+impl_shift!(u64x8, u64, u64x8, u64, ("avx512f", _mm512_sllv_epi64), ("avx512f", _mm512_srlv_epi64));
+impl_shift!(i64x8, i64, u64x8, u64, ("avx512f", _mm512_sllv_epi64), ("avx512f", _mm512_srav_epi64));
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_shift!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, u64x2, i64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, u64x4, i64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, u64x8, i64x8),
+        (u8, i8, u16, i16, u32, i32, u64, i64, u8, i8, u16, i16, u32, i32, u64, i64, u8, i8, u16, i16, u32, i32, u64, i64),
+        (shift_u8x16, shift_i8x16, shift_u16x8, shift_i16x8, shift_u32x4, shift_i32x4, shift_u64x2, shift_i64x2, shift_u8x32, shift_i8x32, shift_u16x16, shift_i16x16, shift_u32x8, shift_i32x8, shift_u64x4, shift_i64x4, shift_u8x64, shift_i8x64, shift_u16x32, shift_i16x32, shift_u32x16, shift_i32x16, shift_u64x8, shift_i64x8));
+}
@@ -7,6 +7,7 @@
 
 use vektor::x86_64::*;
 use vektor::x86::*;
+use crate::core::arch::x86_64::{_mm512_sqrt_ps, _mm512_sqrt_pd};
 use crate::intrin::sqrt::*;
 use crate::arch::current::vecs::*;
 use crate::vecs::*;
@@ -34,3 +35,15 @@ rust_fallback_impl! {
         sqrt => _mm_sqrt_pd(), [0, 1];
     }
 }
+
+rust_fallback_impl! {
+    impl Sqrt for f32x16 where "avx512f" {
+        sqrt => _mm512_sqrt_ps(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    }
+}
+
+rust_fallback_impl! {
+    impl Sqrt for f64x8 where "avx512f" {
+        sqrt => _mm512_sqrt_pd(), [0, 1, 2, 3, 4, 5, 6, 7];
+    }
+}
@@ -0,0 +1,63 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::core::arch::x86_64::{__m128i, __m256i, _mm_sfence};
+use crate::core::arch::x86_64::{_mm_stream_si128, _mm_stream_ps, _mm_stream_pd};
+use crate::core::arch::x86_64::{_mm256_stream_si256, _mm256_stream_ps, _mm256_stream_pd};
+use crate::core::arch::x86_64::{__m512i, _mm512_stream_si512, _mm512_stream_ps, _mm512_stream_pd};
+use crate::intrin::stream::*;
+use crate::arch::current::vecs::*;
+use crate::vecs::*;
+
+/// Order every non-temporal store made before this call before every store
+/// made after it.
+#[inline(always)]
+pub fn stream_fence() {
+    unsafe { _mm_sfence() }
+}
+
+impl_stream!(u8x16, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(i8x16, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(u16x8, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(i16x8, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(u32x4, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(i32x4, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(f32x4, "sse", _mm_stream_ps, f32);
+impl_stream!(u64x2, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(i64x2, "sse2", _mm_stream_si128, __m128i);
+impl_stream!(f64x2, "sse2", _mm_stream_pd, f64);
+
+impl_stream!(u8x32, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(i8x32, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(u16x16, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(i16x16, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(u32x8, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(i32x8, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(f32x8, "avx", _mm256_stream_ps, f32);
+impl_stream!(u64x4, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(i64x4, "avx", _mm256_stream_si256, __m256i);
+impl_stream!(f64x4, "avx", _mm256_stream_pd, f64);
+
+impl_stream!(u8x64, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(i8x64, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(u16x32, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(i16x32, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(u32x16, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(i32x16, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(f32x16, "avx512f", _mm512_stream_ps, f32);
+impl_stream!(u64x8, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(i64x8, "avx512f", _mm512_stream_si512, __m512i);
+impl_stream!(f64x8, "avx512f", _mm512_stream_pd, f64);
+
+mod tests {
+    use crate::prelude::*;
+    use crate::arch::current::vecs::*;
+
+    test_stream!(
+        (u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2, u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4, u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8),
+        (stream_u8x16, stream_i8x16, stream_u16x8, stream_i16x8, stream_u32x4, stream_i32x4, stream_f32x4, stream_u64x2, stream_i64x2, stream_f64x2, stream_u8x32, stream_i8x32, stream_u16x16, stream_i16x16, stream_u32x8, stream_i32x8, stream_f32x8, stream_u64x4, stream_i64x4, stream_f64x4, stream_u8x64, stream_i8x64, stream_u16x32, stream_i16x32, stream_u32x16, stream_i32x16, stream_f32x16, stream_u64x8, stream_i64x8, stream_f64x8));
+}
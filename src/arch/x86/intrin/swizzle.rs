@@ -2,8 +2,10 @@ use crate::arch::current::vecs::*;
 use crate::vecs::*;
 use crate::vektor::x86_64::*;
 use crate::vektor::x86::*;
+use crate::intrin::swizzle::*;
 use crate::intrin::transmute::*;
 use crate::core::mem::transmute;
+use crate::core::arch::x86_64 as core_arch;
 
 impl_packed_swizzle!(u8x64, u8x64, "avx512-butnotyet", _mm512_permutexvar_epi8,
                      (1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 17, 16, 19, 18, 21, 20, 23, 22, 25, 24, 27, 26, 29, 28, 31, 30, 33, 32, 35, 34, 37, 36, 39, 38, 41, 40, 43, 42, 45, 44, 47, 46, 49, 48, 51, 50, 53, 52, 55, 54, 57, 56, 59, 58, 61, 60, 63, 62),
@@ -96,8 +98,140 @@ impl_packed_swizzle!(f64x2, u8x16, "ssse3", _mm_shuffle_epi8,
                      (8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7),
                      (0, 1));
 
+// pshufb only shuffles bytes, so wider elements are shuffled by expanding
+// each element index into the indices of its bytes.
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn shuffle_epi8(a: u8x16, idx: u8x16) -> u8x16 {
+    // Clear the high bit, which would otherwise zero the element
+    transmute(_mm_shuffle_epi8(transmute(a), transmute(idx & u8x16::splat(0x0F))))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn shuffle_epi16(a: u16x8, idx: u16x8) -> u16x8 {
+    let bytes = (idx & u16x8::splat(0x07)) * u16x8::splat(0x0202) + u16x8::splat(0x0100);
+    transmute(shuffle_epi8(transmute(a), transmute(bytes)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn shuffle_epi32(a: u32x4, idx: u32x4) -> u32x4 {
+    let bytes = (idx & u32x4::splat(0x03)) * u32x4::splat(0x04040404) + u32x4::splat(0x03020100);
+    transmute(shuffle_epi8(transmute(a), transmute(bytes)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn shuffle_epi64(a: u64x2, idx: u64x2) -> u64x2 {
+    let bytes = (idx & u64x2::splat(0x01)) * u64x2::splat(0x0808080808080808) + u64x2::splat(0x0706050403020100);
+    transmute(shuffle_epi8(transmute(a), transmute(bytes)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn mm256_shuffle_epi8(a: u8x32, idx: u8x32) -> u8x32 {
+    // vpshufb can't move bytes between the 128-bit lanes, so shuffle copies
+    // of each half of `a` and pick between them with bit 4 of the index.
+    let lo = _mm256_permute2x128_si256(transmute(a), transmute(a), 0x00);
+    let hi = _mm256_permute2x128_si256(transmute(a), transmute(a), 0x11);
+    let idx = idx & u8x32::splat(0x1F);
+    let from_lo = _mm256_shuffle_epi8(transmute(lo), transmute(idx & u8x32::splat(0x0F)));
+    let from_hi = _mm256_shuffle_epi8(transmute(hi), transmute(idx & u8x32::splat(0x0F)));
+    let use_hi = transmute(idx << 3);
+    transmute(_mm256_blendv_epi8(from_lo, from_hi, use_hi))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn mm256_shuffle_epi16(a: u16x16, idx: u16x16) -> u16x16 {
+    let bytes = (idx & u16x16::splat(0x0F)) * u16x16::splat(0x0202) + u16x16::splat(0x0100);
+    transmute(mm256_shuffle_epi8(transmute(a), transmute(bytes)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn mm256_shuffle_epi32(a: u32x8, idx: u32x8) -> u32x8 {
+    transmute(_mm256_permutevar8x32_epi32(transmute(a), transmute(idx)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+unsafe fn mm256_shuffle_epi64(a: u64x4, idx: u64x4) -> u64x4 {
+    // Expand each index into the indices of its two 32-bit halves
+    let halves = (idx & u64x4::splat(0x03)) * u64x4::splat(0x0000000200000002) + u64x4::splat(0x0000000100000000);
+    transmute(_mm256_permutevar8x32_epi32(transmute(a), transmute(halves)))
+}
+
+// vperm* take the indices first, and ignore their bits above the width
+#[inline(always)]
+#[cfg(target_feature = "avx512vbmi")]
+unsafe fn mm512_shuffle_epi8(a: u8x64, idx: u8x64) -> u8x64 {
+    transmute(core_arch::_mm512_permutexvar_epi8(transmute(idx), transmute(a)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx512bw")]
+unsafe fn mm512_shuffle_epi16(a: u16x32, idx: u16x32) -> u16x32 {
+    transmute(core_arch::_mm512_permutexvar_epi16(transmute(idx), transmute(a)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx512f")]
+unsafe fn mm512_shuffle_epi32(a: u32x16, idx: u32x16) -> u32x16 {
+    transmute(core_arch::_mm512_permutexvar_epi32(transmute(idx), transmute(a)))
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx512f")]
+unsafe fn mm512_shuffle_epi64(a: u64x8, idx: u64x8) -> u64x8 {
+    transmute(core_arch::_mm512_permutexvar_epi64(transmute(idx), transmute(a)))
+}
+
+// Every x86 vector is a packed_simd vector, whose `shuffle!` takes its
+// indices as a const generic.
+#[doc(hidden)]
+#[macro_export] macro_rules! __shuffle_impl {
+    ($vec:expr, [$($idx:expr),+]) => ($crate::__packed_simd::shuffle!($vec, [$($idx),+]))
+}
+
+impl_packed_shuffle!(u8x16, u8x16, u8, "ssse3", shuffle_epi8);
+impl_packed_shuffle!(i8x16, u8x16, u8, "ssse3", shuffle_epi8);
+impl_packed_shuffle!(u16x8, u16x8, u16, "ssse3", shuffle_epi16);
+impl_packed_shuffle!(i16x8, u16x8, u16, "ssse3", shuffle_epi16);
+impl_packed_shuffle!(u32x4, u32x4, u32, "ssse3", shuffle_epi32);
+impl_packed_shuffle!(i32x4, u32x4, u32, "ssse3", shuffle_epi32);
+impl_packed_shuffle!(f32x4, u32x4, u32, "ssse3", shuffle_epi32);
+impl_packed_shuffle!(u64x2, u64x2, u64, "ssse3", shuffle_epi64);
+impl_packed_shuffle!(i64x2, u64x2, u64, "ssse3", shuffle_epi64);
+impl_packed_shuffle!(f64x2, u64x2, u64, "ssse3", shuffle_epi64);
+
+impl_packed_shuffle!(u8x32, u8x32, u8, "avx2", mm256_shuffle_epi8);
+impl_packed_shuffle!(i8x32, u8x32, u8, "avx2", mm256_shuffle_epi8);
+impl_packed_shuffle!(u16x16, u16x16, u16, "avx2", mm256_shuffle_epi16);
+impl_packed_shuffle!(i16x16, u16x16, u16, "avx2", mm256_shuffle_epi16);
+impl_packed_shuffle!(u32x8, u32x8, u32, "avx2", mm256_shuffle_epi32);
+impl_packed_shuffle!(i32x8, u32x8, u32, "avx2", mm256_shuffle_epi32);
+impl_packed_shuffle!(f32x8, u32x8, u32, "avx2", mm256_shuffle_epi32);
+impl_packed_shuffle!(u64x4, u64x4, u64, "avx2", mm256_shuffle_epi64);
+impl_packed_shuffle!(i64x4, u64x4, u64, "avx2", mm256_shuffle_epi64);
+impl_packed_shuffle!(f64x4, u64x4, u64, "avx2", mm256_shuffle_epi64);
+
+impl_packed_shuffle!(u8x64, u8x64, u8, "avx512vbmi", mm512_shuffle_epi8);
+impl_packed_shuffle!(i8x64, u8x64, u8, "avx512vbmi", mm512_shuffle_epi8);
+impl_packed_shuffle!(u16x32, u16x32, u16, "avx512bw", mm512_shuffle_epi16);
+impl_packed_shuffle!(i16x32, u16x32, u16, "avx512bw", mm512_shuffle_epi16);
+impl_packed_shuffle!(u32x16, u32x16, u32, "avx512f", mm512_shuffle_epi32);
+impl_packed_shuffle!(i32x16, u32x16, u32, "avx512f", mm512_shuffle_epi32);
+impl_packed_shuffle!(f32x16, u32x16, u32, "avx512f", mm512_shuffle_epi32);
+impl_packed_shuffle!(u64x8, u64x8, u64, "avx512f", mm512_shuffle_epi64);
+impl_packed_shuffle!(i64x8, u64x8, u64, "avx512f", mm512_shuffle_epi64);
+impl_packed_shuffle!(f64x8, u64x8, u64, "avx512f", mm512_shuffle_epi64);
+
 mod tests {
     use crate::prelude::*;
+    use crate::arch::current::vecs::*;
 
     macro_rules! test_packed_swizzle {
         (($($vec:tt),*), ($($fn:tt),*)) => {
@@ -117,4 +251,6 @@ mod tests {
     test_packed_swizzle!((u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2),
                          (flip_u8x64, flip_u8x32, flip_u8x16, flip_i8x64, flip_i8x32, flip_i8x16, flip_u16x32, flip_u16x16, flip_u16x8, flip_i16x32, flip_i16x16, flip_i16x8, flip_u32x16, flip_u32x8, flip_u32x4, flip_i32x16, flip_i32x8, flip_i32x4, flip_f32x16, flip_f32x8, flip_f32x4, flip_u64x8, flip_u64x4, flip_u64x2, flip_i64x8, flip_i64x4, flip_i64x2, flip_f64x8, flip_f64x4, flip_f64x2));
 
+    test_packed_shuffle!((u8x64, u8x32, u8x16, i8x64, i8x32, i8x16, u16x32, u16x16, u16x8, i16x32, i16x16, i16x8, u32x16, u32x8, u32x4, i32x16, i32x8, i32x4, f32x16, f32x8, f32x4, u64x8, u64x4, u64x2, i64x8, i64x4, i64x2, f64x8, f64x4, f64x2),
+                         (shuffle_u8x64, shuffle_u8x32, shuffle_u8x16, shuffle_i8x64, shuffle_i8x32, shuffle_i8x16, shuffle_u16x32, shuffle_u16x16, shuffle_u16x8, shuffle_i16x32, shuffle_i16x16, shuffle_i16x8, shuffle_u32x16, shuffle_u32x8, shuffle_u32x4, shuffle_i32x16, shuffle_i32x8, shuffle_i32x4, shuffle_f32x16, shuffle_f32x8, shuffle_f32x4, shuffle_u64x8, shuffle_u64x4, shuffle_u64x2, shuffle_i64x8, shuffle_i64x4, shuffle_i64x2, shuffle_f64x8, shuffle_f64x4, shuffle_f64x2));
 }
@@ -0,0 +1,205 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Mask types for x86 machines, which are computed with `movemask` where
+//! possible.
+
+#![allow(unused_imports)]
+
+use crate::vektor::x86_64::*;
+use crate::vektor::x86::*;
+use crate::core::mem::transmute;
+use crate::core::ops::{BitAnd, BitOr, BitXor, Not};
+use crate::arch::current::vecs::*;
+use crate::masks::*;
+use crate::vecs::*;
+
+#[inline(always)]
+fn bitmask_m8x64(vec: u8x64) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn bitmask_m8x32(vec: u8x32) -> u64 {
+    optimized!();
+    unsafe { _mm256_movemask_epi8(transmute(vec)) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "avx2"))]
+fn bitmask_m8x32(vec: u8x32) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "sse2")]
+fn bitmask_m8x16(vec: u8x16) -> u64 {
+    optimized!();
+    unsafe { _mm_movemask_epi8(transmute(vec)) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "sse2"))]
+fn bitmask_m8x16(vec: u8x16) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m16x32(vec: u16x32) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx2")]
+fn bitmask_m16x16(vec: u16x16) -> u64 {
+    optimized!();
+    unsafe {
+        // Packing is done within each 128-bit lane, so the bytes we want are
+        // at 0..8 and 16..24.
+        let bits = _mm256_movemask_epi8(_mm256_packs_epi16(transmute(vec), i16x16::splat(0))) as u32 as u64;
+        (bits & 0xFF) | ((bits >> 8) & 0xFF00)
+    }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "avx2"))]
+fn bitmask_m16x16(vec: u16x16) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "sse2")]
+fn bitmask_m16x8(vec: u16x8) -> u64 {
+    optimized!();
+    unsafe { _mm_movemask_epi8(_mm_packs_epi16(transmute(vec), i16x8::splat(0))) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "sse2"))]
+fn bitmask_m16x8(vec: u16x8) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m32x16(vec: u32x16) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx")]
+fn bitmask_m32x8(vec: u32x8) -> u64 {
+    optimized!();
+    unsafe { _mm256_movemask_ps(transmute(vec)) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "avx"))]
+fn bitmask_m32x8(vec: u32x8) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "sse")]
+fn bitmask_m32x4(vec: u32x4) -> u64 {
+    optimized!();
+    unsafe { _mm_movemask_ps(transmute(vec)) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "sse"))]
+fn bitmask_m32x4(vec: u32x4) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+fn bitmask_m64x8(vec: u64x8) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "avx")]
+fn bitmask_m64x4(vec: u64x4) -> u64 {
+    optimized!();
+    unsafe { _mm256_movemask_pd(transmute(vec)) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "avx"))]
+fn bitmask_m64x4(vec: u64x4) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+#[inline(always)]
+#[cfg(target_feature = "sse2")]
+fn bitmask_m64x2(vec: u64x2) -> u64 {
+    optimized!();
+    unsafe { _mm_movemask_pd(transmute(vec)) as u32 as u64 }
+}
+
+#[inline(always)]
+#[cfg(not(target_feature = "sse2"))]
+fn bitmask_m64x2(vec: u64x2) -> u64 {
+    fallback!();
+    bitmask_polyfill(vec)
+}
+
+impl_mask!(m8x64, u8x64, u8, 64, bitmask_m8x64, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31, x32, x33, x34, x35, x36, x37, x38, x39, x40, x41, x42, x43, x44, x45, x46, x47, x48, x49, x50, x51, x52, x53, x54, x55, x56, x57, x58, x59, x60, x61, x62, x63]);
+impl_mask!(m8x32, u8x32, u8, 32, bitmask_m8x32, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31]);
+impl_mask!(m8x16, u8x16, u8, 16, bitmask_m8x16, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15]);
+impl_mask!(m16x32, u16x32, u16, 32, bitmask_m16x32, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31]);
+impl_mask!(m16x16, u16x16, u16, 16, bitmask_m16x16, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15]);
+impl_mask!(m16x8, u16x8, u16, 8, bitmask_m16x8, [x0, x1, x2, x3, x4, x5, x6, x7]);
+impl_mask!(m32x16, u32x16, u32, 16, bitmask_m32x16, [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15]);
+impl_mask!(m32x8, u32x8, u32, 8, bitmask_m32x8, [x0, x1, x2, x3, x4, x5, x6, x7]);
+impl_mask!(m32x4, u32x4, u32, 4, bitmask_m32x4, [x0, x1, x2, x3]);
+impl_mask!(m64x8, u64x8, u64, 8, bitmask_m64x8, [x0, x1, x2, x3, x4, x5, x6, x7]);
+impl_mask!(m64x4, u64x4, u64, 4, bitmask_m64x4, [x0, x1, x2, x3]);
+impl_mask!(m64x2, u64x2, u64, 2, bitmask_m64x2, [x0, x1]);
+
+impl_mask_alias!(m8s, m8x64, ["avx512"], ["avx1024"]);
+impl_mask_alias!(m8s, m8x32, ["avx2"], ["avx512"]);
+impl_mask_alias!(m8s, m8x16, [], ["avx2"]);
+impl_mask_alias!(m16s, m16x32, ["avx512"], ["avx1024"]);
+impl_mask_alias!(m16s, m16x16, ["avx2"], ["avx512"]);
+impl_mask_alias!(m16s, m16x8, [], ["avx2"]);
+impl_mask_alias!(m32s, m32x16, ["avx512"], ["avx1024"]);
+impl_mask_alias!(m32s, m32x8, ["avx2"], ["avx512"]);
+impl_mask_alias!(m32s, m32x4, [], ["avx2"]);
+impl_mask_alias!(m64s, m64x8, ["avx512"], ["avx1024"]);
+impl_mask_alias!(m64s, m64x4, ["avx2"], ["avx512"]);
+impl_mask_alias!(m64s, m64x2, [], ["avx2"]);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use super::*;
+
+    test_mask!(m8x64, u8x64, u8, test_mask_m8x64);
+    test_mask!(m8x32, u8x32, u8, test_mask_m8x32);
+    test_mask!(m8x16, u8x16, u8, test_mask_m8x16);
+    test_mask!(m16x32, u16x32, u16, test_mask_m16x32);
+    test_mask!(m16x16, u16x16, u16, test_mask_m16x16);
+    test_mask!(m16x8, u16x8, u16, test_mask_m16x8);
+    test_mask!(m32x16, u32x16, u32, test_mask_m32x16);
+    test_mask!(m32x8, u32x8, u32, test_mask_m32x8);
+    test_mask!(m32x4, u32x4, u32, test_mask_m32x4);
+    test_mask!(m64x8, u64x8, u64, test_mask_m64x8);
+    test_mask!(m64x4, u64x4, u64, test_mask_m64x4);
+    test_mask!(m64x2, u64x2, u64, test_mask_m64x2);
+}
@@ -1,3 +1,4 @@
 pub mod intrin;
+pub mod masks;
 pub mod vecs;
 pub mod vec_patterns;
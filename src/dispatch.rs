@@ -0,0 +1,278 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Runtime selection of SIMD kernels.
+//!
+//! Faster chooses its vector widths and intrinsics at compile time, so a
+//! binary built for a generic x86-64 target will only use SSE2. The
+//! [`dispatch!`] macro compiles a function once for each [`SimdLevel`], and
+//! calls the version which best suits the machine it's running on.
+//!
+//! Each version is compiled with the instruction sets of its level enabled,
+//! and with `u8s`, `f32s`, `m32s` and friends redefined as the vectors and
+//! masks of that level: 128 bits wide for [`SimdLevel::Sse41`], 256 for
+//! [`SimdLevel::Avx2`], and 512 for [`SimdLevel::Avx512`]. The baseline
+//! version uses the widths the crate was compiled for. The intrinsics faster
+//! selects with `#[cfg(target_feature)]` are still fixed by that target, so
+//! operations on vectors wider than it use their portable implementations,
+//! which LLVM compiles with the instruction sets of the level.
+//!
+//! [`dispatch!`]: ../macro.dispatch.html
+//! [`SimdLevel`]: enum.SimdLevel.html
+//! [`SimdLevel::Sse41`]: enum.SimdLevel.html#variant.Sse41
+//! [`SimdLevel::Avx2`]: enum.SimdLevel.html#variant.Avx2
+//! [`SimdLevel::Avx512`]: enum.SimdLevel.html#variant.Avx512
+
+use crate::core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A set of SIMD instruction sets which a kernel may be compiled for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SimdLevel {
+    /// The instruction sets the crate was compiled for
+    Baseline = 1,
+    /// SSE4.1 and below
+    Sse41 = 2,
+    /// AVX2, FMA, and below
+    Avx2 = 3,
+    /// AVX-512F, AVX-512BW, and below
+    Avx512 = 4,
+}
+
+// Zero until the level is first detected or forced.
+static LEVEL: AtomicUsize = AtomicUsize::new(0);
+
+impl SimdLevel {
+    /// Return every level, from lowest to highest.
+    pub fn all() -> [SimdLevel; 4] {
+        [SimdLevel::Baseline, SimdLevel::Sse41, SimdLevel::Avx2, SimdLevel::Avx512]
+    }
+
+    #[inline(always)]
+    fn from_usize(level: usize) -> SimdLevel {
+        match level {
+            1 => SimdLevel::Baseline,
+            2 => SimdLevel::Sse41,
+            3 => SimdLevel::Avx2,
+            4 => SimdLevel::Avx512,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Return the highest level supported by this machine. Without the `std`
+    /// feature, this is the highest level the crate was compiled for.
+    pub fn detect() -> SimdLevel {
+        #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+                SimdLevel::Avx512
+            } else if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+                SimdLevel::Avx2
+            } else if is_x86_feature_detected!("sse4.1") {
+                SimdLevel::Sse41
+            } else {
+                SimdLevel::Baseline
+            }
+        }
+
+        #[cfg(not(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64"))))]
+        {
+            if cfg!(all(target_feature = "avx512f", target_feature = "avx512bw")) {
+                SimdLevel::Avx512
+            } else if cfg!(all(target_feature = "avx2", target_feature = "fma")) {
+                SimdLevel::Avx2
+            } else if cfg!(target_feature = "sse4.1") {
+                SimdLevel::Sse41
+            } else {
+                SimdLevel::Baseline
+            }
+        }
+    }
+
+    /// Return the level which [`dispatch!`] functions currently run at. This
+    /// is the result of [`SimdLevel::detect`], which is only computed once,
+    /// unless a level has been set with [`SimdLevel::force`].
+    ///
+    /// [`dispatch!`]: ../macro.dispatch.html
+    /// [`SimdLevel::detect`]: #method.detect
+    /// [`SimdLevel::force`]: #method.force
+    #[inline(always)]
+    pub fn current() -> SimdLevel {
+        match LEVEL.load(Ordering::Relaxed) {
+            0 => {
+                let level = SimdLevel::detect();
+                LEVEL.store(level as usize, Ordering::Relaxed);
+                level
+            },
+            level => SimdLevel::from_usize(level),
+        }
+    }
+
+    /// Make every [`dispatch!`] function run at `level`, or at the detected
+    /// level if `level` is `None`. This is intended for tests, and affects
+    /// every thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this machine doesn't support `level`.
+    ///
+    /// [`dispatch!`]: ../macro.dispatch.html
+    pub fn force(level: Option<SimdLevel>) {
+        let level = match level {
+            Some(level) => {
+                assert!(level <= SimdLevel::detect(),
+                        "can't force {:?} on a machine which only supports {:?}",
+                        level, SimdLevel::detect());
+                level
+            },
+            None => SimdLevel::detect(),
+        };
+        LEVEL.store(level as usize, Ordering::Relaxed);
+    }
+}
+
+macro_rules! level_vectors {
+    ($($el:ident, $pvec:ident, $vec:ident;)*) => {
+        $(
+            #[allow(non_camel_case_types)]
+            pub type $pvec = crate::arch::current::vecs::$vec;
+
+            #[inline(always)]
+            pub fn $pvec(data: $el) -> $pvec {
+                <$pvec as crate::vecs::Packed>::splat(data)
+            }
+        )*
+    }
+}
+
+macro_rules! level_masks {
+    ($($pmask:ident, $mask:ident;)*) => {
+        $(
+            #[allow(non_camel_case_types)]
+            pub type $pmask = crate::arch::current::masks::$mask;
+        )*
+    }
+}
+
+/// The vectors and masks which shadow `u8s`, `m8s` and friends in each
+/// version of a [`dispatch!`] function.
+///
+/// [`dispatch!`]: ../macro.dispatch.html
+#[doc(hidden)]
+pub mod levels {
+    pub mod sse41 {
+        level_vectors!(u8, u8s, u8x16; i8, i8s, i8x16; u16, u16s, u16x8; i16, i16s, i16x8;
+                       u32, u32s, u32x4; i32, i32s, i32x4; f32, f32s, f32x4;
+                       u64, u64s, u64x2; i64, i64s, i64x2; f64, f64s, f64x2;);
+        level_masks!(m8s, m8x16; m16s, m16x8; m32s, m32x4; m64s, m64x2;);
+    }
+
+    pub mod avx2 {
+        level_vectors!(u8, u8s, u8x32; i8, i8s, i8x32; u16, u16s, u16x16; i16, i16s, i16x16;
+                       u32, u32s, u32x8; i32, i32s, i32x8; f32, f32s, f32x8;
+                       u64, u64s, u64x4; i64, i64s, i64x4; f64, f64s, f64x4;);
+        level_masks!(m8s, m8x32; m16s, m16x16; m32s, m32x8; m64s, m64x4;);
+    }
+
+    pub mod avx512 {
+        level_vectors!(u8, u8s, u8x64; i8, i8s, i8x64; u16, u16s, u16x32; i16, i16s, i16x32;
+                       u32, u32s, u32x16; i32, i32s, i32x16; f32, f32s, f32x16;
+                       u64, u64s, u64x8; i64, i64s, i64x8; f64, f64s, f64x8;);
+        level_masks!(m8s, m8x64; m16s, m16x32; m32s, m32x16; m64s, m64x8;);
+    }
+}
+
+/// A macro which defines a function whose body is compiled once for each
+/// [`SimdLevel`], and which calls the version for [`SimdLevel::current`].
+/// Arguments must be plain identifiers, and generic functions aren't
+/// supported. On architectures other than x86, the body is compiled once.
+///
+/// Within the body, `u8s`, `f32s`, `m32s` and friends are the vectors and
+/// masks of the level it's compiled for, so their widths differ between
+/// versions. The signature is shared by every version, so it mustn't mention
+/// them, and iterators should be built with `simd_iter_as`, whose vector type
+/// isn't fixed by the crate's target.
+///
+/// Crates using this macro on x86 must enable `avx512_target_feature`.
+///
+/// ```
+/// #![feature(avx512_target_feature)]
+/// #[macro_use] extern crate faster;
+/// use faster::*;
+///
+/// dispatch! {
+///     fn sum_squares(data: &[f32]) -> f32 {
+///         data.simd_iter_as::<f32s>()
+///             .simd_reduce(f32s(0.0), |acc, v| acc + v * v)
+///             .sum()
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(sum_squares(&[2.0; 10]), 40.0);
+/// SimdLevel::force(Some(SimdLevel::Baseline));
+/// assert_eq!(sum_squares(&[2.0; 10]), 40.0);
+/// SimdLevel::force(None);
+/// # }
+/// ```
+///
+/// [`SimdLevel`]: dispatch/enum.SimdLevel.html
+/// [`SimdLevel::current`]: dispatch/enum.SimdLevel.html#method.current
+#[macro_export] macro_rules! dispatch {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident : $ty:ty),*) $body:block) => {
+        dispatch! { $(#[$attr])* $vis fn $name($($arg : $ty),*) -> () $body }
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident : $ty:ty),*) -> $ret:ty $body:block) => {
+        $(#[$attr])*
+        $vis fn $name($($arg : $ty),*) -> $ret {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[inline(always)]
+            fn dispatched($($arg : $ty),*) -> $ret {
+                #[inline]
+                #[target_feature(enable = "avx512f,avx512bw,avx2,fma,sse4.1")]
+                unsafe fn avx512($($arg : $ty),*) -> $ret {
+                    #[allow(unused_imports)]
+                    use $crate::dispatch::levels::avx512::*;
+                    $body
+                }
+
+                #[inline]
+                #[target_feature(enable = "avx2,fma,sse4.1")]
+                unsafe fn avx2($($arg : $ty),*) -> $ret {
+                    #[allow(unused_imports)]
+                    use $crate::dispatch::levels::avx2::*;
+                    $body
+                }
+
+                #[inline]
+                #[target_feature(enable = "sse4.1")]
+                unsafe fn sse41($($arg : $ty),*) -> $ret {
+                    #[allow(unused_imports)]
+                    use $crate::dispatch::levels::sse41::*;
+                    $body
+                }
+
+                #[inline]
+                fn baseline($($arg : $ty),*) -> $ret $body
+
+                // Safe, because each level is only used if the machine
+                // supports it.
+                match $crate::dispatch::SimdLevel::current() {
+                    $crate::dispatch::SimdLevel::Avx512 => unsafe { avx512($($arg),*) },
+                    $crate::dispatch::SimdLevel::Avx2 => unsafe { avx2($($arg),*) },
+                    $crate::dispatch::SimdLevel::Sse41 => unsafe { sse41($($arg),*) },
+                    $crate::dispatch::SimdLevel::Baseline => baseline($($arg),*),
+                }
+            }
+
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            #[inline(always)]
+            fn dispatched($($arg : $ty),*) -> $ret $body
+
+            dispatched($($arg),*)
+        }
+    };
+}
@@ -5,10 +5,13 @@
 // License, v. 2.0. If a copy owf the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::iters::{SIMDIter, SIMDIterator, SIMDObject};
+use crate::iters::{SIMDArray, SIMDIter, SIMDIterator, SIMDObject};
+use crate::vecs::Packed;
 #[allow(unused_imports)] // Remove for specialization
 use crate::iters::SIMDAdapter;
 use crate::arch::current::vecs::*;
+#[cfg(feature = "std")]
+use crate::aligned::AlignedVec;
 
 /// A trait which transforms a contiguous collection into an owned stream of
 /// vectors.
@@ -25,11 +28,36 @@ pub trait IntoSIMDIterator {
 /// of vectors.
 pub trait IntoSIMDRefIterator<'a> {
     type Iter : SIMDIterator;
+    type Array : SIMDArray;
 
     /// Return an iterator over this data which will automatically pack
     /// values into SIMD vectors. See `SIMDIterator::simd_map` and
     /// `SIMDIterator::simd_reduce` for more information.
     fn simd_iter(&'a self, default: <Self::Iter as SIMDObject>::Vector) -> Self::Iter;
+
+    /// Return an iterator over this data which will automatically pack
+    /// values into vectors of type `V`, whose width doesn't depend on the
+    /// target features the crate was compiled with. Elements past the end of
+    /// the data are filled with zeroes.
+    ///
+    /// The 128-, 256- and 512-bit vector types are available on every
+    /// architecture. Where the target has no vectors that wide, they are
+    /// emulated with narrower vectors or scalars.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let words = [1u32, 2, 3, 4, 5, 6, 7, 8];
+    /// let mut iter = (&words[..]).simd_iter_as::<u32x4>();
+    /// assert_eq!(iter.next(), Some(u32x4::new(1, 2, 3, 4)));
+    /// assert_eq!(iter.next(), Some(u32x4::new(5, 6, 7, 8)));
+    /// assert_eq!(iter.next(), None);
+    /// # }
+    /// ```
+    fn simd_iter_as<V>(&'a self) -> SIMDIter<Self::Array, V>
+        where V : Packed<Scalar = <Self::Array as SIMDObject>::Scalar>;
 }
 
 /// A trait which transforms a contiguous collection into a mutable slice-backed
@@ -56,12 +84,70 @@ macro_rules! impl_array_intos {
                         data: self,
                         position: 0,
                         default: default,
+                        prefetch: None,
+                    }
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl IntoSIMDIterator for AlignedVec<$el> {
+                type Iter = SIMDIter<Self>;
+
+                #[inline(always)]
+                fn into_simd_iter(self, default: $vec) -> Self::Iter {
+                    SIMDIter {
+                        data: self,
+                        position: 0,
+                        default: default,
+                        prefetch: None,
+                    }
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl<'a> IntoSIMDRefIterator<'a> for AlignedVec<$el> {
+                type Iter = SIMDIter<&'a Self>;
+                type Array = &'a Self;
+
+                #[inline(always)]
+                fn simd_iter(&'a self, default: $vec) -> Self::Iter {
+                    SIMDIter {
+                        data: self,
+                        position: 0,
+                        default: default,
+                        prefetch: None,
+                    }
+                }
+
+                #[inline(always)]
+                fn simd_iter_as<V>(&'a self) -> SIMDIter<&'a Self, V> where V : Packed<Scalar = $el> {
+                    SIMDIter {
+                        data: self,
+                        position: 0,
+                        default: V::default(),
+                        prefetch: None,
+                    }
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl<'a> IntoSIMDRefMutIterator<'a> for AlignedVec<$el> {
+                type Iter = SIMDIter<&'a mut Self>;
+
+                #[inline(always)]
+                fn simd_iter_mut(&'a mut self, default: $vec) -> Self::Iter {
+                    SIMDIter {
+                        data: self,
+                        position: 0,
+                        default: default,
+                        prefetch: None,
                     }
                 }
             }
 
             impl<'a> IntoSIMDRefIterator<'a> for &'a [$el] {
                 type Iter = SIMDIter<Self>;
+                type Array = Self;
 
                 #[inline(always)]
                 fn simd_iter(&'a self, default: $vec) -> Self::Iter {
@@ -69,6 +155,17 @@ macro_rules! impl_array_intos {
                         data: self,
                         position: 0,
                         default: default,
+                        prefetch: None,
+                    }
+                }
+
+                #[inline(always)]
+                fn simd_iter_as<V>(&'a self) -> SIMDIter<Self, V> where V : Packed<Scalar = $el> {
+                    SIMDIter {
+                        data: self,
+                        position: 0,
+                        default: V::default(),
+                        prefetch: None,
                     }
                 }
             }
@@ -82,6 +179,7 @@ macro_rules! impl_array_intos {
                         data: self,
                         position: 0,
                         default: default,
+                        prefetch: None,
                     }
                 }
             }
@@ -95,12 +193,14 @@ macro_rules! impl_array_intos {
                         data: self,
                         position: 0,
                         default: default,
+                        prefetch: None,
                     }
                 }
             }
 
             impl<'a> IntoSIMDRefIterator<'a> for [$el] {
                 type Iter = SIMDIter<&'a Self>;
+                type Array = &'a Self;
 
                 #[inline(always)]
                 fn simd_iter(&'a self, default: $vec) -> Self::Iter {
@@ -108,6 +208,17 @@ macro_rules! impl_array_intos {
                         data: self,
                         position: 0,
                         default: default,
+                        prefetch: None,
+                    }
+                }
+
+                #[inline(always)]
+                fn simd_iter_as<V>(&'a self) -> SIMDIter<&'a Self, V> where V : Packed<Scalar = $el> {
+                    SIMDIter {
+                        data: self,
+                        position: 0,
+                        default: V::default(),
+                        prefetch: None,
                     }
                 }
             }
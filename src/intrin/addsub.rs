@@ -6,5 +6,56 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 pub trait AddSub {
+    /// Return a vector whose even elements are the differences, and whose odd
+    /// elements are the sums, of the corresponding elements of `self` and
+    /// `other`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(3.0).addsub(f32s(1.0)), f32s::interleave(2.0, 4.0));
+    /// # }
+    /// ```
     fn addsub(&self, other: Self) -> Self;
 }
+
+macro_rules! impl_addsub {
+    ($vec:tt, $feat:expr, $mmfn:tt, [$($even:expr, $odd:expr),*]) => {
+        impl AddSub for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn addsub(&self, other: Self) -> Self {
+                use crate::core::mem::transmute;
+                optimized!();
+                unsafe { transmute($mmfn(transmute(*self), transmute(other))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn addsub(&self, other: Self) -> Self {
+                fallback!();
+                Self::new($(self.extract($even) - other.extract($even),
+                            self.extract($odd) + other.extract($odd)),*)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn addsub_f32s() {
+        assert_eq!(f32s(3.0).addsub(f32s(1.0)), f32s::interleave(2.0, 4.0));
+        assert_eq!(f32s::interleave(1.0, 2.0).addsub(f32s::interleave(0.5, -3.0)), f32s::interleave(0.5, -1.0));
+    }
+
+    #[test]
+    fn addsub_f64s() {
+        assert_eq!(f64s(3.0).addsub(f64s(1.0)), f64s::interleave(2.0, 4.0));
+        assert_eq!(f64s::interleave(1.0, 2.0).addsub(f64s::interleave(0.5, -3.0)), f64s::interleave(0.5, -1.0));
+    }
+}
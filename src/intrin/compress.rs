@@ -0,0 +1,93 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::eq::*;
+
+pub trait Compress : Eq {
+    /// Return a vector containing the elements of `self` for which `mask` is
+    /// set, packed contiguously at the front of the vector in their original
+    /// order. The remaining elements of the vector are zeroed.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let v = u32s::interleave(1, 2);
+    /// let mask = v.eq_mask(u32s(2));
+    /// assert_eq!(v.compress(mask), u32s::halfs(2, 0));
+    /// # }
+    /// ```
+    fn compress(&self, mask: Self::Out) -> Self;
+}
+
+macro_rules! impl_compress {
+    ($vec:tt, $feat:expr, $mmfn:tt) => {
+        impl Compress for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn compress(&self, mask: Self::Out) -> Self {
+                optimized!();
+                unsafe { transmute($mmfn(transmute(*self), mask.to_bitmask())) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn compress(&self, mask: Self::Out) -> Self {
+                fallback!();
+                let mut ret = <Self as Packed>::default();
+                let mut j = 0;
+                for i in 0..Self::WIDTH {
+                    if mask.extract(i) {
+                        ret = ret.replace(j, self.extract(i));
+                        j += 1;
+                    }
+                }
+                ret
+            }
+        }
+    }
+}
+
+macro_rules! test_compress {
+    (($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                let mut v = <$vec as Packed>::default();
+                for i in 0..$vec::WIDTH {
+                    v = v.replace(i, (i + 1) as <$vec as Packed>::Scalar);
+                }
+
+                // Every bit pattern for small vectors, and a sample of them
+                // for large ones.
+                let patterns = if $vec::WIDTH <= 8 { 1u64 << $vec::WIDTH } else { 256 };
+                for p in 0..patterns {
+                    let bits = if $vec::WIDTH <= 8 {
+                        p
+                    } else {
+                        p.wrapping_mul(0x9E3779B97F4A7C15) >> (64 - $vec::WIDTH)
+                    };
+                    let mut mask = <$vec as Eq>::Out::zeroes();
+                    for i in 0..$vec::WIDTH {
+                        mask = mask.replace(i, bits & (1 << i) != 0);
+                    }
+
+                    let mut expected = <$vec as Packed>::default();
+                    let mut j = 0;
+                    for i in 0..$vec::WIDTH {
+                        if mask.extract(i) {
+                            expected = expected.replace(j, v.extract(i));
+                            j += 1;
+                        }
+                    }
+                    assert_eq!(v.compress(mask), expected);
+                }
+            }
+        )*
+    }
+}
@@ -7,7 +7,9 @@
 
 pub trait Destride : Sized {
     fn destride_two(self, other: Self) -> (Self, Self);
+    fn destride_three(self, b: Self, c: Self) -> (Self, Self, Self);
     fn destride_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self);
+    fn destride_eight(self, b: Self, c: Self, d: Self, e: Self, f: Self, g: Self, h: Self) -> (Self, Self, Self, Self, Self, Self, Self, Self);
 }
 
 // TODO: LLVM actually autovectorizes our polyfills, but we should still have an
@@ -42,3 +44,65 @@ macro_rules! destride_four_polyfill {
                    $($d.extract($n + 3)),*))
     }
 }
+
+// Record lengths which don't divide the vector's width straddle vectors, so
+// these gather each lane from wherever it lies.
+macro_rules! destride_three_polyfill {
+    ($self:expr, $b:expr, $c:expr) => {{
+        let vecs = [$self, $b, $c];
+        let width = <Self as crate::vecs::Packed>::WIDTH;
+        let channel = |k: usize| <Self as crate::vec_patterns::Pattern>::from_fn(|l| {
+            let i = 3 * l + k;
+            vecs[i / width].extract(i % width)
+        });
+        (channel(0), channel(1), channel(2))
+    }}
+}
+
+macro_rules! destride_eight_polyfill {
+    ($self:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr) => {{
+        let vecs = [$self, $b, $c, $d, $e, $f, $g, $h];
+        let width = <Self as crate::vecs::Packed>::WIDTH;
+        let channel = |k: usize| <Self as crate::vec_patterns::Pattern>::from_fn(|l| {
+            let i = 8 * l + k;
+            vecs[i / width].extract(i % width)
+        });
+        (channel(0), channel(1), channel(2), channel(3),
+         channel(4), channel(5), channel(6), channel(7))
+    }}
+}
+
+macro_rules! test_destride {
+    (($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                type Scalar = <$vec as Packed>::Scalar;
+                let width = $vec::WIDTH;
+                // The `j`th of some vectors of consecutive records, whose
+                // `i`th element is `i * 5 + 1`
+                let v = |j: usize| <$vec as Pattern>::from_fn(|l| ((j * width + l) * 5 + 1) as Scalar);
+                let check = |channels: &[$vec]| {
+                    let n = channels.len();
+                    for (k, channel) in channels.iter().enumerate() {
+                        for l in 0..width {
+                            assert_eq!(channel.extract(l), ((n * l + k) * 5 + 1) as Scalar);
+                        }
+                    }
+                };
+
+                let (a, b) = v(0).destride_two(v(1));
+                check(&[a, b]);
+
+                let (a, b, c) = v(0).destride_three(v(1), v(2));
+                check(&[a, b, c]);
+
+                let (a, b, c, d) = v(0).destride_four(v(1), v(2), v(3));
+                check(&[a, b, c, d]);
+
+                let (a, b, c, d, e, f, g, h) = v(0).destride_eight(v(1), v(2), v(3), v(4), v(5), v(6), v(7));
+                check(&[a, b, c, d, e, f, g, h]);
+            }
+        )*
+    }
+}
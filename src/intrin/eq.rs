@@ -5,47 +5,46 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::core::ops::BitXor;
+use crate::masks::*;
 use crate::vecs::*;
 
 pub trait Eq : Packed {
-    type Out : Pattern + BitXor<Self::Out, Output = Self::Out>;
+    type Out : Mask;
 
-    /// Return a vector where each element at an index i is filled with 1s if
-    /// the elements of `self` and `other` at index i are equal, and filled with
-    /// zeroes otherwise.
+    /// Return a mask where each element at an index i is set if the elements
+    /// of `self` and `other` at index i are equal, and unset otherwise.
     ///
     /// ```
     /// extern crate faster;
     /// use faster::*;
     ///
     /// # fn main() {
-    /// assert_eq!(u8s::interleave(0, 2).eq_mask(u8s(0)).be_u8s(), u8s::interleave(0xFF, 0).be_u8s());
-    /// assert_eq!(u32s::halfs(1, 0).eq_mask(u32s(0)), u32s::halfs(0, 0xFFFFFFFF));
+    /// assert_eq!(u8s::interleave(0, 2).eq_mask(u8s(0)).to_vector(), u8s::interleave(0xFF, 0));
+    /// assert_eq!(u32s::halfs(1, 0).eq_mask(u32s(0)).to_vector(), u32s::halfs(0, 0xFFFFFFFF));
+    /// assert!(u32s::halfs(1, 0).eq_mask(u32s(0)).any());
     /// # }
     /// ```
     fn eq_mask(&self, other: Self) -> Self::Out;
 
-    /// Return a vector where each element at an index i is filled with 1s if
-    /// the elements of `self` and `other` at index i are not equal, and filled
-    /// with zeroes otherwise.
+    /// Return a mask where each element at an index i is set if the elements
+    /// of `self` and `other` at index i are not equal, and unset otherwise.
     ///
     /// ```
     /// extern crate faster;
     /// use faster::*;
     ///
     /// # fn main() {
-    /// assert_eq!(u8s::interleave(0, 2).ne_mask(u8s(0)), u8s::interleave(0, 0xFF));
-    /// assert_eq!(u32s::halfs(1, 0).ne_mask(u32s(0)), u32s::halfs(0xFFFFFFFF, 0));
+    /// assert_eq!(u8s::interleave(0, 2).ne_mask(u8s(0)).to_vector(), u8s::interleave(0, 0xFF));
+    /// assert_eq!(u32s::halfs(1, 0).ne_mask(u32s(0)).to_vector(), u32s::halfs(0xFFFFFFFF, 0));
     /// # }
     /// ```
     #[inline(always)]
-    fn ne_mask(&self, other: Self) -> Self::Out { self.eq_mask(other) ^ Self::Out::ones() }
+    fn ne_mask(&self, other: Self) -> Self::Out { !self.eq_mask(other) }
 }
 
 macro_rules! rust_fallback_eq {
     (impl $trait:tt for $type:tt where $feat:tt {
-        $($newfn:ident, $rustfn:ident => $mask:tt, $maskel:tt, $mmfn:tt ( $($mmfnargs:expr),* ), [$($n:expr),+]);*;}) => (
+        $($newfn:ident, $rustfn:ident => $mask:tt, $mmfn:tt ( $($mmfnargs:expr),* ), [$($n:expr),+]);*;}) => (
         impl $trait for $type {
             $(
                 type Out = $mask;
@@ -61,14 +60,7 @@ macro_rules! rust_fallback_eq {
                 #[cfg(not(target_feature = $feat))]
                 fn $newfn(&self, other: Self) -> Self::Out {
                     fallback!();
-                    use crate::core::mem::transmute;
-                    unsafe {
-                        Self::Out::new($(transmute(if self.extract($n).$rustfn(&other.extract($n)) {
-                            $maskel::max_value()
-                        } else {
-                            $maskel::min_value()
-                        })),*)
-                    }
+                    Self::Out::new($(self.extract($n).$rustfn(&other.extract($n))),*)
                 }
             )*
         }
@@ -79,16 +71,16 @@ macro_rules! test_packed_eq {
         ($vec:tt, $el:tt, $mask:tt, $maskel:tt, $name:tt) => {
             #[test]
             fn $name() {
-                assert_eq!($vec::halfs(1 as $el, 0 as $el).eq_mask($vec::splat(0 as $el)),
+                assert_eq!($vec::halfs(1 as $el, 0 as $el).eq_mask($vec::splat(0 as $el)).to_vector(),
                            $mask::halfs(0, $maskel::max_value()));
 
-                assert_eq!($vec::interleave(1 as $el, 0 as $el).eq_mask($vec::splat(1 as $el)),
+                assert_eq!($vec::interleave(1 as $el, 0 as $el).eq_mask($vec::splat(1 as $el)).to_vector(),
                            $mask::interleave($maskel::max_value(), 0));
 
-                assert_eq!($vec::halfs(1 as $el, 0 as $el).ne_mask($vec::splat(0 as $el)),
+                assert_eq!($vec::halfs(1 as $el, 0 as $el).ne_mask($vec::splat(0 as $el)).to_vector(),
                            $mask::halfs($maskel::max_value(), 0));
 
-                assert_eq!($vec::interleave(1 as $el, 0 as $el).ne_mask($vec::splat(1 as $el)),
+                assert_eq!($vec::interleave(1 as $el, 0 as $el).ne_mask($vec::splat(1 as $el)).to_vector(),
                            $mask::interleave(0, $maskel::max_value()));
             }
         }
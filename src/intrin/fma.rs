@@ -0,0 +1,134 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Fused multiply-adds. The methods are named after the x86 instructions,
+/// as packed_simd's inherent `mul_add` would shadow a trait method of the
+/// same name.
+pub trait Fma {
+    /// Return a vector containing `(self * a) + b`, computed with a single
+    /// rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.0).fmadd(f32s(3.0), f32s(1.0)), f32s(7.0));
+    /// # }
+    /// ```
+    fn fmadd(&self, a: Self, b: Self) -> Self;
+
+    /// Return a vector containing `(self * a) - b`, computed with a single
+    /// rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f32s(2.0).fmsub(f32s(3.0), f32s(1.0)), f32s(5.0));
+    /// # }
+    /// ```
+    fn fmsub(&self, a: Self, b: Self) -> Self;
+
+    /// Return a vector containing `-(self * a) + b`, computed with a single
+    /// rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(2.0).fnmadd(f64s(3.0), f64s(1.0)), f64s(-5.0));
+    /// # }
+    /// ```
+    fn fnmadd(&self, a: Self, b: Self) -> Self;
+
+    /// Return a vector containing `-(self * a) - b`, computed with a single
+    /// rounding.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(f64s(2.0).fnmsub(f64s(3.0), f64s(1.0)), f64s(-7.0));
+    /// # }
+    /// ```
+    fn fnmsub(&self, a: Self, b: Self) -> Self;
+}
+
+macro_rules! rust_fallback_fma {
+    (impl $trait:tt for $type:tt where $feat:tt {
+        $($newfn:ident => $mmfn:tt, |$x:ident, $a:ident, $b:ident| $rust:expr, [$($n:expr),+]);*;}) => (
+        impl $trait for $type {
+            $(
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn $newfn(&self, a: Self, b: Self) -> Self {
+                    use crate::core::mem::transmute;
+                    optimized!();
+                    unsafe { transmute($mmfn(transmute(*self), transmute(a), transmute(b))) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn $newfn(&self, a: Self, b: Self) -> Self {
+                    fallback!();
+                    // Negation is exact, so every variant rounds only once
+                    Self::new($({
+                        let ($x, $a, $b) = (self.extract($n), a.extract($n), b.extract($n));
+                        $rust
+                    }),*)
+                }
+            )*
+        }
+    );
+}
+
+macro_rules! impl_fma {
+    ($vec:tt, $feat:tt, ($fmadd:tt, $fmsub:tt, $fnmadd:tt, $fnmsub:tt), [$($n:expr),+]) => (
+        rust_fallback_fma! {
+            impl Fma for $vec where $feat {
+                fmadd => $fmadd, |x, a, b| x.mul_add(a, b), [$($n),+];
+                fmsub => $fmsub, |x, a, b| x.mul_add(a, -b), [$($n),+];
+                fnmadd => $fnmadd, |x, a, b| (-x).mul_add(a, b), [$($n),+];
+                fnmsub => $fnmsub, |x, a, b| (-x).mul_add(a, -b), [$($n),+];
+            }
+        }
+    );
+}
+
+macro_rules! test_fma {
+    (($($vec:tt),*), ($($el:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                let vals = [-3.5 as $el, -1.0, -0.0, 0.0, 0.25, 1.0, 7.0, 1.0e10];
+                for &x in vals.iter() {
+                    for &a in vals.iter() {
+                        for &b in vals.iter() {
+                            let (xv, av, bv) = ($vec::splat(x), $vec::splat(a), $vec::splat(b));
+                            assert_eq!(xv.fmadd(av, bv), $vec::splat(x.mul_add(a, b)));
+                            assert_eq!(xv.fmsub(av, bv), $vec::splat(x.mul_add(a, -b)));
+                            assert_eq!(xv.fnmadd(av, bv), $vec::splat((-x).mul_add(a, b)));
+                            assert_eq!(xv.fnmsub(av, bv), $vec::splat((-x).mul_add(a, -b)));
+                        }
+                    }
+                }
+
+                // (1 + e)^2 - (1 + 2e) is lost to rounding unless the
+                // product and difference are computed in one step.
+                let one = 1.0 as $el + $el::EPSILON;
+                let two = 1.0 as $el + 2.0 * $el::EPSILON;
+                let eps2 = $el::EPSILON * $el::EPSILON;
+                assert_eq!($vec::splat(one).fmsub($vec::splat(one), $vec::splat(two)), $vec::splat(eps2));
+                assert_eq!($vec::splat(one).fnmadd($vec::splat(one), $vec::splat(two)), $vec::splat(-eps2));
+            }
+        )*
+    }
+}
@@ -0,0 +1,278 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+use crate::masks::*;
+
+pub trait Gather : Packed {
+    /// A vector of unsigned indices with as many elements as this vector.
+    /// Vectors of 32-bit elements are indexed with 32-bit indices, and
+    /// vectors of 64-bit elements with 64-bit indices.
+    type Indices : Packed;
+
+    /// A mask with as many elements as this vector.
+    type Mask : Mask;
+
+    /// Return the element of `indices` at `lane`, as an index.
+    fn index(indices: &Self::Indices, lane: usize) -> usize;
+
+    /// Return a vector whose `i`th element is `data[indices[i]]`. Every index
+    /// must be in bounds, and must be less than `2^31` (or `2^63` for 64-bit
+    /// indices), because the hardware treats indices as signed.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [10u32, 11, 12, 13, 14, 15, 16, 17, 18, 19];
+    /// let v = unsafe { u32s::gather_unchecked(&data, u32s(9)) };
+    /// assert_eq!(v, u32s(19));
+    /// # }
+    /// ```
+    unsafe fn gather_unchecked(data: &[Self::Scalar], indices: Self::Indices) -> Self;
+
+    /// Return a vector whose `i`th element is `data[indices[i]]` if the `i`th
+    /// element of `mask` is set, or the `i`th element of `src` otherwise.
+    /// Indices of unset elements aren't read, and may be out of bounds.
+    unsafe fn gather_masked_unchecked(data: &[Self::Scalar], indices: Self::Indices, mask: Self::Mask, src: Self) -> Self;
+}
+
+pub trait Scatter : Gather {
+    /// Write the `i`th element of this vector to `data[indices[i]]`, with the
+    /// same requirements on `indices` as `gather_unchecked`. If an index
+    /// appears more than once, the element in the highest lane is written.
+    unsafe fn scatter_unchecked(self, data: &mut [Self::Scalar], indices: Self::Indices);
+
+    /// Write the `i`th element of this vector to `data[indices[i]]` if the
+    /// `i`th element of `mask` is set. Indices of unset elements aren't read,
+    /// and may be out of bounds.
+    unsafe fn scatter_masked_unchecked(self, data: &mut [Self::Scalar], indices: Self::Indices, mask: Self::Mask);
+}
+
+// AVX2 gathers take the base pointer before the indices, and the mask last.
+macro_rules! impl_gather {
+    ($vec:tt, $idx:tt, $mask:tt, $scale:expr, $feat:expr, $gather:tt, $mgather:tt) => {
+        impl Gather for $vec {
+            type Indices = $idx;
+            type Mask = $mask;
+
+            #[inline(always)]
+            fn index(indices: &Self::Indices, lane: usize) -> usize {
+                indices.extract(lane) as usize
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn gather_unchecked(data: &[Self::Scalar], indices: Self::Indices) -> Self {
+                use crate::core::mem::transmute;
+                optimized!();
+                transmute($gather(data.as_ptr() as *const _, transmute(indices), $scale))
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn gather_unchecked(data: &[Self::Scalar], indices: Self::Indices) -> Self {
+                fallback!();
+                gather_polyfill!(Self, data, indices)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn gather_masked_unchecked(data: &[Self::Scalar], indices: Self::Indices, mask: Self::Mask, src: Self) -> Self {
+                use crate::core::mem::transmute;
+                optimized!();
+                transmute($mgather(transmute(src), data.as_ptr() as *const _, transmute(indices), transmute(mask.to_vector()), $scale))
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn gather_masked_unchecked(data: &[Self::Scalar], indices: Self::Indices, mask: Self::Mask, src: Self) -> Self {
+                fallback!();
+                gather_masked_polyfill!(Self, data, indices, mask, src)
+            }
+        }
+    }
+}
+
+// AVX-512 gathers take the indices before the base pointer, and a bitmask
+// before both.
+macro_rules! impl_gather_512 {
+    ($vec:tt, $idx:tt, $mask:tt, $scale:expr, $feat:expr, $gather:tt, $mgather:tt) => {
+        impl Gather for $vec {
+            type Indices = $idx;
+            type Mask = $mask;
+
+            #[inline(always)]
+            fn index(indices: &Self::Indices, lane: usize) -> usize {
+                indices.extract(lane) as usize
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn gather_unchecked(data: &[Self::Scalar], indices: Self::Indices) -> Self {
+                use crate::core::mem::transmute;
+                optimized!();
+                transmute($gather(transmute(indices), data.as_ptr() as *const _, $scale))
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn gather_unchecked(data: &[Self::Scalar], indices: Self::Indices) -> Self {
+                fallback!();
+                gather_polyfill!(Self, data, indices)
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn gather_masked_unchecked(data: &[Self::Scalar], indices: Self::Indices, mask: Self::Mask, src: Self) -> Self {
+                use crate::core::mem::transmute;
+                optimized!();
+                transmute($mgather(transmute(src), mask.to_bitmask() as _, transmute(indices), data.as_ptr() as *const _, $scale))
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn gather_masked_unchecked(data: &[Self::Scalar], indices: Self::Indices, mask: Self::Mask, src: Self) -> Self {
+                fallback!();
+                gather_masked_polyfill!(Self, data, indices, mask, src)
+            }
+        }
+    }
+}
+
+macro_rules! impl_scatter {
+    ($vec:tt, $scale:expr, $feat:expr, $scatter:tt, $mscatter:tt) => {
+        impl Scatter for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn scatter_unchecked(self, data: &mut [Self::Scalar], indices: Self::Indices) {
+                use crate::core::mem::transmute;
+                optimized!();
+                $scatter(data.as_mut_ptr() as *mut _, transmute(indices), transmute(self), $scale)
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn scatter_unchecked(self, data: &mut [Self::Scalar], indices: Self::Indices) {
+                fallback!();
+                for i in 0..Self::WIDTH {
+                    *data.get_unchecked_mut(Self::index(&indices, i)) = self.extract_unchecked(i);
+                }
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn scatter_masked_unchecked(self, data: &mut [Self::Scalar], indices: Self::Indices, mask: Self::Mask) {
+                use crate::core::mem::transmute;
+                optimized!();
+                $mscatter(data.as_mut_ptr() as *mut _, mask.to_bitmask() as _, transmute(indices), transmute(self), $scale)
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn scatter_masked_unchecked(self, data: &mut [Self::Scalar], indices: Self::Indices, mask: Self::Mask) {
+                fallback!();
+                for i in 0..Self::WIDTH {
+                    if mask.extract(i) {
+                        *data.get_unchecked_mut(Self::index(&indices, i)) = self.extract_unchecked(i);
+                    }
+                }
+            }
+        }
+    }
+}
+
+macro_rules! gather_polyfill {
+    ($vec:ty, $data:expr, $indices:expr) => {{
+        let mut ret = <$vec as Packed>::default();
+        for i in 0..<$vec>::WIDTH {
+            ret = ret.replace_unchecked(i, *$data.get_unchecked(<$vec as Gather>::index(&$indices, i)));
+        }
+        ret
+    }}
+}
+
+macro_rules! gather_masked_polyfill {
+    ($vec:ty, $data:expr, $indices:expr, $mask:expr, $src:expr) => {{
+        let mut ret = $src;
+        for i in 0..<$vec>::WIDTH {
+            if $mask.extract(i) {
+                ret = ret.replace_unchecked(i, *$data.get_unchecked(<$vec as Gather>::index(&$indices, i)));
+            }
+        }
+        ret
+    }}
+}
+
+macro_rules! test_gather {
+    (($($vec:tt),*), ($($idx:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                type Scalar = <$vec as Packed>::Scalar;
+                type Index = <$idx as Packed>::Scalar;
+                let width = $vec::WIDTH;
+                let mut data = [0 as Scalar; 64];
+                for (i, x) in data.iter_mut().enumerate() {
+                    *x = (i * 3) as Scalar;
+                }
+                let indices = <$idx as Pattern>::from_fn(|i| ((i * 7 + 3) % 64) as Index);
+                let src = $vec::splat(1 as Scalar);
+
+                let v = unsafe { $vec::gather_unchecked(&data, indices) };
+                for i in 0..width {
+                    assert_eq!(v.extract(i), data[(i * 7 + 3) % 64]);
+                }
+
+                for off in 0..width + 1 {
+                    let mut mask = <$vec as Gather>::Mask::splat(false);
+                    for i in 0..off {
+                        mask = mask.replace(i, true);
+                    }
+                    let v = unsafe { $vec::gather_masked_unchecked(&data, indices, mask, src) };
+                    for i in 0..width {
+                        if mask.extract(i) {
+                            assert_eq!(v.extract(i), data[(i * 7 + 3) % 64]);
+                        } else {
+                            assert_eq!(v.extract(i), src.extract(i));
+                        }
+                    }
+                }
+
+                // Masked-off indices may be out of bounds
+                let wild = <$idx as Pattern>::from_fn(|i| if i % 2 == 0 { i as Index } else { !0 });
+                let mut even = <$vec as Gather>::Mask::splat(false);
+                for i in (0..width).step_by(2) {
+                    even = even.replace(i, true);
+                }
+                let v = unsafe { $vec::gather_masked_unchecked(&data, wild, even, src) };
+                for i in 0..width {
+                    assert_eq!(v.extract(i), if i % 2 == 0 { data[i] } else { src.extract(i) });
+                }
+
+                let mut out = [0 as Scalar; 64];
+                unsafe { v.scatter_unchecked(&mut out, indices); }
+                for i in 0..width {
+                    assert_eq!(out[(i * 7 + 3) % 64], v.extract(i));
+                }
+
+                // The highest lane wins when indices collide
+                let mut out = [0 as Scalar; 64];
+                let ramp = <$vec as Pattern>::from_fn(|i| (i + 1) as Scalar);
+                unsafe { ramp.scatter_unchecked(&mut out, <$idx as Packed>::splat(5 as Index)); }
+                assert_eq!(out[5], width as Scalar);
+
+                let mut out = [0 as Scalar; 64];
+                unsafe { ramp.scatter_masked_unchecked(&mut out, wild, even); }
+                for i in 0..width {
+                    assert_eq!(out[i], if i % 2 == 0 { (i + 1) as Scalar } else { 0 as Scalar });
+                }
+            }
+        )*
+    }
+}
@@ -0,0 +1,90 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// The inverse of [`Destride`], which interleaves the elements of vectors.
+///
+/// [`Destride`]: ../destride/trait.Destride.html
+pub trait Interleave : Sized {
+    /// Return two vectors containing the elements of `self` and `other`
+    /// alternately, beginning with the first element of `self`. The first
+    /// vector contains the first half of each vector's elements, and the
+    /// second vector contains the second half.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let (a, b) = u32x4::new(0, 2, 4, 6).interleave_two(u32x4::new(1, 3, 5, 7));
+    /// assert_eq!(a, u32x4::new(0, 1, 2, 3));
+    /// assert_eq!(b, u32x4::new(4, 5, 6, 7));
+    /// # }
+    /// ```
+    fn interleave_two(self, other: Self) -> (Self, Self);
+
+    /// Return four vectors containing the elements of `self`, `b`, `c` and
+    /// `d` in turn, beginning with the first element of `self`.
+    #[inline(always)]
+    fn interleave_four(self, b: Self, c: Self, d: Self) -> (Self, Self, Self, Self) {
+        // Interleaving pairs of pairs interleaves all four
+        let (ac0, ac1) = self.interleave_two(c);
+        let (bd0, bd1) = b.interleave_two(d);
+        let (w, x) = ac0.interleave_two(bd0);
+        let (y, z) = ac1.interleave_two(bd1);
+        (w, x, y, z)
+    }
+}
+
+macro_rules! interleave_two_polyfill {
+    ($self:expr, $other:expr, $half:expr, $($n:expr),*) => {
+        (Self::new($($self.extract($n), $other.extract($n)),*),
+         Self::new($($self.extract($n + $half), $other.extract($n + $half)),*))
+    }
+}
+
+macro_rules! impl_interleave_polyfill {
+    ($vec:tt, $half:expr, $($n:expr),*) => {
+        impl Interleave for $vec {
+            #[inline(always)]
+            fn interleave_two(self, other: Self) -> (Self, Self) {
+                fallback!();
+                interleave_two_polyfill!(self, other, $half, $($n),*)
+            }
+        }
+    }
+}
+
+macro_rules! test_interleave {
+    (($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                use crate::vecs::Packable;
+
+                let w = $vec::WIDTH;
+                let a = <$vec as Pattern>::from_fn(|i| <$vec as Packed>::Scalar::from_usize(i));
+                let b = <$vec as Pattern>::from_fn(|i| <$vec as Packed>::Scalar::from_usize(w + i));
+                let c = <$vec as Pattern>::from_fn(|i| <$vec as Packed>::Scalar::from_usize(2 * w + i));
+                let d = <$vec as Pattern>::from_fn(|i| <$vec as Packed>::Scalar::from_usize(3 * w + i));
+
+                let (x, y) = a.interleave_two(b);
+                for i in 0..(2 * w) {
+                    let out = if i < w { x.extract(i) } else { y.extract(i - w) };
+                    let inp = if i % 2 == 0 { a.extract(i / 2) } else { b.extract(i / 2) };
+                    assert_eq!(out, inp);
+                }
+
+                let (x, y, z, q) = a.interleave_four(b, c, d);
+                let outs = [x, y, z, q];
+                let inps = [a, b, c, d];
+                for i in 0..(4 * w) {
+                    assert_eq!(outs[i / w].extract(i % w), inps[i % 4].extract(i / 4));
+                }
+            }
+        )*
+    }
+}
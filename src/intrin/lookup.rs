@@ -0,0 +1,129 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait Lookup : Packed {
+    /// Return a vector where each element is the entry of `table` indexed by
+    /// the low four bits of the corresponding element of `self`. The high four
+    /// bits of each element are ignored.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let hex = *b"0123456789abcdef";
+    /// assert_eq!(u8s::interleave(0x0A, 0xF3).lookup16(&hex), u8s::interleave(b'a', b'3'));
+    /// # }
+    /// ```
+    fn lookup16(&self, table: &[Self::Scalar; 16]) -> Self;
+
+    /// Return a vector where each element is the entry of `table` indexed by
+    /// the corresponding element of `self`, interpreted as a `u8`. Elements
+    /// which index past the end of `table` become zero.
+    ///
+    /// Tables are looked up 16 entries at a time, so shorter tables are
+    /// faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `table` is not a multiple of 16, or is greater
+    /// than 256.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut upper = [0u8; 128];
+    /// for (i, x) in upper.iter_mut().enumerate() {
+    ///     *x = (i as u8).to_ascii_uppercase();
+    /// }
+    /// assert_eq!(u8s::interleave(b'a', 0xFF).lookup_bytes(&upper), u8s::interleave(b'A', 0));
+    /// # }
+    /// ```
+    fn lookup_bytes(&self, table: &[Self::Scalar]) -> Self;
+}
+
+macro_rules! impl_lookup {
+    ($vec:tt, $el:tt, $feat:expr, ($lookup16:tt, $lookup_bytes:tt)) => {
+        impl Lookup for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn lookup16(&self, table: &[Self::Scalar; 16]) -> Self {
+                optimized!();
+                unsafe { transmute($lookup16(transmute(*self), transmute::<&[$el], &[u8]>(&table[..]))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn lookup16(&self, table: &[Self::Scalar; 16]) -> Self {
+                fallback!();
+                let mut ret = *self;
+                for i in 0..Self::WIDTH {
+                    ret = ret.replace(i, table[(self.extract(i) as u8 & 0x0F) as usize]);
+                }
+                ret
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn lookup_bytes(&self, table: &[Self::Scalar]) -> Self {
+                optimized!();
+                assert!(table.len() % 16 == 0 && table.len() <= 256);
+                unsafe { transmute($lookup_bytes(transmute(*self), transmute::<&[$el], &[u8]>(table))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn lookup_bytes(&self, table: &[Self::Scalar]) -> Self {
+                fallback!();
+                assert!(table.len() % 16 == 0 && table.len() <= 256);
+                let mut ret = *self;
+                for i in 0..Self::WIDTH {
+                    let idx = self.extract(i) as u8 as usize;
+                    ret = ret.replace(i, if idx < table.len() { table[idx] } else { 0 });
+                }
+                ret
+            }
+        }
+    }
+}
+
+macro_rules! test_lookup {
+    (($($vec:tt),*), ($($el:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                let mut table = [0 as $el; 256];
+                for (i, x) in table.iter_mut().enumerate() {
+                    *x = (i * 37 + 11) as u8 as $el;
+                }
+                let mut table16 = [0 as $el; 16];
+                table16.copy_from_slice(&table[..16]);
+
+                for start in (0..256).step_by($vec::WIDTH) {
+                    let mut v = <$vec as Packed>::default();
+                    for i in 0..$vec::WIDTH {
+                        v = v.replace(i, ((start + i * 3) % 256) as u8 as $el);
+                    }
+
+                    let nibbles = v.lookup16(&table16);
+                    let full = v.lookup_bytes(&table);
+                    let partial = v.lookup_bytes(&table[..48]);
+                    for i in 0..$vec::WIDTH {
+                        let idx = v.extract(i) as u8 as usize;
+                        assert_eq!(nibbles.extract(i), table[idx & 0x0F]);
+                        assert_eq!(full.extract(i), table[idx]);
+                        assert_eq!(partial.extract(i), if idx < 48 { table[idx] } else { 0 });
+                    }
+                }
+            }
+        )*
+    }
+}
@@ -14,8 +14,9 @@ macro_rules! rust_fallback_impl {
                 #[inline(always)]
                 #[cfg(target_feature = $feat)]
                 fn $rustfn(&self) -> Self {
+                    use crate::core::mem::transmute;
                     optimized!();
-                    unsafe { $mmfn(*self, $($mmfnargs),*) }
+                    unsafe { transmute($mmfn(transmute(*self), $($mmfnargs),*)) }
                 }
 
                 #[inline(always)]
@@ -6,27 +6,39 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 pub mod abs;
-pub mod addsub;
+#[macro_use] pub mod addsub;
 pub mod cast;
 pub mod cmp;
+#[macro_use] pub mod compress;
 #[macro_use] pub mod destride;
 pub mod downcast;
 #[macro_use] pub mod endian;
 #[macro_use] pub mod eq;
+#[macro_use] pub mod fma;
+#[macro_use] pub mod gather;
 pub mod hadd;
 pub mod hsub;
+#[macro_use] pub mod interleave;
+#[macro_use] pub mod lookup;
 #[macro_use] pub mod macros;
 #[macro_use] pub mod merge;
+#[macro_use] pub mod ord;
 #[macro_use] pub mod popcnt;
+pub mod prefetch;
+#[macro_use] pub mod prefix_sum;
 pub mod recip;
 pub mod round;
 pub mod rsqrt;
+#[macro_use] pub mod select;
+#[macro_use] pub mod shift;
 #[macro_use] pub mod sum;
 pub mod saturating_add;
 pub mod saturating_hadd;
 pub mod saturating_hsub;
 pub mod saturating_sub;
 pub mod sqrt;
+#[macro_use] pub mod stream;
+#[macro_use] pub mod swizzle;
 #[macro_use] pub mod transmute;
 pub mod upcast;
 
@@ -37,23 +49,35 @@ pub(crate) mod prelude {
     pub use super::addsub::*;
     pub use super::cast::*;
     pub use super::cmp::*;
+    pub use super::compress::*;
     pub use super::destride::*;
     pub use super::downcast::*;
     pub use super::endian::*;
     pub use super::eq::*;
+    pub use super::fma::*;
+    pub use super::gather::*;
     pub use super::hadd::*;
     pub use super::hsub::*;
+    pub use super::interleave::*;
+    pub use super::lookup::*;
     pub use super::merge::*;
+    pub use super::ord::*;
     pub use super::popcnt::*;
+    pub use super::prefetch::*;
+    pub use super::prefix_sum::*;
     pub use super::recip::*;
     pub use super::round::*;
     pub use super::rsqrt::*;
+    pub use super::select::*;
+    pub use super::shift::*;
     pub use super::sum::*;
     pub use super::saturating_add::*;
     pub use super::saturating_hadd::*;
     pub use super::saturating_hsub::*;
     pub use super::saturating_sub::*;
     pub use super::sqrt::*;
+    pub use super::stream::*;
+    pub use super::swizzle::*;
     pub use super::transmute::*;
     pub use super::upcast::*;
 }
@@ -0,0 +1,150 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::intrin::eq::*;
+use crate::vecs::*;
+
+pub trait PartialOrdMask : Eq {
+    /// Return a mask where each element at an index i is set if the element of
+    /// `self` at index i is greater than the element of `other` at index i, and
+    /// unset otherwise.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(0, 200).gt_mask(u8s(100)).to_vector(), u8s::interleave(0, 0xFF));
+    /// assert_eq!(i32s::halfs(-1, 1).gt_mask(i32s(0)).to_vector(), u32s::halfs(0, 0xFFFFFFFF));
+    /// # }
+    /// ```
+    fn gt_mask(&self, other: Self) -> Self::Out;
+
+    /// Return a mask where each element at an index i is set if the element of
+    /// `self` at index i is less than the element of `other` at index i, and unset
+    /// otherwise.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::interleave(0, 200).lt_mask(u8s(100)).to_vector(), u8s::interleave(0xFF, 0));
+    /// assert_eq!(f32s::halfs(-1.0, 1.0).lt_mask(f32s(0.0)).to_vector(), u32s::halfs(0xFFFFFFFF, 0));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn lt_mask(&self, other: Self) -> Self::Out { other.gt_mask(*self) }
+
+    /// Return a mask where each element at an index i is set if the element of
+    /// `self` at index i is greater than or equal to the element of `other` at
+    /// index i, and unset otherwise.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::interleave(100, 99).ge_mask(u16s(100)).to_vector(), u16s::interleave(0xFFFF, 0));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn ge_mask(&self, other: Self) -> Self::Out { !self.lt_mask(other) }
+
+    /// Return a mask where each element at an index i is set if the element of
+    /// `self` at index i is less than or equal to the element of `other` at index
+    /// i, and unset otherwise.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u16s::interleave(100, 101).le_mask(u16s(100)).to_vector(), u16s::interleave(0xFFFF, 0));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn le_mask(&self, other: Self) -> Self::Out { !self.gt_mask(other) }
+}
+
+macro_rules! rust_fallback_ord {
+    (impl $trait:tt for $type:tt where $feat:tt {
+        $($newfn:ident, $rustfn:ident => $mmfn:tt ( $($mmfnargs:expr),* ), [$($n:expr),+]);*;}) => (
+        impl $trait for $type {
+            $(
+                #[inline(always)]
+                #[cfg(target_feature = $feat)]
+                fn $newfn(&self, other: Self) -> Self::Out {
+                    use crate::core::mem::transmute;
+                    optimized!();
+                    unsafe { transmute($mmfn(transmute(*self), transmute(other), $($mmfnargs),*)) }
+                }
+
+                #[inline(always)]
+                #[cfg(not(target_feature = $feat))]
+                fn $newfn(&self, other: Self) -> Self::Out {
+                    fallback!();
+                    Self::Out::new($(self.extract($n).$rustfn(&other.extract($n))),*)
+                }
+            )*
+        }
+    );
+}
+
+macro_rules! test_packed_ord {
+    ($vec:tt, $el:tt, $mask:tt, $maskel:tt, $name:tt) => {
+        #[test]
+        fn $name() {
+            // Compare every pair of interesting values against Rust's scalar
+            // comparison operators, in both lanes of an interleaved vector.
+            let vals = [$el::min_value(), (0 as $el), (1 as $el), ($el::max_value() / (2 as $el)),
+                        ($el::max_value() / (2 as $el) + (1 as $el)), $el::max_value()];
+            let to_mask = |b: bool| if b { $maskel::max_value() } else { 0 };
+            for &a in vals.iter() {
+                for &b in vals.iter() {
+                    let x = $vec::interleave(a, b);
+                    let y = $vec::interleave(b, a);
+                    assert_eq!(x.gt_mask(y).to_vector(), $mask::interleave(to_mask(a > b), to_mask(b > a)));
+                    assert_eq!(x.lt_mask(y).to_vector(), $mask::interleave(to_mask(a < b), to_mask(b < a)));
+                    assert_eq!(x.ge_mask(y).to_vector(), $mask::interleave(to_mask(a >= b), to_mask(b >= a)));
+                    assert_eq!(x.le_mask(y).to_vector(), $mask::interleave(to_mask(a <= b), to_mask(b <= a)));
+                }
+            }
+        }
+    }
+}
+
+macro_rules! test_packed_ord_float {
+    ($vec:tt, $el:tt, $mask:tt, $maskel:tt, $name:tt) => {
+        #[test]
+        fn $name() {
+            // Every comparison with NaN is false, and -0.0 equals 0.0.
+            let vals = [$el::NAN, -(0 as $el), (0 as $el), -(1 as $el), (1 as $el),
+                        $el::MIN_POSITIVE / (2 as $el), $el::NEG_INFINITY, $el::INFINITY];
+            let to_mask = |b: bool| if b { $maskel::max_value() } else { 0 };
+            for &a in vals.iter() {
+                for &b in vals.iter() {
+                    let x = $vec::interleave(a, b);
+                    let y = $vec::interleave(b, a);
+                    assert_eq!(x.gt_mask(y).to_vector(), $mask::interleave(to_mask(a > b), to_mask(b > a)));
+                    assert_eq!(x.lt_mask(y).to_vector(), $mask::interleave(to_mask(a < b), to_mask(b < a)));
+                    assert_eq!(x.ge_mask(y).to_vector(), $mask::interleave(to_mask(a >= b), to_mask(b >= a)));
+                    assert_eq!(x.le_mask(y).to_vector(), $mask::interleave(to_mask(a <= b), to_mask(b <= a)));
+                }
+            }
+
+            let nan = $vec::splat($el::NAN);
+            for &a in vals.iter() {
+                let x = $vec::splat(a);
+                for m in [x.gt_mask(nan), x.lt_mask(nan), x.ge_mask(nan), x.le_mask(nan),
+                          nan.gt_mask(x), nan.lt_mask(x), nan.ge_mask(x), nan.le_mask(x)].iter() {
+                    assert!(m.none());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// The cache level which a prefetch should pull data into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Locality {
+    /// Every level of the cache
+    L1,
+    /// The second level of the cache and above
+    L2,
+    /// The third level of the cache and above
+    L3,
+    /// As close to the processor as possible, without polluting the cache.
+    /// This suits data which is only read once.
+    NonTemporal,
+}
+
+/// The size of a cache line, in bytes. Iterators issue one prefetch per line.
+pub(crate) const CACHE_LINE: usize = 64;
@@ -0,0 +1,128 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait PrefixSum : Packed {
+    /// Return a vector where each element at an index i is the sum of the
+    /// elements of `self` at indices 0 through i, inclusive.
+    ///
+    /// The sum is computed in log2(`Self::WIDTH`) steps, each of which adds
+    /// the vector to a copy of itself shifted up by 1, 2, 4, ... elements, so
+    /// float sums are associated as a tree rather than from left to right.
+    /// Integer sums wrap on overflow.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(1).prefix_sum().extract(u32s::WIDTH - 1), u32s::WIDTH as u32);
+    /// assert_eq!(u32s(1).prefix_sum().extract(0), 1);
+    /// # }
+    /// ```
+    fn prefix_sum(&self) -> Self;
+
+    /// Return a vector where each element at an index i is the sum of the
+    /// elements of `self` at indices 0 through i, exclusive. The first
+    /// element of the result is always zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(1).exclusive_prefix_sum().extract(u32s::WIDTH - 1), u32s::WIDTH as u32 - 1);
+    /// assert_eq!(u32s(1).exclusive_prefix_sum().extract(0), 0);
+    /// # }
+    /// ```
+    fn exclusive_prefix_sum(&self) -> Self;
+}
+
+macro_rules! impl_prefix_sum {
+    ($vec:tt, $feat:expr, $shift:tt, $size:expr, [$($bytes:expr),*]) => {
+        impl PrefixSum for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn prefix_sum(&self) -> Self {
+                optimized!();
+                let mut ret = *self;
+                $(
+                    let shifted: Self = unsafe { $shift!(ret, $bytes) };
+                    ret = ret + shifted;
+                )*
+                ret
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            fn exclusive_prefix_sum(&self) -> Self {
+                optimized!();
+                let mut ret: Self = unsafe { $shift!(*self, $size) };
+                $(
+                    let shifted: Self = unsafe { $shift!(ret, $bytes) };
+                    ret = ret + shifted;
+                )*
+                ret
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn prefix_sum(&self) -> Self {
+                fallback!();
+                // Take the same steps as the vectorized version, so floats
+                // are summed in the same order on every machine.
+                let mut ret = *self;
+                let mut step = 1;
+                while step < Self::WIDTH {
+                    let mut shifted = <Self as Packed>::default();
+                    for i in step..Self::WIDTH {
+                        shifted = shifted.replace(i, ret.extract(i - step));
+                    }
+                    ret = ret + shifted;
+                    step *= 2;
+                }
+                ret
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            fn exclusive_prefix_sum(&self) -> Self {
+                fallback!();
+                let mut shifted = <Self as Packed>::default();
+                for i in 1..Self::WIDTH {
+                    shifted = shifted.replace(i, self.extract(i - 1));
+                }
+                shifted.prefix_sum()
+            }
+        }
+    }
+}
+
+macro_rules! test_prefix_sum {
+    (($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                // Keep the sums small enough not to overflow 8-bit elements
+                let mut v = <$vec as Packed>::default();
+                for i in 0..$vec::WIDTH {
+                    v = v.replace(i, (i % 3 / 2 + i % 2) as <$vec as Packed>::Scalar);
+                }
+
+                let inclusive = v.prefix_sum();
+                let exclusive = v.exclusive_prefix_sum();
+                let mut acc = 0 as <$vec as Packed>::Scalar;
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(exclusive.extract(i), acc);
+                    acc = acc + v.extract(i);
+                    assert_eq!(inclusive.extract(i), acc);
+                }
+            }
+        )*
+    }
+}
@@ -7,7 +7,9 @@
 
 pub trait Recip {
     /// Return a vector containing an estimation of the reciprocal of the
-    /// corresponding elements of `self`.
+    /// corresponding elements of `self`. For normal inputs with normal
+    /// reciprocals, the relative error is at most 1.5 * 2^-12, which is
+    /// within 6144 ULPs of an `f32`.
     ///
     /// # Examples
     ///
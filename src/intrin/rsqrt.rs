@@ -9,8 +9,9 @@
 
 pub trait Rsqrt {
     /// Return a vector containing an approximation of the reciprocals of the
-    /// square-roots of elements in `self`. May contain significant float error
-    /// past 10^-3.
+    /// square-roots of elements in `self`. For positive normal inputs, the
+    /// relative error is at most 1.5 * 2^-12, which is within 6144 ULPs of an
+    /// `f32`.
     ///
     /// ```
     /// extern crate faster;
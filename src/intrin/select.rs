@@ -0,0 +1,92 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::masks::*;
+use crate::vecs::*;
+
+pub trait Select<V> : Mask where V : Packed {
+    /// Return a vector containing the elements of `if_true` where this mask is
+    /// set, and the elements of `if_false` where it is not.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let v = i32s::interleave(-5, 5);
+    /// assert_eq!(v.lt_mask(i32s(0)).select(i32s(0), v), i32s::interleave(0, 5));
+    /// assert_eq!(m32s::partition_mask(1).select(f32s(1.0), f32s(2.0)),
+    ///            f32s::partition(2.0, 1.0, 1));
+    /// # }
+    /// ```
+    fn select(&self, if_true: V, if_false: V) -> V;
+}
+
+macro_rules! impl_select {
+    ($mask:ty, $vec:ty, $blendfeat:expr, $blendv:tt, $bitfeat:expr, ($and:tt, $andnot:tt, $or:tt)) => {
+        #[cfg(target_feature = $blendfeat)]
+        impl Select<$vec> for $mask {
+            #[inline(always)]
+            fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                optimized!();
+                unsafe {
+                    transmute($blendv(transmute(if_false), transmute(if_true),
+                                      transmute(self.to_vector())))
+                }
+            }
+        }
+
+        #[cfg(all(not(target_feature = $blendfeat), target_feature = $bitfeat))]
+        impl Select<$vec> for $mask {
+            #[inline(always)]
+            fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                optimized!();
+                unsafe {
+                    let mask = transmute(self.to_vector());
+                    transmute($or($and(mask, transmute(if_true)),
+                                  $andnot(mask, transmute(if_false))))
+                }
+            }
+        }
+
+        #[cfg(all(not(target_feature = $blendfeat), not(target_feature = $bitfeat)))]
+        impl Select<$vec> for $mask {
+            #[inline(always)]
+            fn select(&self, if_true: $vec, if_false: $vec) -> $vec {
+                fallback!();
+                let mut ret = if_false;
+                for i in 0..<$vec as Packed>::WIDTH {
+                    if self.extract(i) {
+                        ret = ret.replace(i, if_true.extract(i));
+                    }
+                }
+                ret
+            }
+        }
+    }
+}
+
+macro_rules! test_select {
+    (($($mask:tt),*), ($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                let asc = 30i32 as <$vec as Packed>::Scalar;
+                let bsc = 5i32 as <$vec as Packed>::Scalar;
+                let a = $vec::splat(asc);
+                let b = $vec::splat(bsc);
+                assert_eq!($mask::ones().select(a, b), a);
+                assert_eq!($mask::zeroes().select(a, b), b);
+
+                for i in 0..$vec::WIDTH {
+                    assert_eq!($mask::partition_mask(i).select(a, b), $vec::partition(bsc, asc, i));
+                    assert_eq!((!$mask::partition_mask(i)).select(a, b), $vec::partition(asc, bsc, i));
+                }
+            }
+        )*
+    }
+}
@@ -0,0 +1,179 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait Shift : Packed {
+    /// The unsigned vector type which holds the shift amount of each element
+    type Amounts : Packed;
+
+    /// Return a vector where each element at an index i is the element of
+    /// `self` at index i shifted left by the element of `amounts` at index i.
+    /// Elements shifted by at least their bit width become zero.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(1).shl_lanes(u32s::interleave(3, 40)), u32s::interleave(8, 0));
+    /// assert_eq!(i8s(-1).shl_lanes(u8s::interleave(1, 7)), i8s::interleave(-2, -128));
+    /// # }
+    /// ```
+    fn shl_lanes(&self, amounts: Self::Amounts) -> Self;
+
+    /// Return a vector where each element at an index i is the element of
+    /// `self` at index i shifted right by the element of `amounts` at index i.
+    /// Signed elements are shifted arithmetically, and elements shifted by at
+    /// least their bit width become zero, or -1 if they were negative.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s(0x80).shr_lanes(u8s::interleave(7, 8)), u8s::interleave(1, 0));
+    /// assert_eq!(i32s(-8).shr_lanes(u32s::interleave(2, 100)), i32s::interleave(-2, -1));
+    /// # }
+    /// ```
+    fn shr_lanes(&self, amounts: Self::Amounts) -> Self;
+
+    /// Return a vector where each element is the corresponding element of
+    /// `self` rotated left by `n` bits.
+    ///
+    /// packed_simd's vectors have an inherent `rotate_left` which takes a
+    /// vector of amounts, so this method must be called through the trait.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(Shift::rotate_left(&u8s(0x81), 1), u8s(0x03));
+    /// assert_eq!(Shift::rotate_left(&i16s(1), 17), i16s(2));
+    /// # }
+    /// ```
+    fn rotate_left(&self, n: u32) -> Self;
+
+    /// Return a vector where each element is the corresponding element of
+    /// `self` rotated right by `n` bits.
+    ///
+    /// packed_simd's vectors have an inherent `rotate_right` which takes a
+    /// vector of amounts, so this method must be called through the trait.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(Shift::rotate_right(&u8s(0x81), 1), u8s(0xC0));
+    /// assert_eq!(Shift::rotate_right(&u64s(1), 64), u64s(1));
+    /// # }
+    /// ```
+    fn rotate_right(&self, n: u32) -> Self;
+}
+
+macro_rules! impl_shift {
+    ($vec:tt, $el:tt, $amounts:tt, $ael:tt, ($shlfeat:expr, $shl:tt), ($shrfeat:expr, $shr:tt)) => {
+        impl Shift for $vec {
+            type Amounts = $amounts;
+
+            #[inline(always)]
+            #[cfg(target_feature = $shlfeat)]
+            fn shl_lanes(&self, amounts: Self::Amounts) -> Self {
+                optimized!();
+                unsafe { transmute($shl(transmute(*self), transmute(amounts))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $shlfeat))]
+            fn shl_lanes(&self, amounts: Self::Amounts) -> Self {
+                fallback!();
+                let bits = (crate::core::mem::size_of::<$el>() * 8) as $ael;
+                let mut ret = *self;
+                for i in 0..Self::WIDTH {
+                    let amt = amounts.extract(i);
+                    ret = ret.replace(i, if amt < bits { self.extract(i) << amt } else { 0 });
+                }
+                ret
+            }
+
+            #[inline(always)]
+            #[cfg(target_feature = $shrfeat)]
+            fn shr_lanes(&self, amounts: Self::Amounts) -> Self {
+                optimized!();
+                unsafe { transmute($shr(transmute(*self), transmute(amounts))) }
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $shrfeat))]
+            fn shr_lanes(&self, amounts: Self::Amounts) -> Self {
+                fallback!();
+                let bits = (crate::core::mem::size_of::<$el>() * 8) as $ael;
+                let mut ret = *self;
+                for i in 0..Self::WIDTH {
+                    let amt = amounts.extract(i);
+                    let x = self.extract(i);
+                    // Shifting twice fills the element with its sign bit
+                    ret = ret.replace(i, if amt < bits { x >> amt } else { (x >> (bits - 1)) >> 1 });
+                }
+                ret
+            }
+
+            #[inline(always)]
+            fn rotate_left(&self, n: u32) -> Self {
+                // Rotate the unsigned bits, so nothing is sign-extended
+                let bits = (crate::core::mem::size_of::<$el>() * 8) as u32;
+                let n = n % bits;
+                let x: $amounts = unsafe { transmute(*self) };
+                let ret = x.shl_lanes($amounts::splat(n as $ael)) | x.shr_lanes($amounts::splat((bits - n) as $ael));
+                unsafe { transmute(ret) }
+            }
+
+            #[inline(always)]
+            fn rotate_right(&self, n: u32) -> Self {
+                let bits = (crate::core::mem::size_of::<$el>() * 8) as u32;
+                Shift::rotate_left(self, bits - n % bits)
+            }
+        }
+    }
+}
+
+macro_rules! test_shift {
+    (($($vec:tt),*), ($($el:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                let bits = (crate::core::mem::size_of::<$el>() * 8) as u32;
+                let vals = [0 as $el, 1 as $el, $el::max_value(), $el::min_value(),
+                            $el::max_value() / (3 as $el), $el::min_value() / (5 as $el) + (1 as $el)];
+
+                for &x in vals.iter() {
+                    let v = $vec::splat(x);
+                    for amt in 0..(bits + 3) {
+                        let mut amounts = <<$vec as Shift>::Amounts as Packed>::default();
+                        for i in 0..$vec::WIDTH {
+                            // Give each element a different amount
+                            amounts = amounts.replace(i, ((amt + i as u32) % (bits + 3)) as _);
+                        }
+
+                        let shl = v.shl_lanes(amounts);
+                        let shr = v.shr_lanes(amounts);
+                        for i in 0..$vec::WIDTH {
+                            let a = (amt + i as u32) % (bits + 3);
+                            assert_eq!(shl.extract(i), x.checked_shl(a).unwrap_or(0));
+                            assert_eq!(shr.extract(i), x.checked_shr(a).unwrap_or(x >> (bits - 1) >> 1));
+                        }
+
+                        assert_eq!(Shift::rotate_left(&v, amt), $vec::splat(x.rotate_left(amt)));
+                        assert_eq!(Shift::rotate_right(&v, amt), $vec::splat(x.rotate_right(amt)));
+                    }
+                }
+            }
+        )*
+    }
+}
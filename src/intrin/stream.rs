@@ -0,0 +1,78 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::vecs::*;
+
+pub trait Stream : Packed {
+    /// Write `Self::WIDTH` elements from this vector to `data`, beginning at
+    /// `offset`, with a non-temporal store which doesn't pull `data` into the
+    /// cache. `&data[offset]` must be aligned to the size of the vector.
+    ///
+    /// Non-temporal stores aren't ordered with other stores, so
+    /// `stream_fence` must be called before the data is read by another
+    /// thread, or written with ordinary stores.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut data = AlignedVec::from_elem(0u32, 100);
+    /// unsafe { u32s(7).stream_unchecked(data.as_padded_mut_slice(), 0); }
+    /// stream_fence();
+    /// assert_eq!(data[0], 7);
+    /// # }
+    /// ```
+    unsafe fn stream_unchecked(self, data: &mut [Self::Scalar], offset: usize);
+}
+
+macro_rules! impl_stream {
+    ($vec:tt, $feat:expr, $mmfn:tt, $ptr:ty) => {
+        impl Stream for $vec {
+            #[inline(always)]
+            #[cfg(target_feature = $feat)]
+            unsafe fn stream_unchecked(self, data: &mut [Self::Scalar], offset: usize) {
+                use crate::core::mem::{size_of, transmute};
+                optimized!();
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                debug_assert!(data[offset..].as_ptr() as usize % size_of::<Self>() == 0);
+                $mmfn(data.as_mut_ptr().add(offset) as *mut $ptr, transmute(self))
+            }
+
+            #[inline(always)]
+            #[cfg(not(target_feature = $feat))]
+            unsafe fn stream_unchecked(self, data: &mut [Self::Scalar], offset: usize) {
+                fallback!();
+                self.store_aligned_unchecked(data, offset)
+            }
+        }
+    }
+}
+
+macro_rules! test_stream {
+    (($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                use crate::core::slice::from_raw_parts_mut;
+
+                // An array of vectors is aligned to the size of the vector
+                let mut buf = [<$vec as Packed>::default(); 3];
+                let data = unsafe {
+                    from_raw_parts_mut(buf.as_mut_ptr() as *mut <$vec as Packed>::Scalar, 3 * $vec::WIDTH)
+                };
+                let v = $vec::splat(9i32 as <$vec as Packed>::Scalar);
+                unsafe { v.stream_unchecked(data, $vec::WIDTH); }
+                stream_fence();
+
+                assert_eq!(<$vec as Packed>::load(data, 0), <$vec as Packed>::default());
+                assert_eq!(<$vec as Packed>::load(data, $vec::WIDTH), v);
+                assert_eq!(<$vec as Packed>::load(data, 2 * $vec::WIDTH), <$vec as Packed>::default());
+            }
+        )*
+    }
+}
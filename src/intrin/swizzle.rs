@@ -5,6 +5,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use crate::vecs::*;
+
 pub trait Swizzle {
     /// Return a vector containing elements of self, but with even and odd
     /// elements swapped in-place. For (n = 0, 2, ... Self::WIDTH), elements at
@@ -43,3 +45,216 @@ macro_rules! impl_packed_swizzle {
         }
     }
 }
+
+pub trait Shuffle : Packed {
+    /// The unsigned vector type which holds the lane indices of a shuffle
+    type Indices : Packed;
+
+    /// Return a vector where each element at an index i is the element of
+    /// `self` at the index given by the element of `indices` at index i. Only
+    /// the low log2(`Self::WIDTH`) bits of each index are used.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::halfs(1, 2).shuffle(u8s(u8s::WIDTH as u8 - 1)), u8s(2));
+    /// assert_eq!(f32s::interleave(1.0, 2.0).shuffle(u32s::interleave(1, 0)), f32s::interleave(2.0, 1.0));
+    /// # }
+    /// ```
+    fn shuffle(&self, indices: Self::Indices) -> Self;
+
+    /// Return a vector containing the elements of `self` in reverse order.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s::halfs(1, 2).reverse(), u32s::halfs(2, 1));
+    /// # }
+    /// ```
+    fn reverse(&self) -> Self;
+
+    /// Return a vector where each element at an index i is the element of
+    /// `self` at index (i + n) % `Self::WIDTH`, like `slice::rotate_left`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s::partition(1, 2, 1).rotate_lanes(1), u32s::partition(2, 1, u32s::WIDTH - 1));
+    /// # }
+    /// ```
+    fn rotate_lanes(&self, n: usize) -> Self;
+
+    /// Return a vector with every element set to the element of `self` at
+    /// index `idx`.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(i16s::interleave(-1, 1).broadcast_lane(1), i16s(1));
+    /// # }
+    /// ```
+    fn broadcast_lane(&self, idx: usize) -> Self;
+}
+
+/// A macro which takes a vector and a list of lane indices known at compile
+/// time, and returns a vector where each element is the element of the given
+/// vector at the corresponding index. Exactly one index must be given for each
+/// element of the vector, and every index must be less than its width; both
+/// are checked at compile time. On x86, this is packed_simd's `shuffle!`, which
+/// lowers to a single shuffle where the target has one.
+///
+/// ```
+/// #[macro_use] extern crate faster;
+/// use faster::*;
+/// use faster::arch::current::vecs::u32x4;
+///
+/// # fn main() {
+/// assert_eq!(shuffle!(u32x4::new(1, 2, 3, 4), [3, 3, 0, 1]), u32x4::new(4, 4, 1, 2));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// #[macro_use] extern crate faster;
+/// use faster::*;
+/// use faster::arch::current::vecs::u32x4;
+///
+/// # fn main() {
+/// shuffle!(u32x4::new(1, 2, 3, 4), [3, 3, 0, 4]);
+/// # }
+/// ```
+#[macro_export] macro_rules! shuffle {
+    ($vec:expr, [$($idx:expr),+]) => ($crate::__shuffle_impl!($vec, [$($idx),+]))
+}
+
+/// The lane indices of a `shuffle!` on a backend without a native shuffle.
+#[doc(hidden)]
+pub trait ShuffleIndices {
+    const INDICES: &'static [u32];
+}
+
+/// Return `vec` shuffled by `I::INDICES`, checking them against the width of
+/// `V` when the shuffle is monomorphized.
+#[doc(hidden)]
+#[inline(always)]
+pub fn shuffle_polyfill<V : Packed, I : ShuffleIndices>(vec: V) -> V {
+    const {
+        assert!(I::INDICES.len() == V::WIDTH, "shuffle! needs one index per element");
+        let mut i = 0;
+        while i < I::INDICES.len() {
+            assert!((I::INDICES[i] as usize) < V::WIDTH, "shuffle! index out of bounds");
+            i += 1;
+        }
+    }
+    let mut ret = vec;
+    for (i, &idx) in I::INDICES.iter().enumerate() {
+        ret = unsafe { ret.replace_unchecked(i, vec.extract_unchecked(idx as usize)) };
+    }
+    ret
+}
+
+macro_rules! impl_packed_shuffle {
+    ($vec:tt, $ivec:tt, $iel:tt, $feat:expr, $mmfn:tt) => {
+        impl Shuffle for $vec {
+            type Indices = $ivec;
+
+            #[cfg(not(target_feature = $feat))]
+            #[inline(always)]
+            fn shuffle(&self, indices: Self::Indices) -> Self {
+                fallback!();
+                let mut ret = *self;
+                for i in 0..Self::WIDTH {
+                    ret = ret.replace(i, self.extract(indices.extract(i) as usize % Self::WIDTH));
+                }
+                ret
+            }
+
+            #[cfg(target_feature = $feat)]
+            #[inline(always)]
+            fn shuffle(&self, indices: Self::Indices) -> Self {
+                optimized!();
+                unsafe { transmute($mmfn(transmute(*self), indices)) }
+            }
+
+            #[inline(always)]
+            fn reverse(&self) -> Self {
+                const WIDTH: usize = <$vec as Packed>::WIDTH;
+                const REVERSE: [$iel; WIDTH] = {
+                    let mut indices = [0; WIDTH];
+                    let mut i = 0;
+                    while i < WIDTH {
+                        indices[i] = (WIDTH - 1 - i) as $iel;
+                        i += 1;
+                    }
+                    indices
+                };
+                self.shuffle(<$ivec as Packed>::load(&REVERSE, 0))
+            }
+
+            #[inline(always)]
+            fn rotate_lanes(&self, n: usize) -> Self {
+                // Two copies of (0..WIDTH), so every rotation is a window.
+                const WIDTH: usize = <$vec as Packed>::WIDTH;
+                const ROTATE: [$iel; WIDTH * 2] = {
+                    let mut indices = [0; WIDTH * 2];
+                    let mut i = 0;
+                    while i < WIDTH * 2 {
+                        indices[i] = (i % WIDTH) as $iel;
+                        i += 1;
+                    }
+                    indices
+                };
+                self.shuffle(<$ivec as Packed>::load(&ROTATE, n % WIDTH))
+            }
+
+            #[inline(always)]
+            fn broadcast_lane(&self, idx: usize) -> Self {
+                Self::splat(self.extract(idx))
+            }
+        }
+    }
+}
+
+macro_rules! test_packed_shuffle {
+    (($($vec:tt),*), ($($fn:ident),*)) => {
+        $(
+            #[test]
+            fn $fn() {
+                let mut v = <$vec as Packed>::default();
+                for i in 0..$vec::WIDTH {
+                    v = v.replace(i, (i + 1) as <$vec as Packed>::Scalar);
+                }
+
+                for i in 0..$vec::WIDTH {
+                    assert_eq!(v.reverse().extract(i), v.extract($vec::WIDTH - 1 - i));
+                    assert_eq!(v.broadcast_lane(i), $vec::splat(v.extract(i)));
+                    for n in 0..($vec::WIDTH * 2) {
+                        assert_eq!(v.rotate_lanes(n).extract(i), v.extract((i + n) % $vec::WIDTH));
+                    }
+                }
+
+                // Pseudorandom indices, which cross every lane boundary and
+                // have bits set above the log2(WIDTH)th.
+                let mut indices = <<$vec as Shuffle>::Indices as Packed>::default();
+                for seed in 0..16usize {
+                    for i in 0..$vec::WIDTH {
+                        let idx = (i * 7 + seed * 13 + (i ^ seed) * 31) % 256;
+                        indices = indices.replace(i, idx as _);
+                    }
+                    let shuffled = v.shuffle(indices);
+                    for i in 0..$vec::WIDTH {
+                        let idx = indices.extract(i) as usize % $vec::WIDTH;
+                        assert_eq!(shuffled.extract(i), v.extract(idx));
+                    }
+                }
+            }
+        )*
+    }
+}
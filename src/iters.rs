@@ -6,6 +6,20 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::vecs::{Packable, Packed};
+use crate::masks::Mask;
+use crate::intrin::eq::Eq;
+use crate::intrin::compress::Compress;
+use crate::intrin::merge::Merge;
+use crate::intrin::prefix_sum::PrefixSum;
+use crate::intrin::stream::Stream;
+use crate::intrin::swizzle::Shuffle;
+use crate::intrin::gather::{Gather, Scatter};
+use crate::intrin::prefetch::{Locality, CACHE_LINE};
+use crate::arch::current::intrin::prelude::{prefetch, stream_fence};
+use crate::vec_patterns::Pattern;
+use crate::zip::{SIMDZippedObject, SIMDZippedIterable, SIMDZippedIterator, PackedTuple};
+use crate::core::ops::Add;
+use crate::core::mem::size_of;
 use crate::core::slice::from_raw_parts;
 
 pub trait SIMDObject : Sized {
@@ -57,17 +71,6 @@ pub trait SIMDIterable : SIMDObject + SIMDSized + ExactSizeIterator<Item = <Self
             iter: self,
         }
     }
-
-    #[inline(always)]
-    /// Create an iterator which returns `amt` vectors at a time.
-    fn unroll<'a>(&'a mut self, amt: usize) -> Unrolled<'a, Self> {
-        assert!(amt <= 8);
-        Unrolled {
-            iter: self,
-            amt: amt,
-            scratch: [<Self as SIMDObject>::Vector::default(); 8]
-        }
-    }
 }
 
 /// An iterator which automatically packs the values it iterates over into SIMD
@@ -90,6 +93,171 @@ pub trait SIMDIterator : SIMDIterable {
         }
     }
 
+    #[inline(always)]
+    /// Return an iterator which calls `func` on vectors of elements, where
+    /// `func` returns a tuple of vectors. The results may be stored to
+    /// separate buffers with [`unzip_into`] or [`scalar_collect_unzip`].
+    ///
+    /// [`unzip_into`]: ../zip/trait.SIMDZippedIterator.html#method.unzip_into
+    /// [`scalar_collect_unzip`]: ../zip/trait.SIMDZippedIterator.html#method.scalar_collect_unzip
+    fn simd_map_tuple<A, F>(self, func: F) -> SIMDMapTuple<Self, F>
+        where F : FnMut(Self::Vector) -> A, A : PackedTuple {
+        SIMDMapTuple {
+            iter: self,
+            func: func,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator which yields arrays of `N` consecutive vectors of
+    /// this iterator. The vectors which don't fill a whole array are returned
+    /// by [`Unrolled::end`], along with the partial vector of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [1u32; 100];
+    /// let mut iter = (&data[..]).simd_iter(u32s(0)).unroll::<4>();
+    /// let mut sum = u32s(0);
+    /// while let Some([a, b, c, d]) = iter.next() {
+    ///     sum = sum + ((a + b) + (c + d));
+    /// }
+    /// if let Some((rest, n, _)) = iter.end() {
+    ///     for v in &rest[..n] {
+    ///         sum = sum + *v;
+    ///     }
+    /// }
+    /// assert_eq!(sum.sum(), 100);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// [`Unrolled::end`]: struct.Unrolled.html#method.end
+    fn unroll<const N: usize>(self) -> Unrolled<Self, N> {
+        assert!(N > 0, "can't unroll into groups of zero vectors");
+        Unrolled {
+            pending: [self.default(); N],
+            pending_len: 0,
+            iter: self,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator over the running totals of this iterator, beginning
+    /// at `start`. The `i`th element of the result is the sum of `start` and
+    /// elements `0` through `i` of this iterator, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// # #[cfg(feature = "std")] {
+    /// let offsets = (&[1u32, 2, 3, 4, 5][..]).simd_iter(u32s(0))
+    ///     .simd_scan(10)
+    ///     .scalar_collect();
+    /// assert_eq!(offsets, vec![11, 13, 16, 20, 25]);
+    /// # }
+    /// # }
+    /// ```
+    ///
+    /// # Footgun Warning
+    ///
+    /// Integer totals wrap on overflow, and are always equal to those of a
+    /// scalar scan. Float totals are not: each vector is summed as a tree by
+    /// [`PrefixSum::prefix_sum`], and the result is added to the total of
+    /// the previous vectors. The grouping of the additions therefore depends
+    /// on the width of the machine's vectors.
+    ///
+    /// [`PrefixSum::prefix_sum`]: ../intrin/prefix_sum/trait.PrefixSum.html#tymethod.prefix_sum
+    fn simd_scan(self, start: Self::Scalar) -> SIMDScan<Self>
+        where Self::Vector : PrefixSum + Add<Self::Vector, Output = Self::Vector> {
+        SIMDScan {
+            iter: self,
+            carry: Self::Vector::splat(start),
+            exclusive: false,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator over the running totals of this iterator, beginning
+    /// at `start`. The `i`th element of the result is the sum of `start` and
+    /// elements `0` through `i` of this iterator, exclusive. See
+    /// [`simd_scan`] for the order in which floats are summed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// # #[cfg(feature = "std")] {
+    /// let offsets = (&[1u32, 2, 3, 4, 5][..]).simd_iter(u32s(0))
+    ///     .simd_scan_exclusive(10)
+    ///     .scalar_collect();
+    /// assert_eq!(offsets, vec![10, 11, 13, 16, 20]);
+    /// # }
+    /// # }
+    /// ```
+    ///
+    /// [`simd_scan`]: #method.simd_scan
+    fn simd_scan_exclusive(self, start: Self::Scalar) -> SIMDScan<Self>
+        where Self::Vector : PrefixSum + Add<Self::Vector, Output = Self::Vector> {
+        SIMDScan {
+            iter: self,
+            carry: Self::Vector::splat(start),
+            exclusive: true,
+        }
+    }
+
+    #[inline(always)]
+    /// Return an iterator which yields the index of each element alongside
+    /// its value, as a tuple of vectors. This iterator may be mapped, reduced
+    /// and otherwise used like the result of [`zip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// # #[cfg(feature = "std")] {
+    /// let weighted = (&[5u32, 5, 5, 5, 5][..]).simd_iter(u32s(0))
+    ///     .simd_enumerate()
+    ///     .simd_map(|(i, v)| i * v)
+    ///     .scalar_collect();
+    /// assert_eq!(weighted, vec![0, 5, 10, 15, 20]);
+    /// # }
+    /// # }
+    /// ```
+    ///
+    /// # Footgun Warning
+    ///
+    /// The indices are vectors of the iterator's scalar type, so they wrap
+    /// for narrow integers (after 255 for `u8s`), and floats can't represent
+    /// every index above 2^24.
+    ///
+    /// [`zip`]: ../zip/trait.IntoSIMDZip.html#tymethod.zip
+    fn simd_enumerate(self) -> SIMDEnumerate<Self>
+        where Self::Vector : Pattern + Add<Self::Vector, Output = Self::Vector> {
+        SIMDEnumerate {
+            indices: Self::Vector::iota(Self::Scalar::from_usize(0), Self::Scalar::from_usize(1)),
+            step: Self::Vector::splat(Self::Scalar::from_usize(Self::Vector::WIDTH)),
+            iter: self,
+        }
+    }
+
     #[inline(always)]
     /// Pack and run `func` over the iterator, returning no value and not
     /// modifying the iterator.
@@ -154,6 +322,10 @@ pub trait SIMDIterator : SIMDIterable {
     ///
     /// [`Packed::sum`]: vecs/trait.Packed.html#tymethod.sum
     /// [`Packed::product`]: vecs/trait.Packed.html#tymethod.product
+    /// [`simd_reduce_portable`]: #method.simd_reduce_portable
+    ///
+    /// If the result must be identical on every target, use
+    /// [`simd_reduce_portable`] instead.
     fn simd_reduce<A, F>(&mut self, mut start: A, mut func: F) -> A
         where F : FnMut(A, Self::Vector) -> A {
 
@@ -165,8 +337,242 @@ pub trait SIMDIterator : SIMDIterable {
         }
         start
     }
+
+    #[inline(always)]
+    /// Return a vector generated by reducing `func` over the vectors of this
+    /// iterator into `N` independent accumulators, each initialized to
+    /// `start`, and then combining the accumulators with `combine` in a
+    /// pairwise tree. Vector `i` of the iterator is accumulated into
+    /// accumulator `i % N`, and the partial vector from [`end`] into the
+    /// accumulator after the last full vector.
+    ///
+    /// Unlike [`simd_reduce`], whose every call of `func` depends upon the
+    /// previous one, this keeps `N` calls in flight at once, which hides the
+    /// latency of operations like floating-point addition. `start` must be an
+    /// identity of `func` and `combine`, as it's counted `N` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let sum = (&[1.0f32; 1000][..]).simd_iter(f32s(0.0))
+    ///     .simd_reduce_n::<4>(f32s(0.0), |acc, v| acc + v, |a, b| a + b)
+    ///     .sum();
+    /// assert_eq!(sum, 1000.0);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// [`end`]: #tymethod.end
+    /// [`simd_reduce`]: #method.simd_reduce
+    fn simd_reduce_n<const N: usize>(&mut self, start: Self::Vector,
+                                     mut func: impl FnMut(Self::Vector, Self::Vector) -> Self::Vector,
+                                     mut combine: impl FnMut(Self::Vector, Self::Vector) -> Self::Vector)
+                                     -> Self::Vector {
+        assert!(N > 0, "simd_reduce_n needs at least one accumulator");
+        let mut acc = [start; N];
+        let mut i = 0;
+
+        'outer: loop {
+            // N is a constant, so this loop is unrolled
+            for a in acc.iter_mut() {
+                match self.next() {
+                    Some(v) => *a = func(*a, v),
+                    None => break 'outer,
+                }
+                i += 1;
+            }
+        }
+        if let Some((v, _)) = self.end() {
+            acc[i % N] = func(acc[i % N], v);
+        }
+
+        // Fold the top half of the live accumulators into the bottom half
+        let mut live = N;
+        while live > 1 {
+            let half = live / 2;
+            for j in 0..half {
+                acc[j] = combine(acc[j], acc[j + live - half]);
+            }
+            live -= half;
+        }
+        acc[0]
+    }
+
+    #[inline(always)]
+    /// Return a scalar generated by reducing `func` over the values of this
+    /// iterator in [`PORTABLE_WIDTH`] logical lanes, each initialized to
+    /// `start`, and then combining those lanes with `combine` in a fixed
+    /// pairwise tree.
+    ///
+    /// Element `i` of the iterator is always accumulated into logical lane
+    /// `i % PORTABLE_WIDTH`, and the iterator is padded with its default
+    /// vector until every logical lane has received the same number of
+    /// values. As long as `func` operates on each lane independently, the
+    /// result is bit-identical on every architecture and vector width, which
+    /// makes this suitable for floating-point sums which must match across
+    /// machines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [0.1f32; 1000];
+    /// let narrow = (&data[..]).simd_iter_as::<f32x4>()
+    ///     .simd_reduce_portable(0.0, |acc, v| acc + v, |a, b| a + b);
+    /// let wide = (&data[..]).simd_iter_as::<f32x8>()
+    ///     .simd_reduce_portable(0.0, |acc, v| acc + v, |a, b| a + b);
+    ///
+    /// // Bit-identical, although a sequential sum would round differently
+    /// assert_eq!(narrow.to_bits(), wide.to_bits());
+    /// assert!((narrow - 100.0).abs() < 0.01);
+    /// # }
+    /// ```
+    ///
+    /// [`PORTABLE_WIDTH`]: constant.PORTABLE_WIDTH.html
+    fn simd_reduce_portable<F, G>(&mut self, start: Self::Scalar, mut func: F, mut combine: G) -> Self::Scalar
+        where F : FnMut(Self::Vector, Self::Vector) -> Self::Vector,
+              G : FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar {
+        let width = self.width();
+        let accs = PORTABLE_WIDTH / width;
+        debug_assert!(accs * width == PORTABLE_WIDTH);
+
+        // Accumulator `k` holds logical lanes `k * width..(k + 1) * width`
+        let mut acc = [Self::Vector::splat(start); PORTABLE_WIDTH / MIN_WIDTH];
+        let mut i = 0;
+
+        while let Some(v) = self.next() {
+            acc[i % accs] = func(acc[i % accs], v);
+            i += 1;
+        }
+        if let Some((v, n)) = self.end() {
+            // The partial vector is right-aligned; shift its elements to the
+            // front so they land in the same logical lanes on every target.
+            let mut aligned = self.default();
+            for j in n..width {
+                aligned = aligned.replace(j - n, v.extract(j));
+            }
+            acc[i % accs] = func(acc[i % accs], aligned);
+            i += 1;
+        }
+        while i % accs != 0 {
+            acc[i % accs] = func(acc[i % accs], self.default());
+            i += 1;
+        }
+
+        let mut lanes = [start; PORTABLE_WIDTH];
+        for k in 0..accs {
+            for j in 0..width {
+                lanes[k * width + j] = acc[k].extract(j);
+            }
+        }
+
+        let mut step = PORTABLE_WIDTH / 2;
+        while step > 0 {
+            for j in 0..step {
+                lanes[j] = combine(lanes[j], lanes[j + step]);
+            }
+            step /= 2;
+        }
+        lanes[0]
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    /// Pack and run `func` over the iterator, returning a Vec of every
+    /// element whose lane was set in the mask returned by `func`. Elements
+    /// are returned in their original order, and elements which were only
+    /// filled from the iterator's default vector are never returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let big = (&[1i32, 9, 2, 8, 3, 7, 4, 6, 5][..]).simd_iter(i32s(0))
+    ///     .simd_filter(|v| v.gt_mask(i32s(5)));
+    /// assert_eq!(big, vec![9, 8, 7, 6]);
+    /// # }
+    /// ```
+    fn simd_filter<F>(&mut self, func: F) -> Vec<Self::Scalar>
+        where F : FnMut(Self::Vector) -> <Self::Vector as Eq>::Out, Self::Vector : Compress {
+        let cap = (self.len() + 1) * self.width();
+        let mut ret = Vec::with_capacity(cap);
+        unsafe {
+            ret.set_len(cap);
+            let kept = self.simd_filter_into(&mut ret, func).len();
+            ret.set_len(kept);
+        }
+        ret
+    }
+
+    #[inline(always)]
+    /// Pack and run `func` over the iterator, storing every element whose
+    /// lane was set in the mask returned by `func` contiguously in `fill`,
+    /// and returning the filled prefix of `fill`. Elements which were only
+    /// filled from the iterator's default vector are never stored. Elements
+    /// of `fill` after the returned prefix may be overwritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fill` is too small to hold every element which was kept.
+    fn simd_filter_into<'a, F>(&mut self, fill: &'a mut [Self::Scalar], mut func: F) -> &'a mut [Self::Scalar]
+        where F : FnMut(Self::Vector) -> <Self::Vector as Eq>::Out, Self::Vector : Compress {
+        let mut offset = 0;
+
+        while let Some(v) = self.next() {
+            let mask = func(v);
+            offset = store_compressed(fill, offset, v, mask);
+        }
+        if let Some((v, n)) = self.end() {
+            // The first `n` elements of the last vector come from the default
+            // vector, so we must never keep them.
+            let mask = func(v) & <Self::Vector as Eq>::Out::partition_mask(n);
+            offset = store_compressed(fill, offset, v, mask);
+        }
+        &mut fill[..offset]
+    }
+}
+
+/// Store the elements of `vec` selected by `mask` contiguously at `offset` in
+/// `fill`, returning the offset after the last stored element.
+#[inline(always)]
+fn store_compressed<V>(fill: &mut [V::Scalar], offset: usize, vec: V, mask: V::Out) -> usize
+    where V : Compress {
+    let count = mask.count();
+    let packed = vec.compress(mask);
+    if offset + V::WIDTH <= fill.len() {
+        unsafe { packed.store_unchecked(fill, offset); }
+    } else {
+        for i in 0..count {
+            fill[offset + i] = packed.extract(i);
+        }
+    }
+    offset + count
 }
 
+/// The number of logical lanes used by
+/// [`SIMDIterator::simd_reduce_portable`]. This is a multiple of the width of
+/// every vector type on every supported architecture.
+///
+/// [`SIMDIterator::simd_reduce_portable`]: trait.SIMDIterator.html#method.simd_reduce_portable
+pub const PORTABLE_WIDTH: usize = 64;
+
+// The fewest elements in any vector type, which bounds the number of
+// accumulators `simd_reduce_portable` needs.
+const MIN_WIDTH: usize = 2;
+
 /// A trait defining a SIMD iterator over a mutable blob of primitive data
 pub trait SIMDIteratorMut : SIMDIterator {
     /// Pack and run `func` over the iterator, modifying each element in-place.
@@ -192,6 +598,138 @@ pub trait SIMDArray : SIMDObject + SIMDSized {
     unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector;
     fn load_scalar(&self, offset: usize) -> Self::Scalar;
     unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar;
+
+    /// Load a vector of any width from this array, beginning at `offset`.
+    #[inline(always)]
+    fn load_as<V>(&self, offset: usize) -> V where V : Packed<Scalar = Self::Scalar> {
+        assert!(offset + V::WIDTH <= self.scalar_len());
+        unsafe { self.load_as_unchecked(offset) }
+    }
+
+    /// Load a vector of any width from this array, beginning at `offset`,
+    /// without checking the bounds of the array.
+    #[inline(always)]
+    unsafe fn load_as_unchecked<V>(&self, offset: usize) -> V where V : Packed<Scalar = Self::Scalar> {
+        let mut ret = V::default();
+        for i in 0..V::WIDTH {
+            ret = ret.replace_unchecked(i, self.load_scalar_unchecked(offset + i));
+        }
+        ret
+    }
+
+    /// Load the last, partial vector of this array, whose elements from
+    /// `offset` onward are placed after the first `empty_amt` elements of
+    /// `default`, without checking the bounds of the array.
+    #[inline(always)]
+    unsafe fn load_tail_unchecked<V>(&self, offset: usize, empty_amt: usize, default: V) -> V
+        where V : Packed<Scalar = Self::Scalar> + Shuffle {
+        load_tail_right_aligned(self, offset, empty_amt, default)
+    }
+
+    /// Hint that the element at `offset` will be loaded soon. Offsets past
+    /// the end of the array are ignored.
+    #[inline(always)]
+    fn prefetch_at(&self, _offset: usize, _locality: Locality) {}
+
+    /// Return a vector whose `i`th element is the element of this array at
+    /// the `i`th index of `indices`. Hardware gathers are used where
+    /// available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let table = [1.0f32, 2.0, 4.0, 8.0];
+    /// assert_eq!((&table[..]).gather(u32s(2)), f32s(4.0));
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn gather(&self, indices: <Self::Vector as Gather>::Indices) -> Self::Vector where Self::Vector : Gather {
+        for i in 0..Self::Vector::WIDTH {
+            checked_index::<Self::Vector>(&indices, i, self.scalar_len());
+        }
+        unsafe { self.gather_unchecked(indices) }
+    }
+
+    /// Return a vector whose `i`th element is the element of this array at
+    /// the `i`th index of `indices`, without checking the bounds of the
+    /// array.
+    #[inline(always)]
+    unsafe fn gather_unchecked(&self, indices: <Self::Vector as Gather>::Indices) -> Self::Vector where Self::Vector : Gather {
+        let mut ret = <Self::Vector as Packed>::default();
+        for i in 0..Self::Vector::WIDTH {
+            ret = ret.replace_unchecked(i, self.load_scalar_unchecked(<Self::Vector as Gather>::index(&indices, i)));
+        }
+        ret
+    }
+
+    /// Return a vector whose `i`th element is the element of this array at
+    /// the `i`th index of `indices` if the `i`th element of `mask` is set, or
+    /// the `i`th element of `src` otherwise. Only the indices of set elements
+    /// are checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of any set element is out of bounds.
+    #[inline(always)]
+    fn gather_masked(&self, indices: <Self::Vector as Gather>::Indices, mask: <Self::Vector as Gather>::Mask, src: Self::Vector) -> Self::Vector where Self::Vector : Gather {
+        for i in 0..Self::Vector::WIDTH {
+            if mask.extract(i) {
+                checked_index::<Self::Vector>(&indices, i, self.scalar_len());
+            }
+        }
+        unsafe { self.gather_masked_unchecked(indices, mask, src) }
+    }
+
+    /// Return a vector whose `i`th element is the element of this array at
+    /// the `i`th index of `indices` if the `i`th element of `mask` is set, or
+    /// the `i`th element of `src` otherwise, without checking the bounds of
+    /// the array.
+    #[inline(always)]
+    unsafe fn gather_masked_unchecked(&self, indices: <Self::Vector as Gather>::Indices, mask: <Self::Vector as Gather>::Mask, src: Self::Vector) -> Self::Vector where Self::Vector : Gather {
+        let mut ret = src;
+        for i in 0..Self::Vector::WIDTH {
+            if mask.extract(i) {
+                ret = ret.replace_unchecked(i, self.load_scalar_unchecked(<Self::Vector as Gather>::index(&indices, i)));
+            }
+        }
+        ret
+    }
+}
+
+/// Load the last, partial vector of `array` by loading the last full vector's
+/// worth of elements and replacing the ones before `offset` with `default`.
+#[inline(always)]
+pub(crate) unsafe fn load_tail_right_aligned<A, V>(array: &A, offset: usize, empty_amt: usize, default: V) -> V
+    where A : SIMDArray + ?Sized, V : Packed<Scalar = A::Scalar> {
+    let len = array.scalar_len();
+    // Right-align the partial vector to ensure the load is vectorized
+    if V::WIDTH < len {
+        default.merge_partitioned(array.load_as_unchecked(len - V::WIDTH), empty_amt)
+    } else {
+        let mut ret = default;
+        for i in offset..len {
+            ret = ret.replace_unchecked(i + empty_amt, array.load_scalar_unchecked(i));
+        }
+        ret
+    }
+}
+
+/// Return the `lane`th index of `indices`, which must be less than `len`, and
+/// small enough for the hardware to treat as a signed index. Callers pass
+/// the array's `scalar_len`, which excludes the padding of an `AlignedVec`.
+#[inline(always)]
+fn checked_index<V>(indices: &V::Indices, lane: usize, len: usize) -> usize where V : Gather {
+    let idx = V::index(indices, lane);
+    let sign = 8 * size_of::<<V::Indices as Packed>::Scalar>() - 1;
+    assert!(idx < len && (idx as u64) >> sign == 0,
+            "index {} is out of bounds for an array of length {}", idx, len);
+    idx
 }
 
 /// A trait defining a random-access mutable blob of data which can be loaded
@@ -201,15 +739,109 @@ pub trait SIMDArrayMut : SIMDArray {
     unsafe fn store_unchecked(&mut self, value: Self::Vector, offset: usize);
     fn store_scalar(&mut self, value: Self::Scalar, offset: usize);
     unsafe fn store_scalar_unchecked(&mut self, value: Self::Scalar, offset: usize);
+
+    /// Write the `i`th element of `values` to this array at the `i`th index
+    /// of `indices`. If an index appears more than once, the element in the
+    /// highest lane is written. Hardware scatters are used where available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let mut hist = [0u32; 20];
+    /// (&mut hist[..]).scatter(u32s::interleave(3, 17), u32s(1));
+    /// assert_eq!(hist[3], 1);
+    /// assert_eq!(hist[17], 1);
+    /// # }
+    /// ```
+    #[inline(always)]
+    fn scatter(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector) where Self::Vector : Scatter {
+        for i in 0..Self::Vector::WIDTH {
+            checked_index::<Self::Vector>(&indices, i, self.scalar_len());
+        }
+        unsafe { self.scatter_unchecked(indices, values) }
+    }
+
+    /// Write the `i`th element of `values` to this array at the `i`th index
+    /// of `indices`, without checking the bounds of the array.
+    #[inline(always)]
+    unsafe fn scatter_unchecked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector) where Self::Vector : Scatter {
+        for i in 0..Self::Vector::WIDTH {
+            self.store_scalar_unchecked(values.extract_unchecked(i), <Self::Vector as Gather>::index(&indices, i));
+        }
+    }
+
+    /// Write the `i`th element of `values` to this array at the `i`th index
+    /// of `indices` if the `i`th element of `mask` is set. Only the indices
+    /// of set elements are checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of any set element is out of bounds.
+    #[inline(always)]
+    fn scatter_masked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector, mask: <Self::Vector as Gather>::Mask) where Self::Vector : Scatter {
+        for i in 0..Self::Vector::WIDTH {
+            if mask.extract(i) {
+                checked_index::<Self::Vector>(&indices, i, self.scalar_len());
+            }
+        }
+        unsafe { self.scatter_masked_unchecked(indices, values, mask) }
+    }
+
+    /// Write the `i`th element of `values` to this array at the `i`th index
+    /// of `indices` if the `i`th element of `mask` is set, without checking
+    /// the bounds of the array.
+    #[inline(always)]
+    unsafe fn scatter_masked_unchecked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector, mask: <Self::Vector as Gather>::Mask) where Self::Vector : Scatter {
+        for i in 0..Self::Vector::WIDTH {
+            if mask.extract(i) {
+                self.store_scalar_unchecked(values.extract_unchecked(i), <Self::Vector as Gather>::index(&indices, i));
+            }
+        }
+    }
 }
 
 /// A slice-backed iterator which can automatically pack its constituent
-/// elements into vectors.
+/// elements into vectors. The vectors are `A::Vector` unless another width is
+/// chosen with [`simd_iter_as`].
+///
+/// [`simd_iter_as`]: ../into_iters/trait.IntoSIMDRefIterator.html#tymethod.simd_iter_as
 #[derive(Clone, Debug)]
-pub struct SIMDIter<A : SIMDArray> {
+pub struct SIMDIter<A : SIMDArray, V = <A as SIMDObject>::Vector> {
     pub position: usize,
     pub data: A,
-    pub default: A::Vector,
+    pub default: V,
+    pub prefetch: Option<(usize, Locality)>,
+}
+
+impl<A, V> SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
+    /// Return this iterator, which prefetches the elements `distance`
+    /// elements ahead of each vector it loads into `locality`. One prefetch
+    /// is issued for each cache line, rather than for each vector.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [1.0f32; 4096];
+    /// let sum = data.simd_iter(f32s(0.0))
+    ///     .prefetch(512, Locality::L1)
+    ///     .simd_reduce(f32s(0.0), |acc, v| acc + v)
+    ///     .sum();
+    /// assert_eq!(sum, 4096.0);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn prefetch(mut self, distance: usize, locality: Locality) -> Self {
+        self.prefetch = Some((distance, locality));
+        self
+    }
 }
 
 /// A lazy mapping iterator which applies its function to a stream of vectors.
@@ -219,6 +851,31 @@ pub struct SIMDMap<I, F> where I : SIMDIterable {
     pub func: F,
 }
 
+/// A lazy mapping iterator which applies its function to a stream of vectors,
+/// yielding a tuple of vectors.
+#[derive(Debug)]
+pub struct SIMDMapTuple<I, F> where I : SIMDIterable {
+    pub iter: I,
+    pub func: F,
+}
+
+/// An iterator which yields the running totals of a stream of vectors.
+#[derive(Clone, Debug)]
+pub struct SIMDScan<I> where I : SIMDIterable {
+    pub iter: I,
+    pub carry: I::Vector,
+    pub exclusive: bool,
+}
+
+/// An iterator which yields the indices of a stream of vectors alongside the
+/// vectors themselves.
+#[derive(Clone, Debug)]
+pub struct SIMDEnumerate<I> where I : SIMDIterable {
+    pub iter: I,
+    pub indices: I::Vector,
+    pub step: I::Vector,
+}
+
 /// An iterator which packs an iterator of scalars into an iterator of vectors.
 /// Cannot take advantage of vectorized loads, so it's very slow to gather data!
 #[derive(Clone)]
@@ -329,6 +986,16 @@ impl<'a, S, V> SIMDArrayMut for &'a mut [S] where S : 'a + Packable<Vector = V>,
         debug_assert!(offset < self.len());
         *self.get_unchecked_mut(offset) = value;
     }
+
+    #[inline(always)]
+    unsafe fn scatter_unchecked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector) where Self::Vector : Scatter {
+        <Self::Vector as Scatter>::scatter_unchecked(values, &mut self[..], indices)
+    }
+
+    #[inline(always)]
+    unsafe fn scatter_masked_unchecked(&mut self, indices: <Self::Vector as Gather>::Indices, values: Self::Vector, mask: <Self::Vector as Gather>::Mask) where Self::Vector : Scatter {
+        <Self::Vector as Scatter>::scatter_masked_unchecked(values, &mut self[..], indices, mask)
+    }
 }
 
 /// A slice-backed iterator which yields scalar elements using the Iterator API.
@@ -359,47 +1026,83 @@ impl<T> Unpacked<T> where T : SIMDIterable {
     }
 }
 
-/// An iterator which yields multiple elements of a PackedIter
+/// An iterator which yields arrays of `N` consecutive vectors of its
+/// contained iterator.
 #[derive(Debug)]
-pub struct Unrolled<'a, T : 'a + SIMDIterable> {
-    iter: &'a mut T,
-    amt: usize,
-    scratch: [T::Vector; 8],
+pub struct Unrolled<T, const N: usize> where T : SIMDIterator {
+    iter: T,
+    pending: [T::Vector; N],
+    pending_len: usize,
 }
 
-impl<'a, T> Unrolled<'a, T> where T : 'a + SIMDIterable {
+impl<T, const N: usize> Unrolled<T, N> where T : SIMDIterator {
     #[inline(always)]
     pub fn chunk_len(&self) -> usize {
-        self.amt
+        N
     }
 
     #[inline(always)]
     pub fn chunk_pos(&self) -> usize {
         self.iter.vector_pos() / self.chunk_len()
     }
+
+    /// Return the vectors left over once this iterator stops yielding
+    /// arrays, followed by the partial vector of the contained iterator, the
+    /// number of those vectors, and the number of elements at the start of
+    /// the last of them which are the default, or None if there are no
+    /// vectors. Vectors past that number are the contained iterator's
+    /// default. Like [`SIMDIterator::end`], the last vector may be partially
+    /// filled with the default.
+    ///
+    /// This must only be called once `next` has returned None, as the vectors
+    /// of the contained iterator which haven't been grouped yet would be lost.
+    ///
+    /// [`SIMDIterator::end`]: trait.SIMDIterator.html#tymethod.end
+    #[inline(always)]
+    pub fn end(&mut self) -> Option<([T::Vector; N], usize, usize)> {
+        debug_assert!(self.iter.next().is_none(), "end() called before next() returned None");
+        let mut ret = self.pending;
+        let mut n = self.pending_len;
+        let mut empty = 0;
+        self.pending = [self.iter.default(); N];
+        self.pending_len = 0;
+
+        // Fewer than N vectors are pending once next() has returned None
+        if let Some((v, e)) = self.iter.end() {
+            ret[n] = v;
+            n += 1;
+            empty = e;
+        }
+        if n > 0 { Some((ret, n, empty)) } else { None }
+    }
+
+    /// Return the iterator this iterator was unrolled from.
+    #[inline(always)]
+    pub fn reroll(self) -> T {
+        self.iter
+    }
 }
 
-impl<'a, T> Iterator for Unrolled<'a, T> where T : 'a + SIMDIterator {
-    type Item = &'a [T::Vector];
+impl<T, const N: usize> Iterator for Unrolled<T, N> where T : SIMDIterator {
+    type Item = [T::Vector; N];
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        let mut i = 0;
-        while i < self.amt {
+        if self.pending_len > 0 {
+            return None;
+        }
+        let mut ret = [self.iter.default(); N];
+        for i in 0..N {
             if let Some(vec) = self.iter.next() {
-                self.scratch[i] = vec;
-                i += 1;
+                ret[i] = vec;
             } else {
-                break;
-            }
-        }
-        if i > 0 {
-            unsafe { // TODO: Is this unsafe? Contravariant lifetimes?
-                Some(from_raw_parts((&mut self.scratch).as_mut_ptr(), i))
+                // Hold onto the incomplete group for end()
+                self.pending = ret;
+                self.pending_len = i;
+                return None;
             }
-        } else {
-            None
         }
+        Some(ret)
     }
 }
 
@@ -432,6 +1135,34 @@ macro_rules! impl_iter {
                 debug_assert!(offset < self.len());
                 *self.get_unchecked(offset)
             }
+
+            #[inline(always)]
+            fn load_as<W>(&self, offset: usize) -> W where W : Packed<Scalar = Self::Scalar> {
+                W::load(&self, offset)
+            }
+
+            #[inline(always)]
+            unsafe fn load_as_unchecked<W>(&self, offset: usize) -> W where W : Packed<Scalar = Self::Scalar> {
+                debug_assert!(self[offset..].len() >= W::WIDTH);
+                W::load_unchecked(&self, offset)
+            }
+
+            #[inline(always)]
+            fn prefetch_at(&self, offset: usize, locality: Locality) {
+                if offset < self.len() {
+                    prefetch(unsafe { self.as_ptr().add(offset) }, locality);
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn gather_unchecked(&self, indices: <Self::Vector as Gather>::Indices) -> Self::Vector where Self::Vector : Gather {
+                <Self::Vector as Gather>::gather_unchecked(&self[..], indices)
+            }
+
+            #[inline(always)]
+            unsafe fn gather_masked_unchecked(&self, indices: <Self::Vector as Gather>::Indices, mask: <Self::Vector as Gather>::Mask, src: Self::Vector) -> Self::Vector where Self::Vector : Gather {
+                <Self::Vector as Gather>::gather_masked_unchecked(&self[..], indices, mask, src)
+            }
         }
 
         impl< $($genera),* > SIMDSized for $name $($pred )* {
@@ -448,24 +1179,34 @@ impl_iter!(Vec<S>, ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar
 impl_iter!(&'a [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
 impl_iter!(&'a mut [S], ('a, S, V) where S : Packable<Vector = V>, V : Packed<Scalar = S>);
 
-impl<A> SIMDObject for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
-    type Vector = A::Vector;
+impl<A, V> SIMDObject for SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
+    type Vector = V;
     type Scalar = A::Scalar;
 }
 
-impl<A> ExactSizeIterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+impl<A, V> ExactSizeIterator for SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
     #[inline(always)]
     fn len(&self) -> usize {
         self.data.scalar_len() / self.width()
     }
 }
 
-impl<A> Iterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+impl<A, V> Iterator for SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
     type Item = <Self as SIMDObject>::Vector;
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         if self.position + self.width() <= self.scalar_len() {
+            if let Some((distance, locality)) = self.prefetch {
+                // Prefetch the cache line which begins within the vector
+                // `distance` elements ahead, if one does.
+                let size = size_of::<A::Scalar>();
+                let start = (self.position + distance) * size;
+                let line = (start + CACHE_LINE - 1) / CACHE_LINE * CACHE_LINE;
+                if line < start + self.width() * size {
+                    self.data.prefetch_at(line / size, locality);
+                }
+            }
             let ret = unsafe { self.load_unchecked(self.position) };
             let width = self.width(); // Appease borrow checker
             self.advance(width);
@@ -476,15 +1217,15 @@ impl<A> Iterator for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Sca
     }
 }
 
-impl<A> SIMDArray for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+impl<A, V> SIMDArray for SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
     #[inline(always)]
     fn load(&self, offset: usize) -> Self::Vector {
-        self.data.load(offset)
+        self.data.load_as(offset)
     }
 
     #[inline(always)]
     unsafe fn load_unchecked(&self, offset: usize) -> Self::Vector {
-        self.data.load_unchecked(offset)
+        self.data.load_as_unchecked(offset)
     }
 
     #[inline(always)]
@@ -496,16 +1237,37 @@ impl<A> SIMDArray for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Sc
     unsafe fn load_scalar_unchecked(&self, offset: usize) -> Self::Scalar {
         self.data.load_scalar_unchecked(offset)
     }
+
+    #[inline(always)]
+    fn load_as<W>(&self, offset: usize) -> W where W : Packed<Scalar = Self::Scalar> {
+        self.data.load_as(offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_as_unchecked<W>(&self, offset: usize) -> W where W : Packed<Scalar = Self::Scalar> {
+        self.data.load_as_unchecked(offset)
+    }
+
+    #[inline(always)]
+    unsafe fn load_tail_unchecked<W>(&self, offset: usize, empty_amt: usize, default: W) -> W
+        where W : Packed<Scalar = Self::Scalar> + Shuffle {
+        self.data.load_tail_unchecked(offset, empty_amt, default)
+    }
+
+    #[inline(always)]
+    fn prefetch_at(&self, offset: usize, locality: Locality) {
+        self.data.prefetch_at(offset, locality)
+    }
 }
 
-impl<A> SIMDSized for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+impl<A, V> SIMDSized for SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
     #[inline(always)]
     fn scalar_len(&self) -> usize {
         self.data.scalar_len()
     }
 }
 
-impl<A> SIMDIterable for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A::Scalar : Packable {
+impl<A, V> SIMDIterable for SIMDIter<A, V> where A : SIMDArray, V : Packed<Scalar = A::Scalar> {
     #[inline(always)]
     fn scalar_pos(&self) -> usize {
         self.position
@@ -522,23 +1284,12 @@ impl<A> SIMDIterable for SIMDIter<A> where A : SIMDArray, A::Vector : Packed, A:
     }
 }
 
-impl<T, S, V> SIMDIterator for T where T : SIMDIterable + SIMDArray<Scalar = S, Vector = V>, S : Packable, V : Packed<Scalar = S> {
+impl<T, S, V> SIMDIterator for T where T : SIMDIterable + SIMDArray<Scalar = S, Vector = V>, S : Packable, V : Packed<Scalar = S> + Shuffle {
     #[inline(always)]
     fn end(&mut self) -> Option<(Self::Vector, usize)> {
         if self.scalar_pos() < self.scalar_len() {
-            let mut ret = self.default();
             let empty_amt = self.width() - (self.scalar_len() - self.scalar_pos());
-            // Right-align the partial vector to ensure the load is vectorized
-            if self.width() < self.scalar_len() {
-                ret = unsafe { self.load_unchecked(self.scalar_len() - self.width()) };
-                ret = self.default().merge_partitioned(ret, empty_amt);
-            } else {
-                for i in self.scalar_pos()..self.scalar_len() {
-                    unsafe {
-                        ret = ret.replace_unchecked(i + empty_amt, self.load_scalar_unchecked(i));
-                    }
-                }
-            }
+            let ret = unsafe { self.load_tail_unchecked(self.scalar_pos(), empty_amt, self.default()) };
             self.finalize();
             Some((ret, empty_amt))
         } else {
@@ -548,7 +1299,7 @@ impl<T, S, V> SIMDIterator for T where T : SIMDIterable + SIMDArray<Scalar = S,
 
 }
 
-impl<T> SIMDIteratorMut for SIMDIter<T> where T : SIMDArrayMut {
+impl<T> SIMDIteratorMut for SIMDIter<T> where T : SIMDArrayMut, T::Vector : Shuffle {
     fn simd_for_each<F>(&mut self, mut func: F)
         where F : FnMut(&mut Self::Vector) -> () {
         let mut lastvec = Self::Vector::default();
@@ -585,7 +1336,7 @@ pub trait UnsafeIterator : Iterator + SIMDIterable {
     unsafe fn end_unchecked(&mut self, offset: usize, empty_amt: usize) -> Self::Vector;
 }
 
-impl<T, S, V> UnsafeIterator for T where T : SIMDIterable + SIMDArray<Scalar = S, Vector = V>, S : Packable, V : Packed<Scalar = S> {
+impl<T, S, V> UnsafeIterator for T where T : SIMDIterable + SIMDArray<Scalar = S, Vector = V>, S : Packable, V : Packed<Scalar = S> + Shuffle {
     #[inline(always)]
     unsafe fn next_unchecked(&mut self, offset: usize) -> Self::Item {
         debug_assert!(offset + self.width() <= self.scalar_len());
@@ -595,18 +1346,8 @@ impl<T, S, V> UnsafeIterator for T where T : SIMDIterable + SIMDArray<Scalar = S
     #[inline(always)]
     unsafe fn end_unchecked(&mut self, offset: usize, empty_amt: usize) -> Self::Vector {
         debug_assert!(offset < self.scalar_len());
-        let mut ret = self.default();
         debug_assert_eq!(empty_amt, self.width() - (self.scalar_len() - offset));
-        // Right-align the partial vector to ensure the load is vectorized
-        if self.width() < self.scalar_len() {
-            ret = self.load_unchecked(self.scalar_len() - self.width());
-            ret = self.default().merge_partitioned(ret, empty_amt);
-        } else {
-            for i in offset..self.scalar_len() {
-                ret = ret.replace_unchecked(i + empty_amt, self.load_scalar_unchecked(i));
-            }
-        }
-        ret
+        self.load_tail_unchecked(offset, empty_amt, self.default())
     }
 
 }
@@ -676,6 +1417,228 @@ impl<'a, A, B, I, F> SIMDIterator for SIMDMap<I, F>
     }
 }
 
+impl<A, I, F> Iterator for SIMDMapTuple<I, F>
+    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : PackedTuple {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.func)
+    }
+}
+
+impl<A, I, F> ExactSizeIterator for SIMDMapTuple<I, F>
+    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<A, I, F> SIMDZippedObject for SIMDMapTuple<I, F>
+    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : PackedTuple {
+    type Vectors = A;
+    type Scalars = A::Scalars;
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        A::width()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        A::size()
+    }
+}
+
+impl<A, I, F> SIMDZippedIterable for SIMDMapTuple<I, F>
+    where I : SIMDIterable, F : FnMut(I::Vector) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.scalar_len()
+    }
+
+    #[inline(always)]
+    fn advance(&mut self, amount: usize) {
+        self.iter.advance(amount);
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vectors {
+        A::default()
+    }
+}
+
+impl<A, I, F> SIMDZippedIterator for SIMDMapTuple<I, F>
+    where I : SIMDIterator, F : FnMut(I::Vector) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let (v, n) = self.iter.end()?;
+        let nr = n * I::Scalar::SIZE / A::size();
+        Some(((self.func)(v), nr))
+    }
+}
+
+impl<I> SIMDScan<I>
+    where I : SIMDIterable, I::Vector : PrefixSum + Add<I::Vector, Output = I::Vector> {
+    /// Return the running totals of `vec`, and carry the total of its last
+    /// element into the next vector.
+    #[inline(always)]
+    fn scan(&mut self, vec: I::Vector) -> I::Vector {
+        let last = I::Vector::WIDTH - 1;
+        if self.exclusive {
+            let ret = self.carry + vec.exclusive_prefix_sum();
+            self.carry = I::Vector::splat(ret.extract(last)) + I::Vector::splat(vec.extract(last));
+            ret
+        } else {
+            let ret = self.carry + vec.prefix_sum();
+            self.carry = I::Vector::splat(ret.extract(last));
+            ret
+        }
+    }
+}
+
+impl<I> Iterator for SIMDScan<I>
+    where I : SIMDIterable, I::Vector : PrefixSum + Add<I::Vector, Output = I::Vector> {
+    type Item = I::Vector;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let vec = self.iter.next()?;
+        Some(self.scan(vec))
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDScan<I>
+    where I : SIMDIterable, I::Vector : PrefixSum + Add<I::Vector, Output = I::Vector> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> SIMDObject for SIMDScan<I> where I : SIMDIterable {
+    type Vector = I::Vector;
+    type Scalar = I::Scalar;
+}
+
+impl<I> SIMDSized for SIMDScan<I> where I : SIMDIterable {
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.scalar_len()
+    }
+}
+
+impl<I> SIMDIterable for SIMDScan<I>
+    where I : SIMDIterable, I::Vector : PrefixSum + Add<I::Vector, Output = I::Vector> {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn advance(&mut self, amount: usize) {
+        self.iter.advance(amount);
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vector {
+        <Self::Vector as Packed>::default()
+    }
+}
+
+impl<I> SIMDIterator for SIMDScan<I>
+    where I : SIMDIterator, I::Vector : PrefixSum + Add<I::Vector, Output = I::Vector> {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vector, usize)> {
+        let (vec, n) = self.iter.end()?;
+        // Zero the elements which were filled from the default vector, so
+        // they don't contribute to the totals of the elements after them.
+        let vec = <Self::Vector as Packed>::default().merge_partitioned(vec, n);
+        Some((self.scan(vec), n))
+    }
+}
+
+impl<I> Iterator for SIMDEnumerate<I>
+    where I : SIMDIterable, I::Vector : Pattern + Add<I::Vector, Output = I::Vector> {
+    type Item = (I::Vector, I::Vector);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let vec = self.iter.next()?;
+        let indices = self.indices;
+        self.indices = self.indices + self.step;
+        Some((indices, vec))
+    }
+}
+
+impl<I> ExactSizeIterator for SIMDEnumerate<I>
+    where I : SIMDIterable, I::Vector : Pattern + Add<I::Vector, Output = I::Vector> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> SIMDZippedObject for SIMDEnumerate<I> where I : SIMDIterable {
+    type Vectors = (I::Vector, I::Vector);
+    type Scalars = (I::Scalar, I::Scalar);
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        self.iter.width()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.iter.size()
+    }
+}
+
+impl<I> SIMDZippedIterable for SIMDEnumerate<I>
+    where I : SIMDIterable, I::Vector : Pattern + Add<I::Vector, Output = I::Vector> {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.scalar_len()
+    }
+
+    #[inline(always)]
+    fn advance(&mut self, amount: usize) {
+        self.iter.advance(amount);
+        self.indices = self.indices + I::Vector::splat(I::Scalar::from_usize(amount));
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vectors {
+        (<I::Vector as Packed>::default(), self.iter.default())
+    }
+}
+
+impl<I> SIMDZippedIterator for SIMDEnumerate<I>
+    where I : SIMDIterator, I::Vector : Pattern + Add<I::Vector, Output = I::Vector> {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let pos = self.iter.scalar_pos();
+        let (vec, n) = self.iter.end()?;
+        // The partial vector is right-aligned, so the first `n` lanes don't
+        // correspond to any element.
+        let indices = I::Vector::from_fn(|i| {
+            if i < n { I::Scalar::from_usize(0) } else { I::Scalar::from_usize(pos + i - n) }
+        });
+        Some(((indices, vec), n))
+    }
+}
+
 /// A trait which can transform a stream of vectors into a contiguous
 /// collection of scalars.
 pub trait IntoScalar<T> : SIMDObject where T : Packable {
@@ -686,6 +1649,21 @@ pub trait IntoScalar<T> : SIMDObject where T : Packable {
     /// Take an iterator of SIMD vectors and store them in-order in `fill`.
     fn scalar_fill<'a>(&mut self, fill: &'a mut [T]) -> &'a mut [T];
 
+    /// Take an iterator of SIMD vectors and store them in-order in `fill`,
+    /// using non-temporal stores which don't pull `fill` into the cache.
+    /// This is faster than [`scalar_fill`] for buffers much larger than the
+    /// cache, which won't be read again soon.
+    ///
+    /// Non-temporal stores are used from the first element of `fill` which
+    /// is aligned to the size of the vector, such as the start of an
+    /// [`AlignedVec`]. The elements before it and the last vector are stored
+    /// normally, and the stores are fenced before this returns.
+    ///
+    /// [`scalar_fill`]: #tymethod.scalar_fill
+    /// [`AlignedVec`]: ../aligned/struct.AlignedVec.html
+    fn scalar_fill_streaming<'a>(&mut self, fill: &'a mut [T]) -> &'a mut [T]
+        where Self::Vector : Stream;
+
     /// Take an iterator of SIMD vectors, and store them in-order in a Vec,
     /// including possibly redundant elements at the end of the iterator.
     #[cfg(feature = "std")]
@@ -764,6 +1742,86 @@ impl<'a, T, I> IntoScalar<T> for I
         fill
     }
 
+    #[inline(always)]
+    fn scalar_fill_streaming<'b>(&mut self, fill: &'b mut [Self::Scalar]) -> &'b mut [Self::Scalar]
+        where Self::Vector : Stream {
+        let width = self.width();
+        let mut offset = 0;
+        let mut lastvec = Self::Vector::default();
+        // The number of elements before the first offset in `fill` which is
+        // aligned to the size of a vector, if any offset is.
+        let misalignment = fill.as_ptr() as usize % size_of::<Self::Vector>();
+        let prologue = if misalignment % size_of::<Self::Scalar>() == 0 {
+            Some((size_of::<Self::Vector>() - misalignment) % size_of::<Self::Vector>() / size_of::<Self::Scalar>())
+        } else {
+            None
+        };
+
+        match prologue {
+            Some(0) => {
+                while let Some(vec) = self.next() {
+                    unsafe { vec.stream_unchecked(fill, offset); }
+                    offset += width;
+                    lastvec = vec;
+                }
+            },
+            Some(prologue) => {
+                // Store the first vector normally, which covers the prologue.
+                // Every aligned vector after it straddles two of our vectors,
+                // so stitch them together before streaming them.
+                let mut pair = [Self::Vector::default(); 2];
+                while let Some(vec) = self.next() {
+                    if offset > 0 {
+                        pair[0] = lastvec;
+                        pair[1] = vec;
+                        unsafe {
+                            let pair = from_raw_parts(pair.as_ptr() as *const Self::Scalar, width * 2);
+                            Self::Vector::load_unchecked(pair, prologue)
+                                .stream_unchecked(fill, offset - width + prologue);
+                        }
+                    } else {
+                        unsafe { vec.store_unchecked(fill, offset); }
+                    }
+                    offset += width;
+                    lastvec = vec;
+                }
+            },
+            None => {
+                while let Some(vec) = self.next() {
+                    unsafe { vec.store_unchecked(fill, offset); }
+                    offset += width;
+                    lastvec = vec;
+                }
+            }
+        }
+
+        // The vectors stored below may overlap the streamed ones, so they
+        // must land first.
+        stream_fence();
+
+        if let Some((p, n)) = self.end() {
+            if offset > 0 {
+                // We stored a vector in this buffer; overwrite the unused elements
+                unsafe {
+                    p.store_unchecked(fill, offset - n);
+                    lastvec.store_unchecked(fill, offset - width);
+                }
+            } else {
+                // The buffer won't fit one vector; store elementwise
+                for i in 0..(width - n) {
+                    unsafe {
+                        fill[offset + i] = p.extract_unchecked(i + n);
+                    }
+                }
+            }
+        } else if offset > 0 {
+            // The end of the last vector may not have been streamed
+            unsafe { lastvec.store_unchecked(fill, offset - width); }
+        }
+
+        fill
+    }
+
     #[inline(always)]
     #[cfg(feature = "std")]
     fn scalar_collect_all(&mut self) -> Vec<Self::Scalar> {
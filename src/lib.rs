@@ -209,16 +209,22 @@ mod core {
 }
 
 extern crate packed_simd;
+#[doc(hidden)]
+pub use packed_simd as __packed_simd;
 extern crate vektor;
 
 #[macro_use] pub(crate) mod debug;
 #[macro_use] pub mod zip;
 #[macro_use] pub mod vecs;
+#[macro_use] pub mod masks;
 pub mod vec_patterns;
 pub mod iters;
 pub mod into_iters;
+#[cfg(feature = "std")]
+pub mod aligned;
 #[macro_use] pub mod intrin;
 #[macro_use] pub mod arch;
+#[macro_use] pub mod dispatch;
 pub mod prelude;
 pub mod stride_zip;
 pub mod stride;
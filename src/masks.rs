@@ -0,0 +1,289 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::core::fmt::Debug;
+use crate::core::ops::{BitAnd, BitOr, BitXor, Not};
+use crate::vecs::*;
+
+/// A SIMD vector of booleans, such as the result of a vector comparison.
+///
+/// Each element of a mask occupies as many bits as the elements of the vector
+/// it was produced from, and is either all ones (true) or all zeroes (false).
+pub trait Mask : Sized + Copy + Debug + PartialEq
+    + BitAnd<Self, Output = Self> + BitOr<Self, Output = Self>
+    + BitXor<Self, Output = Self> + Not<Output = Self> {
+    /// The unsigned vector type with the same layout as this mask
+    type Vector : Pattern;
+
+    /// The number of elements in this mask
+    const WIDTH: usize;
+
+    #[inline(always)]
+    /// Return the number of elements in this mask
+    fn width(&self) -> usize {
+        Self::WIDTH
+    }
+
+    /// Return a mask with all elements set to `data`.
+    fn splat(data: bool) -> Self;
+
+    /// Return a mask from a vector whose elements are all either memset to
+    /// 0xFF or to 0x00. The result of converting any other vector is
+    /// unspecified.
+    fn from_vector(vec: Self::Vector) -> Self;
+
+    /// Return a vector whose elements are memset to 0xFF where this mask is
+    /// set, and to 0x00 elsewhere.
+    fn to_vector(self) -> Self::Vector;
+
+    /// Return the `idx`th element of this mask.
+    fn extract(&self, idx: usize) -> bool;
+
+    /// Replace the `idx`th element of this mask with `data`.
+    fn replace(&mut self, idx: usize, data: bool) -> Self;
+
+    /// Return an integer whose `i`th bit is set if the `i`th element of this
+    /// mask is set.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u32s(1).eq_mask(u32s(1)).to_bitmask(), (1 << u32s::WIDTH) - 1);
+    /// assert_eq!(u8s::partition(1, 0, 1).eq_mask(u8s(1)).to_bitmask(), 1);
+    /// # }
+    /// ```
+    fn to_bitmask(&self) -> u64;
+
+    #[inline(always)]
+    /// Return true if any element of this mask is set.
+    fn any(&self) -> bool {
+        self.to_bitmask() != 0
+    }
+
+    #[inline(always)]
+    /// Return true if every element of this mask is set.
+    fn all(&self) -> bool {
+        self.to_bitmask() == u64::max_value() >> (64 - Self::WIDTH)
+    }
+
+    #[inline(always)]
+    /// Return true if no element of this mask is set.
+    fn none(&self) -> bool {
+        self.to_bitmask() == 0
+    }
+
+    #[inline(always)]
+    /// Return the number of set elements in this mask.
+    fn count(&self) -> usize {
+        self.to_bitmask().count_ones() as usize
+    }
+
+    #[inline(always)]
+    /// Return the index of the first set element of this mask, or None if no
+    /// elements are set.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// assert_eq!(u8s::partition(0, 1, 3).eq_mask(u8s(1)).first_set(), Some(3));
+    /// assert_eq!(u8s(0).eq_mask(u8s(1)).first_set(), None);
+    /// # }
+    /// ```
+    fn first_set(&self) -> Option<usize> {
+        let bits = self.to_bitmask();
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+
+    #[inline(always)]
+    /// Return a mask with every element set. See [`Pattern::ones`].
+    ///
+    /// [`Pattern::ones`]: ../vec_patterns/trait.Pattern.html#tymethod.ones
+    fn ones() -> Self {
+        Self::from_vector(Self::Vector::ones())
+    }
+
+    #[inline(always)]
+    /// Return a mask with no elements set. See [`Pattern::zeroes`].
+    ///
+    /// [`Pattern::zeroes`]: ../vec_patterns/trait.Pattern.html#tymethod.zeroes
+    fn zeroes() -> Self {
+        Self::from_vector(Self::Vector::zeroes())
+    }
+
+    #[inline(always)]
+    /// Return a mask whose first `off` elements are unset, and whose last
+    /// `Self::WIDTH - off` elements are set. See [`Pattern::partition_mask`].
+    ///
+    /// [`Pattern::partition_mask`]: ../vec_patterns/trait.Pattern.html#tymethod.partition_mask
+    fn partition_mask(off: usize) -> Self {
+        Self::from_vector(Self::Vector::partition_mask(off))
+    }
+}
+
+/// Return the bitmask of `vec` one element at a time, for machines which
+/// can't compute it in a single instruction.
+#[inline(always)]
+pub(crate) fn bitmask_polyfill<V>(vec: V) -> u64 where V : Packed, V::Scalar : PartialEq + Default {
+    let mut ret = 0u64;
+    for i in 0..V::WIDTH {
+        if vec.extract(i) != V::Scalar::default() {
+            ret |= 1 << i;
+        }
+    }
+    ret
+}
+
+macro_rules! impl_mask {
+    ($mask:ident, $vec:ident, $el:ident, $width:expr, $bitmask:ident, [$($elname:ident),+]) => {
+        /// A SIMD vector of booleans, laid out as a vector of integers which
+        /// are memset to 0xFF when true and 0x00 when false.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[allow(non_camel_case_types)]
+        #[repr(transparent)]
+        pub struct $mask($vec);
+
+        impl $mask {
+            /// Return a mask with each element set to the corresponding
+            /// argument.
+            #[inline(always)]
+            pub fn new($($elname: bool),+) -> Self {
+                $mask($vec::new($(if $elname { $el::max_value() } else { 0 }),+))
+            }
+        }
+
+        impl Mask for $mask {
+            type Vector = $vec;
+
+            const WIDTH: usize = $width;
+
+            #[inline(always)]
+            fn splat(data: bool) -> Self {
+                $mask($vec::splat(if data { $el::max_value() } else { 0 }))
+            }
+
+            #[inline(always)]
+            fn from_vector(vec: Self::Vector) -> Self {
+                $mask(vec)
+            }
+
+            #[inline(always)]
+            fn to_vector(self) -> Self::Vector {
+                self.0
+            }
+
+            #[inline(always)]
+            fn extract(&self, idx: usize) -> bool {
+                self.0.extract(idx) != 0
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, idx: usize, data: bool) -> Self {
+                $mask(self.0.replace(idx, if data { $el::max_value() } else { 0 }))
+            }
+
+            #[inline(always)]
+            fn to_bitmask(&self) -> u64 {
+                $bitmask(self.0)
+            }
+        }
+
+        impl BitAnd<$mask> for $mask {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitand(self, other: Self) -> Self {
+                $mask(self.0 & other.0)
+            }
+        }
+
+        impl BitOr<$mask> for $mask {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitor(self, other: Self) -> Self {
+                $mask(self.0 | other.0)
+            }
+        }
+
+        impl BitXor<$mask> for $mask {
+            type Output = Self;
+
+            #[inline(always)]
+            fn bitxor(self, other: Self) -> Self {
+                $mask(self.0 ^ other.0)
+            }
+        }
+
+        impl Not for $mask {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self {
+                $mask(self.0 ^ $vec::splat($el::max_value()))
+            }
+        }
+    }
+}
+
+macro_rules! impl_mask_alias {
+    ($pmask:tt, $mask:tt, [$($feat:expr),*], [$($nfeat:expr),*]) => (
+        /// A SIMD mask with as many elements as the vectors of the same
+        /// element size.
+        #[allow(non_camel_case_types)]
+        #[cfg(all($(target_feature = $feat,)* not($(target_feature = $nfeat)*)))]
+        pub type $pmask = $mask;
+    );
+}
+
+macro_rules! test_mask {
+    ($mask:tt, $vec:tt, $el:tt, $name:tt) => {
+        #[test]
+        fn $name() {
+            let width = $mask::WIDTH;
+            let all = u64::max_value() >> (64 - width);
+
+            assert_eq!($mask::splat(true).to_bitmask(), all);
+            assert_eq!($mask::splat(false).to_bitmask(), 0);
+            assert_eq!($mask::ones(), $mask::splat(true));
+            assert_eq!($mask::zeroes(), $mask::splat(false));
+            assert_eq!(!$mask::ones(), $mask::zeroes());
+            assert!($mask::ones().all() && $mask::ones().any() && !$mask::ones().none());
+            assert!(!$mask::zeroes().all() && !$mask::zeroes().any() && $mask::zeroes().none());
+
+            for i in 0..width {
+                let one = $mask::splat(false).replace(i, true);
+                assert!(one.extract(i));
+                assert_eq!(one.to_bitmask(), 1 << i);
+                assert_eq!(one.count(), 1);
+                assert_eq!(one.first_set(), Some(i));
+                assert!(one.any() && !one.none());
+                assert_eq!(one.all(), width == 1);
+
+                let part = $mask::partition_mask(i);
+                assert_eq!(part.to_bitmask(), all & !((1 << i) - 1));
+                assert_eq!(part.count(), width - i);
+                assert_eq!(part.first_set(), Some(i));
+                assert_eq!(part, $mask::from_vector($vec::partition_mask(i)));
+            }
+
+            let alt = $mask::from_vector($vec::interleave($el::max_value(), 0));
+            assert_eq!(alt.to_bitmask(), all & 0x5555555555555555);
+            assert_eq!((alt | !alt).to_bitmask(), all);
+            assert_eq!((alt & !alt).to_bitmask(), 0);
+            assert_eq!((alt ^ $mask::ones()), !alt);
+            assert_eq!(alt.to_vector(), $vec::interleave($el::max_value(), 0));
+        }
+    }
+}
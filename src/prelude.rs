@@ -7,10 +7,18 @@
 
 pub use crate::iters::*;
 pub use crate::into_iters::*;
+#[cfg(feature = "std")]
+pub use crate::aligned::AlignedVec;
 pub use crate::vecs::{Packed, Pattern};
+pub use crate::masks::Mask;
 pub use crate::arch::current::vecs::{u8s, i8s, u16s, i16s, u32s, i32s, f32s, u64s, i64s, f64s};
+pub use crate::arch::current::vecs::{u8x16, i8x16, u16x8, i16x8, u32x4, i32x4, f32x4, u64x2, i64x2, f64x2};
+pub use crate::arch::current::vecs::{u8x32, i8x32, u16x16, i16x16, u32x8, i32x8, f32x8, u64x4, i64x4, f64x4};
+pub use crate::arch::current::vecs::{u8x64, i8x64, u16x32, i16x32, u32x16, i32x16, f32x16, u64x8, i64x8, f64x8};
+pub use crate::arch::current::masks::{m8s, m16s, m32s, m64s};
 pub use crate::arch::current::intrin::prelude::*;
 pub use crate::intrin::prelude::*;
 pub use crate::zip::*;
 pub use crate::stride_zip::*;
 pub use crate::stride::*;
+pub use crate::dispatch::SimdLevel;
@@ -1,34 +1,112 @@
 use crate::iters::{SIMDIterator};
 use crate::vecs::{Packed, Packable};
+use crate::vec_patterns::Pattern;
 use crate::intrin::destride::*;
 use crate::zip::{SIMDZippedIterable, SIMDZippedIterator, SIMDZippedObject};
 
-pub struct StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
+/// A vector which may be destrided into `N` vectors, one for each element of
+/// a record of `N` elements.
+pub trait DestrideN<const N: usize> : Destride + Packed + Pattern {
+    type Scalars;
+    type Vectors;
+
+    /// Return the destrided elements of `vecs`, which contain consecutive
+    /// records of `N` elements.
+    fn destride_n(vecs: [Self; N]) -> Self::Vectors;
+}
+
+impl<V> DestrideN<2> for V where V : Destride + Packed + Pattern {
+    type Scalars = (V::Scalar, V::Scalar);
+    type Vectors = (V, V);
+
+    #[inline(always)]
+    fn destride_n(vecs: [Self; 2]) -> Self::Vectors {
+        vecs[0].destride_two(vecs[1])
+    }
+}
+
+impl<V> DestrideN<3> for V where V : Destride + Packed + Pattern {
+    type Scalars = (V::Scalar, V::Scalar, V::Scalar);
+    type Vectors = (V, V, V);
+
+    #[inline(always)]
+    fn destride_n(vecs: [Self; 3]) -> Self::Vectors {
+        vecs[0].destride_three(vecs[1], vecs[2])
+    }
+}
+
+impl<V> DestrideN<4> for V where V : Destride + Packed + Pattern {
+    type Scalars = (V::Scalar, V::Scalar, V::Scalar, V::Scalar);
+    type Vectors = (V, V, V, V);
+
+    #[inline(always)]
+    fn destride_n(vecs: [Self; 4]) -> Self::Vectors {
+        vecs[0].destride_four(vecs[1], vecs[2], vecs[3])
+    }
+}
+
+impl<V> DestrideN<8> for V where V : Destride + Packed + Pattern {
+    type Scalars = (V::Scalar, V::Scalar, V::Scalar, V::Scalar, V::Scalar, V::Scalar, V::Scalar, V::Scalar);
+    type Vectors = (V, V, V, V, V, V, V, V);
+
+    #[inline(always)]
+    fn destride_n(vecs: [Self; 8]) -> Self::Vectors {
+        vecs[0].destride_eight(vecs[1], vecs[2], vecs[3], vecs[4], vecs[5], vecs[6], vecs[7])
+    }
+}
+
+pub struct StrideZip<T, const N: usize = 2> where T : SIMDIterator, T::Vector : DestrideN<N> {
     base: usize,
-    peek: Option<T::Vector>,
+    pending: [T::Vector; N],
+    pending_len: usize,
     iter: T
 }
 
 /// A trait which can transform a collection of iterators into a `Zip`
 pub trait IntoStrideZip : Sized {
-    /// Return an iterator which may iterate over `self` in lockstep.
-    fn stride_zip(self) -> StrideZip<Self>
-        where Self : SIMDIterator, Self::Vector : Destride;
+    /// Return an iterator which destrides the records of `N` elements in
+    /// `self`, yielding a tuple of `N` vectors. Records of 2, 3, 4 and 8
+    /// elements are supported.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let pairs = [1u8, 2, 3, 4, 5, 6];
+    /// let mut sums = [0u8; 3];
+    /// (&pairs[..]).simd_iter(u8s(0)).stride_zip::<2>()
+    ///     .simd_map(|(a, b)| a + b)
+    ///     .scalar_fill(&mut sums);
+    /// assert_eq!(sums, [3, 7, 11]);
+    ///
+    /// let rgb = [10u8, 20, 30, 40, 50, 60];
+    /// let mut luma = [0u8; 2];
+    /// (&rgb[..]).simd_iter(u8s(0)).stride_zip::<3>()
+    ///     .simd_map(|(r, g, b)| (r >> 2) + (g >> 1) + (b >> 2))
+    ///     .scalar_fill(&mut luma);
+    /// assert_eq!(luma, [19, 50]);
+    /// # }
+    /// ```
+    fn stride_zip<const N: usize>(self) -> StrideZip<Self, N>
+        where Self : SIMDIterator, Self::Vector : DestrideN<N>;
 }
 
-impl<T> IntoStrideZip for T where T : SIMDIterator, T::Vector : Destride {
-    fn stride_zip(self) -> StrideZip<Self> {
+impl<T> IntoStrideZip for T where T : SIMDIterator {
+    fn stride_zip<const N: usize>(self) -> StrideZip<Self, N>
+        where Self : SIMDIterator, Self::Vector : DestrideN<N> {
         StrideZip {
             base: self.scalar_pos(),
-            peek: None,
+            pending: [self.default(); N],
+            pending_len: 0,
             iter: self
         }
     }
 }
 
-impl<T> SIMDZippedObject for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
-    type Scalars = (T::Scalar, T::Scalar);
-    type Vectors = (T::Vector, T::Vector);
+impl<T, const N: usize> SIMDZippedObject for StrideZip<T, N> where T : SIMDIterator, T::Vector : DestrideN<N> {
+    type Scalars = <T::Vector as DestrideN<N>>::Scalars;
+    type Vectors = <T::Vector as DestrideN<N>>::Vectors;
 
     /// Return the vector length of this object.
     #[inline(always)]
@@ -43,70 +121,93 @@ impl<T> SIMDZippedObject for StrideZip<T> where T : SIMDIterator, T::Vector : De
     }
 }
 
-impl<T> ExactSizeIterator for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
+impl<T, const N: usize> ExactSizeIterator for StrideZip<T, N> where T : SIMDIterator, T::Vector : DestrideN<N> {
     #[inline(always)]
     fn len(&self) -> usize {
-        self.iter.len() / 2
+        self.iter.len() / N
     }
 }
 
-impl<T> SIMDZippedIterable for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
+impl<T, const N: usize> SIMDZippedIterable for StrideZip<T, N> where T : SIMDIterator, T::Vector : DestrideN<N> {
     #[inline(always)]
     fn scalar_pos(&self) -> usize {
-        (self.iter.scalar_pos() - self.base) / 2
+        (self.iter.scalar_pos() - self.base) / N
     }
 
     #[inline(always)]
     fn vector_pos(&self) -> usize {
-        (self.iter.vector_pos() - (self.base / self.width())) / 2
+        (self.iter.vector_pos() - (self.base / self.width())) / N
     }
 
     #[inline(always)]
     fn scalar_len(&self) -> usize {
-        self.iter.scalar_len() / 2
+        self.iter.scalar_len() / N
     }
 
     #[inline(always)]
     fn advance(&mut self, amount: usize) {
-        self.iter.advance(2 * amount);
+        self.iter.advance(N * amount);
     }
 
     #[inline(always)]
     fn default(&self) -> Self::Vectors {
-        (T::Vector::default(), T::Vector::default())
+        <T::Vector as DestrideN<N>>::destride_n([<T::Vector as Packed>::default(); N])
     }
 }
 
-impl<T> Iterator for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
+impl<T, const N: usize> Iterator for StrideZip<T, N> where T : SIMDIterator, T::Vector : DestrideN<N> {
     type Item = <Self as SIMDZippedObject>::Vectors;
-    
+
     fn next(&mut self) -> Option<Self::Item> {
-        let first = self.iter.next()?;
-        let second = self.iter.next();
-        if let Some(second) = second {
-            Some(first.destride_two(second))
-        } else {
-            self.peek = Some(first);
-            None
+        if self.pending_len > 0 {
+            return None;
         }
+        let mut vecs = [self.iter.default(); N];
+        for i in 0..N {
+            if let Some(v) = self.iter.next() {
+                vecs[i] = v;
+            } else {
+                // Hold onto the incomplete group for end()
+                self.pending = vecs;
+                self.pending_len = i;
+                return None;
+            }
+        }
+        Some(<T::Vector as DestrideN<N>>::destride_n(vecs))
     }
 }
 
-impl<T> SIMDZippedIterator for StrideZip<T> where T : SIMDIterator, T::Vector : Destride {
+impl<T, const N: usize> SIMDZippedIterator for StrideZip<T, N> where T : SIMDIterator, T::Vector : DestrideN<N> {
     fn end(&mut self) -> Option<(Self::Vectors, usize)> {
-        let first = self.iter.next();
-        let (end, n) = self.iter.end().unwrap_or((self.iter.default(), 0));
-        if let Some(first) = first {
-            Some((first.destride_two(end), (self.width() + n) / 2))
-        } else {
-            if let Some(v) = self.peek {
-                self.peek = None;
-                Some((v.destride_two(end), (self.width() + n) / 2))
-            } else if n > 0 {
-                Some((end.destride_two(self.iter.default()), n / 2))
-            } else {
-                None
-            }
+        let width = self.width();
+        let default = self.iter.default();
+        let vecs = self.pending;
+        let k = self.pending_len;
+        self.pending_len = 0;
+
+        let (p, n) = self.iter.end().unwrap_or((default, width));
+        // Incomplete records at the very end are dropped
+        let records = (k * width + width - n) / N;
+        if records == 0 {
+            return None;
+        }
+
+        // Right-align the remaining records, so each destrided vector is
+        // right-aligned like any other partial vector.
+        let skip = N * width - records * N;
+        let mut shifted = vecs;
+        for (j, v) in shifted.iter_mut().enumerate() {
+            *v = T::Vector::from_fn(|l| {
+                let g = j * width + l;
+                if g < skip {
+                    default.extract(l)
+                } else if g - skip < k * width {
+                    vecs[(g - skip) / width].extract((g - skip) % width)
+                } else {
+                    p.extract(n + g - skip - k * width)
+                }
+            });
         }
+        Some((<T::Vector as DestrideN<N>>::destride_n(shifted), width - records))
     }
 }
@@ -11,6 +11,9 @@ use crate::vecs::*;
  
 /// Constructors which may be used to instantiate vectors with patterned data.
 pub trait Pattern : Packed {
+    /// An array with one element for each lane of this vector.
+    type Array;
+
     /// Return a vector whose first `Self::WIDTH / 2` elements are `hi`, and
     /// whose last `Self::WIDTH / 2` elements are `lo`.
     fn halfs(hi: Self::Scalar, lo: Self::Scalar) -> Self;
@@ -32,4 +35,27 @@ pub trait Pattern : Packed {
 
     /// Return a vector made entirely of zeroes.
     fn zeroes() -> Self;
+
+    /// Return a vector whose element at index `i` is `start + step * i`. The
+    /// arithmetic wraps for integer vectors.
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let v = u32s::iota(10, 2);
+    /// for i in 0..u32s::WIDTH {
+    ///     assert_eq!(v.extract(i), 10 + 2 * i as u32);
+    /// }
+    /// # }
+    /// ```
+    fn iota(start: Self::Scalar, step: Self::Scalar) -> Self;
+
+    /// Return a vector whose element at index `i` is `f(i)`. `f` is called
+    /// once for each lane, in ascending order.
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(f: F) -> Self;
+
+    /// Return a vector whose element at index `i` is `data[i]`.
+    fn from_array(data: Self::Array) -> Self;
 }
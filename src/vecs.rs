@@ -40,6 +40,16 @@ pub trait Packed : Sized + Copy + Debug + Merge {
     /// at `offset`, without asserting length of data.
     unsafe fn store_unchecked(self, data: &mut [Self::Scalar], offset: usize);
 
+    /// Create a new vector with `Self::WIDTH` elements from `data`, beginning
+    /// at `offset`, without asserting length of data. `&data[offset]` must be
+    /// aligned to the size of the vector.
+    unsafe fn load_aligned_unchecked(data: &[Self::Scalar], offset: usize) -> Self;
+
+    /// Write `Self::WIDTH` elements from this vector to `data`, beginning at
+    /// `offset`, without asserting length of data. `&data[offset]` must be
+    /// aligned to the size of the vector.
+    unsafe fn store_aligned_unchecked(self, data: &mut [Self::Scalar], offset: usize);
+
     /// Assert all elements of the vector are equal, then return the
     /// element. Opposite operation of `Self::splat`.
     fn coalesce(self) -> Self::Scalar;
@@ -77,6 +87,9 @@ pub trait Packed : Sized + Copy + Debug + Merge {
 pub trait Packable where Self : Sized + Copy + Debug {
     type Vector : Packed<Scalar = Self> + Clone;
     const SIZE: usize;
+
+    /// Convert `n` to this type, as if by `n as Self`.
+    fn from_usize(n: usize) -> Self;
 }
 
 // Vector types which aren't interpreted as SIMD vectors, for systems which
@@ -102,6 +115,11 @@ macro_rules! impl_packed {
         impl Packable for $el {
             type Vector = $vec;
             const SIZE: usize = $sz;
+
+            #[inline(always)]
+            fn from_usize(n: usize) -> Self {
+                n as $el
+            }
         }
 
         impl Packed for $vec {
@@ -131,6 +149,20 @@ macro_rules! impl_packed {
                 $vec::write_to_slice_unaligned_unchecked(self, &mut data[offset..]);
             }
 
+            #[inline(always)]
+            unsafe fn load_aligned_unchecked(data: &[$el], offset: usize) -> $vec {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                debug_assert!(data[offset..].as_ptr() as usize % crate::core::mem::size_of::<Self>() == 0);
+                $vec::from_slice_aligned_unchecked(&data[offset..])
+            }
+
+            #[inline(always)]
+            unsafe fn store_aligned_unchecked(self, data: &mut [$el], offset: usize) {
+                debug_assert!(data[offset..].len() >= Self::WIDTH);
+                debug_assert!(data[offset..].as_ptr() as usize % crate::core::mem::size_of::<Self>() == 0);
+                $vec::write_to_slice_aligned_unchecked(self, &mut data[offset..]);
+            }
+
             #[inline(always)]
             fn coalesce(self) -> Self::Scalar {
                 for i in 1..Self::WIDTH {
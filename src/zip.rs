@@ -5,8 +5,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::iters::{SIMDIterator, SIMDIterable, SIMDObject, UnsafeIterator, SIMDSized};
+use crate::iters::{SIMDIterator, SIMDIterable, SIMDObject, UnsafeIterator, SIMDSized, SIMDArrayMut, SIMDIter};
 use crate::vecs::{Packed, Packable};
+use crate::intrin::interleave::Interleave;
 
 /// A macro which takes a number n and an expression, and returns a tuple
 /// containing n copies of the expression. Only works for numbers less than or
@@ -50,12 +51,102 @@ pub struct SIMDZipMap<I, F> where I : SIMDZippedIterator {
     func: F,
 }
 
+/// A lazy mapping iterator which applies its function to a stream of tuples of
+/// vectors, yielding a tuple of vectors.
+pub struct SIMDZipMapTuple<I, F> where I : SIMDZippedIterator {
+    iter: I,
+    func: F,
+}
+
+/// An iterator which yields arrays of `N` consecutive tuples of vectors of its
+/// contained iterator.
+pub struct SIMDZipUnrolled<T, const N: usize> where T : SIMDZippedIterator, T::Vectors : Copy {
+    iter: T,
+    pending: [T::Vectors; N],
+    pending_len: usize,
+}
+
 /// A trait which can transform a collection of iterators into a `Zip`
 pub trait IntoSIMDZip : Sized {
     /// Return an iterator which may iterate over `self` in lockstep.
     fn zip(self) -> Zip<Self>;
 }
 
+/// A tuple of vectors with the same number of lanes, each of which may be
+/// stored to its own buffer of scalars.
+pub trait PackedTuple : Copy {
+    type Scalars;
+
+    /// A tuple of scalar slices, one for each vector.
+    type Slices<'a> where Self : 'a;
+
+    /// A tuple of scalar vectors, one for each vector.
+    #[cfg(feature = "std")]
+    type Vecs;
+
+    /// Return a tuple of default vectors.
+    fn default() -> Self;
+
+    /// Return the number of lanes in each vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different numbers of lanes.
+    fn width() -> usize;
+
+    /// Return the size of the scalars of the first vector.
+    fn size() -> usize;
+
+    /// Return the length of the shortest slice in `fill`.
+    fn fill_len(fill: &Self::Slices<'_>) -> usize;
+
+    /// Write each vector to its slice in `fill`, beginning at `offset`.
+    fn store(self, fill: &mut Self::Slices<'_>, offset: usize);
+
+    /// Write each vector to its slice in `fill`, beginning at `offset`,
+    /// without checking the bounds of the slices.
+    unsafe fn store_unchecked(self, fill: &mut Self::Slices<'_>, offset: usize);
+
+    /// Write all but the first `skip` lanes of each vector to its slice in
+    /// `fill`, beginning at `offset`, without checking the bounds of the
+    /// slices.
+    unsafe fn store_skipping_unchecked(self, fill: &mut Self::Slices<'_>, offset: usize, skip: usize);
+
+    /// Return a tuple of vectors of `len` zeroes.
+    #[cfg(feature = "std")]
+    fn vecs(len: usize) -> Self::Vecs;
+
+    /// Return a tuple of slices of every element of `vecs`.
+    #[cfg(feature = "std")]
+    fn as_slices(vecs: &mut Self::Vecs) -> Self::Slices<'_>;
+
+    /// Shorten each vector in `vecs` to `len` elements.
+    #[cfg(feature = "std")]
+    fn truncate(vecs: &mut Self::Vecs, len: usize);
+}
+
+/// A tuple of vectors of the same type, whose elements may be interleaved
+/// into a single buffer of scalars.
+pub trait InterleavedTuple : PackedTuple {
+    type Scalar : Packable;
+
+    /// The number of vectors in the tuple.
+    const LEN: usize;
+
+    /// Write the interleaved elements of the vectors to `fill`, beginning at
+    /// `offset`.
+    fn store_interleaved(self, fill: &mut [Self::Scalar], offset: usize);
+
+    /// Write the interleaved elements of the vectors to `fill`, beginning at
+    /// `offset`, without checking the bounds of `fill`.
+    unsafe fn store_interleaved_unchecked(self, fill: &mut [Self::Scalar], offset: usize);
+
+    /// Write all but the first `skip` lanes of each vector to `fill`,
+    /// interleaved, beginning at `offset`, without checking the bounds of
+    /// `fill`.
+    unsafe fn store_interleaved_skipping_unchecked(self, fill: &mut [Self::Scalar], offset: usize, skip: usize);
+}
+
 pub trait SIMDZippedObject : Sized {
     type Scalars;
     type Vectors;
@@ -111,17 +202,6 @@ pub trait SIMDZippedIterable : SIMDZippedObject + ExactSizeIterator<Item = <Self
     //         iter: self,
     //     }
     // }
-
-    // #[inline(always)]
-    // /// Create an iterator which returns `amt` vectors at a time.
-    // fn unroll<'a>(&'a mut self, amt: usize) -> Unrolled<'a, Self> {
-    //     assert!(amt <= 8);
-    //     Unrolled {
-    //         iter: self,
-    //         amt: amt,
-    //         scratch: [<Self as SIMDZippedObject>::Vectors::default(); 8]
-    //     }
-    // }
 }
 
 /// An iterator which automatically packs the values it iterates over into SIMD
@@ -143,6 +223,244 @@ pub trait SIMDZippedIterator : SIMDZippedIterable {
         }
     }
 
+    /// Return an iterator which calls `func` on vectors of elements, where
+    /// `func` returns a tuple of vectors.
+    #[inline(always)]
+    fn simd_map_tuple<A, F>(self, func: F) -> SIMDZipMapTuple<Self, F>
+        where F : FnMut(Self::Vectors) -> A, A : PackedTuple {
+        SIMDZipMapTuple {
+            iter: self,
+            func: func,
+        }
+    }
+
+    /// Take an iterator of tuples of SIMD vectors and store each element of
+    /// the tuples in-order in its own slice of `fill`, returning the number of
+    /// scalars stored in each slice. Only the elements corresponding to an
+    /// element of this iterator are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let data = [3i32, 1, 4, 1, 5, 9, 2, 6, 5];
+    /// let mut doubled = [0i32; 9];
+    /// let mut squared = [0i32; 9];
+    /// let len = (&data[..]).simd_iter(i32s(0))
+    ///     .simd_map_tuple(|v| (v + v, v * v))
+    ///     .unzip_into((&mut doubled[..], &mut squared[..]));
+    /// assert_eq!(len, 9);
+    /// assert_eq!(doubled, [6, 2, 8, 2, 10, 18, 4, 12, 10]);
+    /// assert_eq!(squared, [9, 1, 16, 1, 25, 81, 4, 36, 25]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a slice of `fill` is too short to hold its results.
+    #[inline(always)]
+    fn unzip_into(&mut self, mut fill: <Self::Vectors as PackedTuple>::Slices<'_>) -> usize
+        where Self::Vectors : PackedTuple {
+        let width = <Self::Vectors as PackedTuple>::width();
+        let mut offset = 0;
+        let mut lastvec = self.default();
+
+        while let Some(vecs) = self.next() {
+            vecs.store(&mut fill, offset);
+            offset += width;
+            lastvec = vecs;
+        }
+
+        if let Some((p, n)) = self.end() {
+            assert!(offset + width - n <= <Self::Vectors as PackedTuple>::fill_len(&fill));
+            unsafe {
+                if offset > 0 {
+                    // We stored a vector in this buffer; overwrite the unused elements
+                    p.store_unchecked(&mut fill, offset - n);
+                    lastvec.store_unchecked(&mut fill, offset - width);
+                } else {
+                    // The buffer may not fit one vector; store elementwise
+                    p.store_skipping_unchecked(&mut fill, offset, n);
+                }
+            }
+            offset += width - n;
+        }
+        offset
+    }
+
+    /// Take an iterator of tuples of SIMD vectors and store each element of
+    /// the tuples in-order in its own Vec.
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    fn scalar_collect_unzip(&mut self) -> <Self::Vectors as PackedTuple>::Vecs
+        where Self::Vectors : PackedTuple {
+        let width = <Self::Vectors as PackedTuple>::width();
+        let mut ret = <Self::Vectors as PackedTuple>::vecs((self.len() + 1) * width);
+        let len = self.unzip_into(<Self::Vectors as PackedTuple>::as_slices(&mut ret));
+        <Self::Vectors as PackedTuple>::truncate(&mut ret, len);
+        ret
+    }
+
+    /// Pack and run `func` over the iterator, storing the vectors it returns
+    /// in-order into `out`, beginning at the position of `out`. Only the
+    /// elements corresponding to an element of this iterator are written, so
+    /// `out` may be exactly as long as the iterators being zipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let a = [1.0f32; 10];
+    /// let b = [2.0f32; 10];
+    /// let c = [3.0f32; 10];
+    /// let mut out = [0.0f32; 10];
+    /// (a.simd_iter(f32s(0.0)), b.simd_iter(f32s(0.0)), c.simd_iter(f32s(0.0))).zip()
+    ///     .simd_map_into(out.simd_iter_mut(f32s(0.0)), |(a, b, c)| a * b + c);
+    /// assert_eq!(out, [5.0f32; 10]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` has a different number of lanes than this iterator,
+    /// or is too short to hold its results.
+    #[inline(always)]
+    fn simd_map_into<A, F>(&mut self, mut out: SIMDIter<A>, mut func: F)
+        where A : SIMDArrayMut, F : FnMut(Self::Vectors) -> A::Vector {
+        let width = out.width();
+        if width != self.width() {
+            panic!("You can only map into an iterator with as many lanes as the zip.");
+        }
+        let start = out.scalar_pos();
+        let mut lastvec = <A::Vector as Packed>::default();
+
+        while let Some(v) = self.next() {
+            let vec = func(v);
+            let offset = out.scalar_pos();
+            out.data.store(vec, offset);
+            out.advance(width);
+            lastvec = vec;
+        }
+
+        if let Some((p, n)) = self.end() {
+            let vec = func(p);
+            let offset = out.scalar_pos();
+            assert!(offset + width - n <= out.scalar_len());
+            if offset - start >= width {
+                // We stored a vector in the output; overwrite the unused elements
+                unsafe {
+                    out.data.store_unchecked(vec, offset - n);
+                    out.data.store_unchecked(lastvec, offset - width);
+                }
+            } else {
+                // The output may not fit one vector; store elementwise
+                for i in 0..(width - n) {
+                    unsafe { out.data.store_scalar_unchecked(vec.extract_unchecked(i + n), offset + i); }
+                }
+            }
+            out.advance(width - n);
+        }
+    }
+
+    /// Take an iterator of tuples of SIMD vectors and store their elements
+    /// in-order in `fill`, interleaving the elements of each tuple. This is
+    /// the inverse of [`Stride::stride_two`] and [`Stride::stride_four`].
+    /// Return the part of `fill` which was written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate faster;
+    /// use faster::*;
+    ///
+    /// # fn main() {
+    /// let complex = [3.0f32, 4.0, 6.0, 8.0, 5.0, 12.0];
+    /// let mut out = [0.0f32; 6];
+    /// (&complex[..]).stride_two((f32s(0.0), f32s(0.0))).zip()
+    ///     .simd_map_tuple(|(re, im)| ((re * re + im * im).sqrt(), im / re))
+    ///     .scalar_fill_interleaved(&mut out[..]);
+    /// assert_eq!(out, [5.0, 4.0 / 3.0, 10.0, 4.0 / 3.0, 13.0, 12.0 / 5.0]);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fill` is too short to hold the results.
+    ///
+    /// [`Stride::stride_two`]: ../stride/trait.Stride.html#tymethod.stride_two
+    /// [`Stride::stride_four`]: ../stride/trait.Stride.html#tymethod.stride_four
+    #[inline(always)]
+    fn scalar_fill_interleaved<'a>(&mut self, fill: &'a mut [<Self::Vectors as InterleavedTuple>::Scalar])
+                                   -> &'a mut [<Self::Vectors as InterleavedTuple>::Scalar]
+        where Self::Vectors : InterleavedTuple {
+        let width = <Self::Vectors as PackedTuple>::width();
+        let len = <Self::Vectors as InterleavedTuple>::LEN;
+        let mut offset = 0;
+        let mut lastvec = self.default();
+
+        while let Some(vecs) = self.next() {
+            vecs.store_interleaved(fill, offset * len);
+            offset += width;
+            lastvec = vecs;
+        }
+
+        if let Some((p, n)) = self.end() {
+            assert!((offset + width - n) * len <= fill.len());
+            unsafe {
+                if offset > 0 {
+                    // We stored a vector in this buffer; overwrite the unused elements
+                    p.store_interleaved_unchecked(fill, (offset - n) * len);
+                    lastvec.store_interleaved_unchecked(fill, (offset - width) * len);
+                } else {
+                    // The buffer may not fit the vectors; store elementwise
+                    p.store_interleaved_skipping_unchecked(fill, offset * len, n);
+                }
+            }
+            offset += width - n;
+        }
+        &mut fill[..offset * len]
+    }
+
+    /// Take an iterator of tuples of SIMD vectors and store their elements
+    /// in-order in a Vec, interleaving the elements of each tuple.
+    #[inline(always)]
+    #[cfg(feature = "std")]
+    fn scalar_collect_interleaved(&mut self) -> Vec<<Self::Vectors as InterleavedTuple>::Scalar>
+        where Self::Vectors : InterleavedTuple {
+        let width = <Self::Vectors as PackedTuple>::width();
+        let len = <Self::Vectors as InterleavedTuple>::LEN;
+        let mut ret = vec![<<Self::Vectors as InterleavedTuple>::Scalar as Packable>::from_usize(0); (self.len() + 1) * width * len];
+        let written = self.scalar_fill_interleaved(&mut ret[..]).len();
+        ret.truncate(written);
+        ret
+    }
+
+    /// Return an iterator which yields arrays of `N` consecutive tuples of
+    /// vectors of this iterator. The tuples which don't fill a whole array are
+    /// returned by [`SIMDZipUnrolled::end`], along with the partial tuple of
+    /// this iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// [`SIMDZipUnrolled::end`]: struct.SIMDZipUnrolled.html#method.end
+    #[inline(always)]
+    fn unroll<const N: usize>(self) -> SIMDZipUnrolled<Self, N> where Self::Vectors : Copy {
+        assert!(N > 0, "can't unroll into groups of zero vectors");
+        SIMDZipUnrolled {
+            pending: [self.default(); N],
+            pending_len: 0,
+            iter: self,
+        }
+    }
+
     /// Pack and run `func` over the iterator, returning no value and not
     /// modifying the iterator.
     #[inline(always)]
@@ -370,6 +688,280 @@ where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : Packed {
     }
 }
 
+impl<I, F, A> Iterator for SIMDZipMapTuple<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    type Item = A;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.func)
+    }
+}
+
+impl<I, F, A> ExactSizeIterator for SIMDZipMapTuple<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, F, A> SIMDZippedObject for SIMDZipMapTuple<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    type Vectors = A;
+    type Scalars = A::Scalars;
+
+    #[inline(always)]
+    fn width(&self) -> usize {
+        A::width()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        A::size()
+    }
+}
+
+impl<I, F, A> SIMDZippedIterable for SIMDZipMapTuple<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn scalar_pos(&self) -> usize {
+        self.iter.scalar_pos()
+    }
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.scalar_len()
+    }
+
+    #[inline(always)]
+    fn advance(&mut self, amount: usize) {
+        self.iter.advance(amount)
+    }
+
+    #[inline(always)]
+    fn default(&self) -> Self::Vectors {
+        A::default()
+    }
+}
+
+impl<I, F, A> SIMDZippedIterator for SIMDZipMapTuple<I, F>
+    where I : SIMDZippedIterator, F : FnMut(I::Vectors) -> A, A : PackedTuple {
+    #[inline(always)]
+    fn end(&mut self) -> Option<(Self::Vectors, usize)> {
+        let (v, n) = self.iter.end()?;
+        let nr = n * self.iter.size() / A::size();
+        Some(((self.func)(v), nr))
+    }
+}
+
+macro_rules! impl_packed_tuple {
+    (($($a:tt),*), ($($n:tt),*)) => (
+        impl<$($a),*> PackedTuple for ($($a),*) where $($a : Packed),* {
+            type Scalars = ($($a::Scalar),*);
+            type Slices<'a> = ($(&'a mut [$a::Scalar]),*) where Self : 'a;
+            #[cfg(feature = "std")]
+            type Vecs = ($(Vec<$a::Scalar>),*);
+
+            #[inline(always)]
+            fn default() -> Self {
+                ($(<$a as Packed>::default()),*)
+            }
+
+            #[inline(always)]
+            fn width() -> usize {
+                let widths = [$($a::WIDTH),*];
+                if widths.iter().any(|w| *w != widths[0]) {
+                    panic!("You can only unzip vectors with the same number of lanes.");
+                }
+                widths[0]
+            }
+
+            #[inline(always)]
+            fn size() -> usize {
+                [$(<$a::Scalar as Packable>::SIZE),*][0]
+            }
+
+            #[inline(always)]
+            fn fill_len(fill: &Self::Slices<'_>) -> usize {
+                let lens = [$(fill.$n.len()),*];
+                lens.iter().fold(lens[0], |acc, len| acc.min(*len))
+            }
+
+            #[inline(always)]
+            fn store(self, fill: &mut Self::Slices<'_>, offset: usize) {
+                $(<$a as Packed>::store(self.$n, &mut *fill.$n, offset);)*
+            }
+
+            #[inline(always)]
+            unsafe fn store_unchecked(self, fill: &mut Self::Slices<'_>, offset: usize) {
+                $(<$a as Packed>::store_unchecked(self.$n, &mut *fill.$n, offset);)*
+            }
+
+            #[inline(always)]
+            unsafe fn store_skipping_unchecked(self, fill: &mut Self::Slices<'_>, offset: usize, skip: usize) {
+                $(
+                    for i in skip..$a::WIDTH {
+                        *fill.$n.get_unchecked_mut(offset + i - skip) = <$a as Packed>::extract_unchecked(&self.$n, i);
+                    }
+                )*
+            }
+
+            #[inline(always)]
+            #[cfg(feature = "std")]
+            fn vecs(len: usize) -> Self::Vecs {
+                ($(vec![<$a::Scalar as Packable>::from_usize(0); len]),*)
+            }
+
+            #[inline(always)]
+            #[cfg(feature = "std")]
+            fn as_slices(vecs: &mut Self::Vecs) -> Self::Slices<'_> {
+                ($(&mut vecs.$n[..]),*)
+            }
+
+            #[inline(always)]
+            #[cfg(feature = "std")]
+            fn truncate(vecs: &mut Self::Vecs, len: usize) {
+                $(vecs.$n.truncate(len);)*
+            }
+        }
+    );
+}
+
+impl<A> InterleavedTuple for (A, A) where A : Packed + Interleave {
+    type Scalar = A::Scalar;
+    const LEN: usize = 2;
+
+    #[inline(always)]
+    fn store_interleaved(self, fill: &mut [A::Scalar], offset: usize) {
+        let (a, b) = self.0.interleave_two(self.1);
+        <A as Packed>::store(a, fill, offset);
+        <A as Packed>::store(b, fill, offset + A::WIDTH);
+    }
+
+    #[inline(always)]
+    unsafe fn store_interleaved_unchecked(self, fill: &mut [A::Scalar], offset: usize) {
+        let (a, b) = self.0.interleave_two(self.1);
+        <A as Packed>::store_unchecked(a, fill, offset);
+        <A as Packed>::store_unchecked(b, fill, offset + A::WIDTH);
+    }
+
+    #[inline(always)]
+    unsafe fn store_interleaved_skipping_unchecked(self, fill: &mut [A::Scalar], offset: usize, skip: usize) {
+        for i in skip..A::WIDTH {
+            let j = offset + (i - skip) * 2;
+            *fill.get_unchecked_mut(j) = <A as Packed>::extract_unchecked(&self.0, i);
+            *fill.get_unchecked_mut(j + 1) = <A as Packed>::extract_unchecked(&self.1, i);
+        }
+    }
+}
+
+impl<A> InterleavedTuple for (A, A, A, A) where A : Packed + Interleave {
+    type Scalar = A::Scalar;
+    const LEN: usize = 4;
+
+    #[inline(always)]
+    fn store_interleaved(self, fill: &mut [A::Scalar], offset: usize) {
+        let (a, b, c, d) = self.0.interleave_four(self.1, self.2, self.3);
+        <A as Packed>::store(a, fill, offset);
+        <A as Packed>::store(b, fill, offset + A::WIDTH);
+        <A as Packed>::store(c, fill, offset + A::WIDTH * 2);
+        <A as Packed>::store(d, fill, offset + A::WIDTH * 3);
+    }
+
+    #[inline(always)]
+    unsafe fn store_interleaved_unchecked(self, fill: &mut [A::Scalar], offset: usize) {
+        let (a, b, c, d) = self.0.interleave_four(self.1, self.2, self.3);
+        <A as Packed>::store_unchecked(a, fill, offset);
+        <A as Packed>::store_unchecked(b, fill, offset + A::WIDTH);
+        <A as Packed>::store_unchecked(c, fill, offset + A::WIDTH * 2);
+        <A as Packed>::store_unchecked(d, fill, offset + A::WIDTH * 3);
+    }
+
+    #[inline(always)]
+    unsafe fn store_interleaved_skipping_unchecked(self, fill: &mut [A::Scalar], offset: usize, skip: usize) {
+        for i in skip..A::WIDTH {
+            let j = offset + (i - skip) * 4;
+            *fill.get_unchecked_mut(j) = <A as Packed>::extract_unchecked(&self.0, i);
+            *fill.get_unchecked_mut(j + 1) = <A as Packed>::extract_unchecked(&self.1, i);
+            *fill.get_unchecked_mut(j + 2) = <A as Packed>::extract_unchecked(&self.2, i);
+            *fill.get_unchecked_mut(j + 3) = <A as Packed>::extract_unchecked(&self.3, i);
+        }
+    }
+}
+
+impl_packed_tuple!((A, B), (0, 1));
+impl_packed_tuple!((A, B, C), (0, 1, 2));
+impl_packed_tuple!((A, B, C, D), (0, 1, 2, 3));
+
+impl<T, const N: usize> SIMDZipUnrolled<T, N> where T : SIMDZippedIterator, T::Vectors : Copy {
+    #[inline(always)]
+    pub fn chunk_len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    pub fn chunk_pos(&self) -> usize {
+        self.iter.vector_pos() / self.chunk_len()
+    }
+
+    /// Return the tuples left over once this iterator stops yielding arrays,
+    /// followed by the partial tuple of the contained iterator, the number of
+    /// those tuples, and the number of elements at the start of each vector
+    /// of the last of them which are the default, or None if there are no
+    /// tuples. Tuples past that number are the contained iterator's default.
+    ///
+    /// This must only be called once `next` has returned None, as the tuples
+    /// of the contained iterator which haven't been grouped yet would be lost.
+    #[inline(always)]
+    pub fn end(&mut self) -> Option<([T::Vectors; N], usize, usize)> {
+        debug_assert!(self.iter.next().is_none(), "end() called before next() returned None");
+        let mut ret = self.pending;
+        let mut n = self.pending_len;
+        let mut empty = 0;
+        self.pending = [self.iter.default(); N];
+        self.pending_len = 0;
+
+        // Fewer than N tuples are pending once next() has returned None
+        if let Some((v, e)) = self.iter.end() {
+            ret[n] = v;
+            n += 1;
+            empty = e;
+        }
+        if n > 0 { Some((ret, n, empty)) } else { None }
+    }
+
+    /// Return the iterator this iterator was unrolled from.
+    #[inline(always)]
+    pub fn reroll(self) -> T {
+        self.iter
+    }
+}
+
+impl<T, const N: usize> Iterator for SIMDZipUnrolled<T, N>
+    where T : SIMDZippedIterator, T::Vectors : Copy {
+    type Item = [T::Vectors; N];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_len > 0 {
+            return None;
+        }
+        let mut ret = [self.iter.default(); N];
+        for i in 0..N {
+            if let Some(vecs) = self.iter.next() {
+                ret[i] = vecs;
+            } else {
+                // Hold onto the incomplete group for end()
+                self.pending = ret;
+                self.pending_len = i;
+                return None;
+            }
+        }
+        Some(ret)
+    }
+}
+
 impl_iter_zip!((A, B),
                (AA, BB),
                (1));
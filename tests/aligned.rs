@@ -0,0 +1,111 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![feature(test)]
+
+extern crate faster;
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use faster::*;
+    use faster::aligned::ALIGNMENT;
+
+    #[test]
+    fn layout() {
+        for len in 0..100 {
+            let data: Vec<u16> = (0..len as u16).collect();
+            let aligned = AlignedVec::from_slice(&data[..], 7);
+            assert_eq!(&aligned[..], &data[..]);
+            assert_eq!(aligned.as_padded_slice().as_ptr() as usize % ALIGNMENT, 0);
+            assert_eq!(aligned.padded_len() * 2 % ALIGNMENT, 0);
+            assert!(aligned.padded_len() - len < ALIGNMENT / 2);
+            assert!(aligned.as_padded_slice()[len..].iter().all(|x| *x == 7));
+            assert_eq!(&aligned.clone()[..], &data[..]);
+        }
+    }
+
+    #[test]
+    fn iterate_logical() {
+        for len in 0..100 {
+            let data: Vec<u32> = (0..len as u32).collect();
+            let mut aligned = AlignedVec::from_slice(&data[..], 0);
+            aligned.fill_padding(1000);
+
+            // The padding isn't read, and the default fills the last vector.
+            let empty = (u32s::WIDTH - len % u32s::WIDTH) % u32s::WIDTH;
+            let sum = aligned.simd_iter(u32s(7))
+                .simd_reduce(u32s(0), |acc, v| acc + v)
+                .sum();
+            assert_eq!(sum, data.iter().sum::<u32>() + 7 * empty as u32);
+
+            let sum = aligned.simd_iter_as::<u32x4>()
+                .simd_reduce(u32x4::splat(0), |acc, v| acc + v)
+                .sum();
+            assert_eq!(sum, data.iter().sum::<u32>());
+
+            // Every element is visited once, and the padding is untouched.
+            aligned.simd_iter_mut(u32s(0)).simd_for_each(|v| *v = *v * u32s(2));
+            let expected: Vec<u32> = data.iter().map(|x| x * 2).collect();
+            assert_eq!(&aligned[..], &expected[..]);
+            assert!(aligned.as_padded_slice()[len..].iter().all(|x| *x == 1000));
+
+            let collected = aligned.simd_iter(u32s(0)).simd_map(|v| v).scalar_collect();
+            assert_eq!(collected.len(), len);
+            assert_eq!(&collected[..], &expected[..]);
+        }
+    }
+
+    #[test]
+    fn tail() {
+        for len in 1..100 {
+            let data: Vec<u8> = (0..len as u8).collect();
+            let mut aligned = AlignedVec::from_slice(&data[..], 0);
+            aligned.fill_padding(200);
+
+            // The last vector holds the last elements after the default's.
+            let mut iter = aligned.simd_iter(u8s(100));
+            while iter.next().is_some() {}
+            if let Some((v, n)) = iter.end() {
+                assert_eq!(n, u8s::WIDTH - len % u8s::WIDTH);
+                for i in 0..u8s::WIDTH {
+                    let expected = if i < n { 100 } else { data[i + len - u8s::WIDTH] };
+                    assert_eq!(v.extract(i), expected);
+                }
+            }
+
+            let mut iter = aligned.simd_iter_as::<u8x64>();
+            while iter.next().is_some() {}
+            if let Some((v, n)) = iter.end() {
+                assert_eq!(n, 64 - len % 64);
+                for i in 0..64 {
+                    let expected = if i < n { 0 } else { data[i + len - 64] };
+                    assert_eq!(v.extract(i), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        let aligned = AlignedVec::from_elem(1u32, 10);
+        assert_eq!(aligned.load_scalar(9), 1);
+        assert_eq!(aligned.load_as::<u32x4>(6), u32x4::splat(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn load_scalar_padding() {
+        AlignedVec::from_elem(1u32, 10).load_scalar(10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn load_as_padding() {
+        AlignedVec::from_elem(1u32, 10).load_as::<u32x4>(8);
+    }
+}
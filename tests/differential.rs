@@ -0,0 +1,433 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Checks every intrinsic against a plain-Rust model of each lane, using edge
+//! cases and pseudo-random inputs. Run with `--features force-unknown-arch` to
+//! check the polyfills against the same models.
+
+#![feature(test,stdsimd)]
+
+extern crate faster;
+
+#[cfg(test)]
+mod tests {
+    use faster::*;
+    use std::fmt::Debug;
+
+    const SEED: u64 = 0x9E3779B97F4A7C15;
+    const RANDOM_INPUTS: usize = 1000;
+
+    /// Intel documents a relative error of at most 1.5 * 2^-12 for `rcpps`
+    /// and `rsqrtps`, which is at most 6144 ULPs of an `f32`.
+    const ESTIMATE_ULPS: u32 = 6144;
+
+    /// A xorshift generator, so every run sees the same inputs.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
+
+    trait Arbitrary : Copy + Debug + PartialEq {
+        /// Values which are likely to expose bugs in an intrinsic.
+        fn edges() -> Vec<Self>;
+
+        fn random(rng: &mut Rng) -> Self;
+
+        /// Whether two results are identical, treating all NaNs as equal.
+        fn same(&self, other: &Self) -> bool {
+            self == other
+        }
+    }
+
+    macro_rules! impl_arbitrary_int {
+        ($($el:ident),*) => ($(
+            impl Arbitrary for $el {
+                fn edges() -> Vec<Self> {
+                    vec![$el::min_value(), $el::min_value().wrapping_add(1), 0, 1,
+                         (0 as $el).wrapping_sub(1), $el::max_value() / 2, $el::max_value() / 2 + 1,
+                         $el::max_value() - 1, $el::max_value(), 0x55u8 as $el, 0xAAu8 as $el]
+                }
+
+                fn random(rng: &mut Rng) -> Self {
+                    rng.next_u64() as $el
+                }
+            }
+        )*)
+    }
+
+    macro_rules! impl_arbitrary_float {
+        ($($el:ident, $bits:ident),*) => ($(
+            impl Arbitrary for $el {
+                fn edges() -> Vec<Self> {
+                    use std::$el::*;
+                    vec![NAN, -NAN, INFINITY, NEG_INFINITY, 0.0, -0.0, MIN_POSITIVE, -MIN_POSITIVE,
+                         MIN_POSITIVE / 2.0, $el::from_bits(1), -$el::from_bits(1), MAX, MIN,
+                         EPSILON, 1.0, -1.0, 0.5, -0.5, 1.5, 2.5, -2.5, 0.49999997, 1e10, -1e-10]
+                }
+
+                fn random(rng: &mut Rng) -> Self {
+                    // Half arbitrary bit patterns, and half small numbers with
+                    // fractional parts.
+                    if rng.next_u64() & 1 == 0 {
+                        $el::from_bits(rng.next_u64() as $bits)
+                    } else {
+                        (rng.next_u64() % 2_000_000) as $el / 1000.0 - 1000.0
+                    }
+                }
+
+                fn same(&self, other: &Self) -> bool {
+                    (self.is_nan() && other.is_nan()) || self.to_bits() == other.to_bits()
+                }
+            }
+        )*)
+    }
+
+    impl_arbitrary_int!(u8, i8, u16, i16, u32, i32, u64, i64);
+    impl_arbitrary_float!(f32, u32, f64, u64);
+
+    /// Every rotation of the edge cases, so each one appears in every lane.
+    fn rotations<T: Arbitrary>(width: usize) -> Vec<Vec<T>> {
+        let edges = T::edges();
+        (0..edges.len())
+            .map(|k| (0..width).map(|i| edges[(k + i) % edges.len()]).collect())
+            .collect()
+    }
+
+    fn random<T: Arbitrary>(width: usize, rng: &mut Rng) -> Vec<T> {
+        (0..width).map(|_| T::random(rng)).collect()
+    }
+
+    fn lanes<V: Packed>(v: V) -> Vec<V::Scalar> {
+        (0..V::WIDTH).map(|i| v.extract(i)).collect()
+    }
+
+    fn concat<V: Packed>((a, b): (V, V)) -> Vec<V::Scalar> {
+        let mut ret = lanes(a);
+        ret.extend(lanes(b));
+        ret
+    }
+
+    fn map<T: Copy, U>(xs: &[T], f: impl Fn(T) -> U) -> Vec<U> {
+        xs.iter().map(|&x| f(x)).collect()
+    }
+
+    fn zip<T: Copy, U>(xs: &[T], ys: &[T], f: impl Fn(T, T) -> U) -> Vec<U> {
+        xs.iter().zip(ys.iter()).map(|(&x, &y)| f(x, y)).collect()
+    }
+
+    /// The interleaved horizontal operation described by `HAdd`.
+    fn hop<T: Copy>(xs: &[T], ys: &[T], f: impl Fn(T, T) -> T) -> Vec<T> {
+        (0..xs.len() / 2)
+            .flat_map(|k| vec![f(xs[2 * k], xs[2 * k + 1]), f(ys[2 * k], ys[2 * k + 1])])
+            .collect()
+    }
+
+    fn exact<T: Arbitrary>(got: &T, expected: &T) -> bool {
+        got.same(expected)
+    }
+
+    /// The distance between two floats, in units of least precision.
+    fn ulps(a: f32, b: f32) -> u32 {
+        if a.is_nan() || b.is_nan() {
+            return if a.is_nan() && b.is_nan() { 0 } else { u32::max_value() };
+        }
+        let key = |x: f32| {
+            let bits = x.to_bits() as i64;
+            if bits & 0x8000_0000 != 0 { 0x8000_0000 - bits } else { bits }
+        };
+        (key(a) - key(b)).abs() as u32
+    }
+
+    /// Check an approximation which should be within the given number of
+    /// ULPs. Inputs for which the model is `None` aren't checked.
+    fn approx(got: &f32, expected: &Option<(f32, u32)>) -> bool {
+        match *expected {
+            Some((e, n)) => ulps(*got, e) <= n,
+            None => true,
+        }
+    }
+
+    /// Panic with the first lane of `got` which `ok` rejects.
+    fn compare<T: Debug, E: Debug>(name: &str, input: &dyn Debug, got: &[T], expected: &[E],
+                                   ok: impl Fn(&T, &E) -> bool) {
+        assert_eq!(got.len(), expected.len(), "{} returned {} lanes, but the model returned {}",
+                   name, got.len(), expected.len());
+        for (i, (g, e)) in got.iter().zip(expected.iter()).enumerate() {
+            assert!(ok(g, e), "{} diverges from the scalar model at lane {} of {:?}: got {:?}, expected {:?}",
+                    name, i, input, g, e);
+        }
+    }
+
+    fn unary<V, T, E>(name: &str, op: impl Fn(V) -> Vec<T>,
+                      model: impl Fn(&[V::Scalar]) -> Vec<E>, ok: impl Fn(&T, &E) -> bool)
+        where V: Packed, V::Scalar: Arbitrary, T: Debug, E: Debug {
+        let mut rng = Rng(SEED);
+        let mut inputs = rotations::<V::Scalar>(V::WIDTH);
+        inputs.extend((0..RANDOM_INPUTS).map(|_| random(V::WIDTH, &mut rng)));
+        for input in inputs {
+            compare(name, &input, &op(V::load(&input, 0)), &model(&input), &ok);
+        }
+    }
+
+    /// Like `binary`, but maps every scalar through `domain` first, for
+    /// operations which are only defined over part of their input type.
+    fn binary_within<V, T, E>(name: &str, domain: impl Fn(V::Scalar) -> V::Scalar,
+                              op: impl Fn(V, V) -> Vec<T>,
+                              model: impl Fn(&[V::Scalar], &[V::Scalar]) -> Vec<E>,
+                              ok: impl Fn(&T, &E) -> bool)
+        where V: Packed, V::Scalar: Arbitrary, T: Debug, E: Debug {
+        let mut rng = Rng(SEED);
+        let edges = rotations::<V::Scalar>(V::WIDTH);
+        let mut inputs = Vec::new();
+        for a in edges.iter() {
+            for b in edges.iter() {
+                inputs.push((a.clone(), b.clone()));
+            }
+        }
+        inputs.extend((0..RANDOM_INPUTS).map(|_| (random(V::WIDTH, &mut rng), random(V::WIDTH, &mut rng))));
+        for (a, b) in inputs {
+            let a = map(&a, &domain);
+            let b = map(&b, &domain);
+            compare(name, &(&a, &b), &op(V::load(&a, 0), V::load(&b, 0)), &model(&a, &b), &ok);
+        }
+    }
+
+    fn binary<V, T, E>(name: &str, op: impl Fn(V, V) -> Vec<T>,
+                       model: impl Fn(&[V::Scalar], &[V::Scalar]) -> Vec<E>,
+                       ok: impl Fn(&T, &E) -> bool)
+        where V: Packed, V::Scalar: Arbitrary, T: Debug, E: Debug {
+        binary_within(name, |x| x, op, model, ok)
+    }
+
+    macro_rules! differential_abs {
+        ($($vec:ident, $el:ident, $model:expr, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                unary("abs", |v: $vec| lanes(Abs::abs(&v)), |x: &[$el]| map(x, $model), exact);
+            }
+        )*)
+    }
+
+    differential_abs!(i8s, i8, |x: i8| x.wrapping_abs() as u8, abs_i8s,
+                      i16s, i16, |x: i16| x.wrapping_abs() as u16, abs_i16s,
+                      i32s, i32, |x: i32| x.wrapping_abs() as u32, abs_i32s,
+                      i64s, i64, |x: i64| x.wrapping_abs() as u64, abs_i64s,
+                      f32s, f32, |x: f32| x.abs(), abs_f32s,
+                      f64s, f64, |x: f64| x.abs(), abs_f64s);
+
+    macro_rules! differential_cmp_int {
+        ($($vec:ident, $el:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                binary("max", |a: $vec, b: $vec| lanes(Cmp::max(&a, b)),
+                       |a: &[$el], b: &[$el]| zip(a, b, |x, y| x.max(y)), exact);
+                binary("min", |a: $vec, b: $vec| lanes(Cmp::min(&a, b)),
+                       |a: &[$el], b: &[$el]| zip(a, b, |x, y| x.min(y)), exact);
+            }
+        )*)
+    }
+
+    differential_cmp_int!(u8s, u8, cmp_u8s, i8s, i8, cmp_i8s, u16s, u16, cmp_u16s,
+                          i16s, i16, cmp_i16s, u32s, u32, cmp_u32s, i32s, i32, cmp_i32s);
+
+    macro_rules! differential_cmp_float {
+        ($($vec:ident, $el:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                // Which operand is returned for NaNs and signed zeroes isn't
+                // specified, so accept either of them.
+                let ok = |g: &$el, &(x, y, m): &($el, $el, $el)| {
+                    if x.is_nan() || y.is_nan() { g.is_nan() || g.same(&x) || g.same(&y) } else { *g == m }
+                };
+                binary("max", |a: $vec, b: $vec| lanes(Cmp::max(&a, b)),
+                       |a: &[$el], b: &[$el]| zip(a, b, |x, y| (x, y, x.max(y))), ok);
+                binary("min", |a: $vec, b: $vec| lanes(Cmp::min(&a, b)),
+                       |a: &[$el], b: &[$el]| zip(a, b, |x, y| (x, y, x.min(y))), ok);
+            }
+        )*)
+    }
+
+    differential_cmp_float!(f32s, f32, cmp_f32s, f64s, f64, cmp_f64s);
+
+    macro_rules! differential_saturating {
+        ($($vec:ident, $el:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                binary("saturating_add", |a: $vec, b: $vec| lanes(SaturatingAdd::saturating_add(&a, b)),
+                       |a: &[$el], b: &[$el]| zip(a, b, $el::saturating_add), exact);
+                binary("saturating_sub", |a: $vec, b: $vec| lanes(SaturatingSub::saturating_sub(&a, b)),
+                       |a: &[$el], b: &[$el]| zip(a, b, $el::saturating_sub), exact);
+            }
+        )*)
+    }
+
+    differential_saturating!(u8s, u8, saturating_u8s, i8s, i8, saturating_i8s,
+                             u16s, u16, saturating_u16s, i16s, i16, saturating_i16s);
+
+    macro_rules! differential_saturating_hadd {
+        ($($vec:ident, $el:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                binary("saturating_hadd", |a: $vec, b: $vec| lanes(SaturatingHAdd::saturating_hadd(&a, b)),
+                       |a: &[$el], b: &[$el]| hop(a, b, $el::saturating_add), exact);
+            }
+        )*)
+    }
+
+    differential_saturating_hadd!(u8s, u8, saturating_hadd_u8s, i8s, i8, saturating_hadd_i8s,
+                                  u16s, u16, saturating_hadd_u16s, i16s, i16, saturating_hadd_i16s,
+                                  u32s, u32, saturating_hadd_u32s, i32s, i32, saturating_hadd_i32s,
+                                  u64s, u64, saturating_hadd_u64s, i64s, i64, saturating_hadd_i64s);
+
+    macro_rules! differential_hop {
+        ($($vec:ident, $el:ident, $domain:expr, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                // The polyfills panic on overflow in debug builds, so keep the
+                // inputs small enough not to overflow.
+                binary_within("hadd", $domain, |a: $vec, b: $vec| lanes(HAdd::hadd(&a, b)),
+                              |a: &[$el], b: &[$el]| hop(a, b, |x, y| x + y), exact);
+                binary_within("hsub", $domain, |a: $vec, b: $vec| lanes(HSub::hsub(&a, b)),
+                              |a: &[$el], b: &[$el]| hop(a, b, |x, y| x - y), exact);
+            }
+        )*)
+    }
+
+    // Unsigned hsub underflows whenever the second element of a pair is
+    // larger, so it's only checked for signed and float vectors.
+    differential_hop!(i8s, i8, |x: i8| x / 2, hop_i8s,
+                      i16s, i16, |x: i16| x / 2, hop_i16s,
+                      i32s, i32, |x: i32| x / 2, hop_i32s,
+                      i64s, i64, |x: i64| x / 2, hop_i64s,
+                      f32s, f32, |x: f32| x, hop_f32s,
+                      f64s, f64, |x: f64| x, hop_f64s);
+
+    macro_rules! differential_round {
+        ($($vec:ident, $el:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                // Ties may be rounded away from zero or to even.
+                unary("round", |v: $vec| lanes(Round::round(&v)),
+                      |x: &[$el]| map(x, |x| {
+                          let even = if (x - x.trunc()).abs() == 0.5 { 2.0 * (x / 2.0).round() } else { x.round() };
+                          (x.round(), even)
+                      }),
+                      |g: &$el, &(away, even): &($el, $el)| g.same(&away) || g.same(&even));
+                unary("ceil", |v: $vec| lanes(Round::ceil(&v)), |x: &[$el]| map(x, $el::ceil), exact);
+                unary("floor", |v: $vec| lanes(Round::floor(&v)), |x: &[$el]| map(x, $el::floor), exact);
+                unary("trunc", |v: $vec| lanes(Round::trunc(&v)), |x: &[$el]| map(x, $el::trunc), exact);
+            }
+        )*)
+    }
+
+    differential_round!(f32s, f32, round_f32s, f64s, f64, round_f64s);
+
+    #[test]
+    fn sqrt_f32s() {
+        unary("sqrt", |v: f32s| lanes(Sqrt::sqrt(&v)), |x: &[f32]| map(x, f32::sqrt), exact);
+    }
+
+    #[test]
+    fn sqrt_f64s() {
+        unary("sqrt", |v: f64s| lanes(Sqrt::sqrt(&v)), |x: &[f64]| map(x, f64::sqrt), exact);
+    }
+
+    #[test]
+    fn recip_f32s() {
+        use std::f32::MIN_POSITIVE;
+        unary("recip", |v: f32s| lanes(Recip::recip(&v)), |x: &[f32]| map(x, |x| {
+            if x.is_nan() || x == 0.0 || x.is_infinite() {
+                Some((1.0 / x, 0))
+            } else if x.abs() >= MIN_POSITIVE && x.abs() < 1.0 / MIN_POSITIVE {
+                Some((1.0 / x, ESTIMATE_ULPS))
+            } else {
+                // Hardware estimates flush denormals to zero
+                None
+            }
+        }), approx);
+    }
+
+    #[test]
+    fn rsqrt_f32s() {
+        use std::f32::{MIN_POSITIVE, NAN};
+        unary("rsqrt", |v: f32s| lanes(Rsqrt::rsqrt(&v)), |x: &[f32]| map(x, |x| {
+            if x.is_nan() || x < 0.0 {
+                Some((NAN, 0))
+            } else if x == 0.0 || x.is_infinite() {
+                Some((1.0 / x.sqrt(), 0))
+            } else if x >= MIN_POSITIVE {
+                Some((1.0 / x.sqrt(), ESTIMATE_ULPS))
+            } else {
+                // Hardware estimates flush denormals to zero
+                None
+            }
+        }), approx);
+    }
+
+    macro_rules! differential_upcast {
+        ($($vec:ident, $el:ident, $out:ident, $outel:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                unary("upcast", |v: $vec| concat(<$vec as Upcast<$out>>::upcast(v)),
+                      |x: &[$el]| map(x, |x| x as $outel), exact);
+            }
+        )*)
+    }
+
+    differential_upcast!(u8s, u8, u16s, u16, upcast_u8s,
+                         i8s, i8, i16s, i16, upcast_i8s,
+                         u16s, u16, u32s, u32, upcast_u16s,
+                         i16s, i16, i32s, i32, upcast_i16s,
+                         u32s, u32, u64s, u64, upcast_u32s,
+                         i32s, i32, i64s, i64, upcast_i32s_i64s,
+                         i32s, i32, f64s, f64, upcast_i32s_f64s,
+                         f32s, f32, f64s, f64, upcast_f32s);
+
+    macro_rules! differential_downcast {
+        ($($vec:ident, $el:ident, $out:ident, $outel:ident, $model:expr, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                binary("saturating_downcast", |a: $vec, b: $vec| lanes(<$vec as Downcast<$out>>::saturating_downcast(a, b)),
+                       |a: &[$el], b: &[$el]| {
+                           let mut ret: Vec<$outel> = map(a, $model);
+                           ret.extend(map(b, $model));
+                           ret
+                       }, exact);
+            }
+        )*)
+    }
+
+    differential_downcast!(i16s, i16, i8s, i8, |x: i16| x.max(i8::min_value() as i16).min(i8::max_value() as i16) as i8, downcast_i16s,
+                           u16s, u16, u8s, u8, |x: u16| x.min(u8::max_value() as u16) as u8, downcast_u16s,
+                           i32s, i32, i16s, i16, |x: i32| x.max(i16::min_value() as i32).min(i16::max_value() as i32) as i16, downcast_i32s,
+                           u32s, u32, u16s, u16, |x: u32| x.min(u16::max_value() as u32) as u16, downcast_u32s,
+                           i64s, i64, i32s, i32, |x: i64| x.max(i32::min_value() as i64).min(i32::max_value() as i64) as i32, downcast_i64s,
+                           u64s, u64, u32s, u32, |x: u64| x.min(u32::max_value() as u64) as u32, downcast_u64s,
+                           f64s, f64, f32s, f32, |x: f64| x as f32, downcast_f64s);
+
+    macro_rules! differential_bits {
+        ($($vec:ident, $el:ident, $fn:ident),*) => ($(
+            #[test]
+            fn $fn() {
+                unary("count_ones", |v: $vec| vec![Popcnt::count_ones(&v)],
+                      |x: &[$el]| vec![x.iter().map(|x| x.count_ones() as usize).sum::<usize>()],
+                      |g: &usize, e: &usize| g == e);
+                unary("swap_bytes", |v: $vec| lanes(Reendianize::swap_bytes(&v)),
+                      |x: &[$el]| map(x, $el::swap_bytes), exact);
+            }
+        )*)
+    }
+
+    differential_bits!(u8s, u8, bits_u8s, i8s, i8, bits_i8s, u16s, u16, bits_u16s, i16s, i16, bits_i16s,
+                       u32s, u32, bits_u32s, i32s, i32, bits_i32s, u64s, u64, bits_u64s, i64s, i64, bits_i64s);
+}
@@ -0,0 +1,97 @@
+// This file is part of faster, the SIMD library for humans.
+// Copyright 2017 Adam Niederer <adam.niederer@gmail.com>
+
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![feature(test,stdsimd,avx512_target_feature)]
+
+#[macro_use] extern crate faster;
+
+#[cfg(test)]
+mod tests {
+    use faster::*;
+
+    dispatch! {
+        fn sum_of_squares(data: &[i32]) -> i32 {
+            data.simd_iter_as::<i32s>()
+                .simd_reduce(i32s(0), |acc, v| acc + v * v)
+                .sum()
+        }
+    }
+
+    dispatch! {
+        fn double_into(data: &[f32], out: &mut [f32]) {
+            data.simd_iter_as::<f32s>()
+                .simd_map(|v| v + v)
+                .scalar_fill(out);
+        }
+    }
+
+    dispatch! {
+        fn below(data: &[i32], limit: i32) -> Vec<i32> {
+            data.simd_iter(i32s(0))
+                .simd_filter(|v| v.lt_mask(i32s(limit)))
+        }
+    }
+
+    dispatch! {
+        fn zero_negatives(data: &[f32], out: &mut [f32]) {
+            data.simd_iter(f32s(0.0))
+                .simd_map(|v| v.lt_mask(f32s(0.0)).select(f32s(0.0), v))
+                .scalar_fill(out);
+        }
+    }
+
+    dispatch! {
+        fn widths() -> (usize, usize) {
+            (u8s::WIDTH, m32s::WIDTH)
+        }
+    }
+
+    /// Tests every level supported by this machine in one function, as the
+    /// forced level is shared by every thread.
+    #[test]
+    fn forced_levels() {
+        let ints = (0..1000).collect::<Vec<i32>>();
+        let floats = (0..1000).map(|x| x as f32).collect::<Vec<f32>>();
+        let expected = ints.iter().map(|x| x * x).sum::<i32>();
+
+        for &level in SimdLevel::all().iter().filter(|&&l| l <= SimdLevel::detect()) {
+            SimdLevel::force(Some(level));
+            assert_eq!(SimdLevel::current(), level);
+
+            // Each level's version uses that level's vectors
+            let bytes = match level {
+                SimdLevel::Baseline => u8s::WIDTH,
+                SimdLevel::Sse41 => 16,
+                SimdLevel::Avx2 => 32,
+                SimdLevel::Avx512 => 64,
+            };
+            assert_eq!(widths(), (bytes, bytes / 4));
+
+            for n in 0..64 {
+                assert_eq!(sum_of_squares(&ints[..n]), ints[..n].iter().map(|x| x * x).sum::<i32>());
+            }
+            assert_eq!(sum_of_squares(&ints), expected);
+
+            let mut out = vec![0.0f32; floats.len()];
+            double_into(&floats, &mut out);
+            assert_eq!(out, floats.iter().map(|x| x * 2.0).collect::<Vec<f32>>());
+
+            // Masks and the compresses behind filters are that level's too
+            for n in 0..64 {
+                let mixed = ints[..n].iter().map(|x| if x % 2 == 0 { *x } else { 1000 - x }).collect::<Vec<i32>>();
+                assert_eq!(below(&mixed, 500), mixed.iter().cloned().filter(|&x| x < 500).collect::<Vec<i32>>());
+            }
+
+            let signed = floats.iter().map(|x| x - 500.0).collect::<Vec<f32>>();
+            zero_negatives(&signed, &mut out);
+            assert_eq!(out, signed.iter().map(|&x| if x < 0.0 { 0.0 } else { x }).collect::<Vec<f32>>());
+        }
+
+        SimdLevel::force(None);
+        assert_eq!(SimdLevel::current(), SimdLevel::detect());
+    }
+}
@@ -36,4 +36,347 @@ mod tests {
         let sum = vec.simd_iter(u32s(0u32)).simd_reduce(u32s(0u32), |acc, x| acc + x).sum();
         assert_eq!(sum, 2 * 129);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unroll() {
+        for len in 0..100 {
+            let data: Vec<u32> = (0..len as u32).collect();
+
+            // Whole groups are yielded in order
+            let mut out = vec![0u32; len];
+            let mut iter = data.simd_iter(u32s(0)).unroll::<3>();
+            let mut pos = 0;
+            for group in &mut iter {
+                for v in group.iter() {
+                    v.store(&mut out, pos);
+                    pos += u32s::WIDTH;
+                }
+            }
+            assert_eq!(pos % (3 * u32s::WIDTH), 0);
+            assert_eq!(&out[..pos], &data[..pos]);
+
+            // Everything else comes out of end(), padded with the default
+            let mut iter = data.simd_iter(u32s(1000)).unroll::<3>();
+            for _ in &mut iter {}
+            let rest = match iter.end() {
+                Some((rest, n, empty)) => {
+                    assert!(n > 0 && n <= 3);
+                    assert_eq!((n - 1) * u32s::WIDTH + (u32s::WIDTH - empty), len - pos);
+                    assert!(rest[n..].iter().all(|v| *v == u32s(1000)));
+                    for i in 0..empty {
+                        assert_eq!(rest[n - 1].extract(i), 1000);
+                    }
+                    rest[..n].iter().map(|v| v.sum()).sum::<u32>() - 1000 * empty as u32
+                },
+                None => 0,
+            };
+            assert_eq!(data[..pos].iter().sum::<u32>() + rest, data.iter().sum::<u32>());
+            assert!(iter.end().is_none());
+
+            // Adapters can be unrolled too
+            let mut mapped = data.simd_iter(u32s(0)).simd_map(|v| v * u32s(2)).unroll::<4>();
+            let sum = (&mut mapped).fold(0, |acc, group| acc + group.iter().map(|v| v.sum()).sum::<u32>());
+            let rest = mapped.end().map_or(0, |(rest, n, _)| rest[..n].iter().map(|v| v.sum()).sum::<u32>());
+            assert_eq!(sum + rest, data.iter().sum::<u32>() * 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn unroll_end_early() {
+        let data = [1u32; 100];
+        let mut iter = data.simd_iter(u32s(0)).unroll::<2>();
+        iter.end();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_reduce_n() {
+        fn check<const N: usize>(data: &[u32]) {
+            let expected = data.iter().sum::<u32>();
+            let sum = data.simd_iter(u32s(0))
+                .simd_reduce_n::<N>(u32s(0), |acc, x| acc + x, |a, b| a + b)
+                .sum();
+            assert_eq!(sum, expected);
+        }
+
+        for len in 0..200 {
+            let data: Vec<u32> = (0..len as u32).map(|x| x * 3 + 1).collect();
+            check::<1>(&data[..]);
+            check::<2>(&data[..]);
+            check::<3>(&data[..]);
+            check::<4>(&data[..]);
+            check::<8>(&data[..]);
+            check::<13>(&data[..]);
+        }
+    }
+
+    #[test]
+    fn simd_reduce_portable() {
+        // Model the reduction with PORTABLE_WIDTH scalar accumulators
+        let model = |data: &[f32], default: f32| {
+            let mut lanes = [0.5f32; PORTABLE_WIDTH];
+            let padded = (data.len() + PORTABLE_WIDTH - 1) / PORTABLE_WIDTH * PORTABLE_WIDTH;
+            for i in 0..padded {
+                let x = if i < data.len() { data[i] } else { default };
+                lanes[i % PORTABLE_WIDTH] += x;
+            }
+            let mut step = PORTABLE_WIDTH / 2;
+            while step > 0 {
+                for j in 0..step {
+                    lanes[j] += lanes[j + step];
+                }
+                step /= 2;
+            }
+            lanes[0]
+        };
+
+        let mut data = [0f32; 300];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = 1.0 / (i as f32 + 3.0);
+        }
+
+        for len in 0..data.len() {
+            let sum = (&data[..len]).simd_iter(f32s(0.25))
+                .simd_reduce_portable(0.5, |acc, v| acc + v, |a, b| a + b);
+            assert_eq!(sum.to_bits(), model(&data[..len], 0.25).to_bits());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_filter() {
+        let data: Vec<i32> = (0..200).map(|x| (x * 37) % 23 - 11).collect();
+
+        for len in 0..data.len() {
+            let expected: Vec<i32> = data[..len].iter().cloned().filter(|&x| x > 0).collect();
+
+            // The default vector passes the predicate, so it must be masked
+            // off by the filter rather than by the closure.
+            let filtered = (&data[..len]).simd_iter(i32s(1))
+                .simd_filter(|v| v.gt_mask(i32s(0)));
+            assert_eq!(filtered, expected);
+
+            let mut fill = vec![0i32; expected.len()];
+            let kept = (&data[..len]).simd_iter(i32s(1))
+                .simd_filter_into(&mut fill, |v| v.gt_mask(i32s(0)));
+            assert_eq!(kept, &expected[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_scan() {
+        let data: Vec<u32> = (0..200u32).map(|x| x.wrapping_mul(0x9E3779B9)).collect();
+
+        for len in 0..data.len() {
+            let mut inclusive = Vec::with_capacity(len);
+            let mut exclusive = Vec::with_capacity(len);
+            let mut acc = 7u32;
+            for &x in data[..len].iter() {
+                exclusive.push(acc);
+                acc = acc.wrapping_add(x);
+                inclusive.push(acc);
+            }
+
+            // A non-zero default must not leak into the totals.
+            let scanned = (&data[..len]).simd_iter(u32s(3))
+                .simd_scan(7)
+                .scalar_collect();
+            assert_eq!(scanned, inclusive);
+
+            let scanned = (&data[..len]).simd_iter(u32s(3))
+                .simd_scan_exclusive(7)
+                .scalar_collect();
+            assert_eq!(scanned, exclusive);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_scan_float() {
+        // Small integers are summed exactly in any order
+        let data: Vec<f32> = (0..100).map(|x| (x % 7) as f32).collect();
+        let mut acc = 0.0f32;
+        let expected: Vec<f32> = data.iter().map(|x| { acc += x; acc }).collect();
+
+        let scanned = (&data[..]).simd_iter(f32s(1.0))
+            .simd_scan(0.0)
+            .scalar_collect();
+        assert_eq!(scanned, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_enumerate() {
+        let data: Vec<u32> = (0..100u32).map(|x| x.wrapping_mul(0x9E3779B9)).collect();
+
+        for len in 0..data.len() {
+            let expected: Vec<u32> = data[..len].iter().enumerate()
+                .map(|(i, x)| x ^ i as u32)
+                .collect();
+
+            let enumerated = (&data[..len]).simd_iter(u32s(3))
+                .simd_enumerate()
+                .simd_map(|(i, v)| v ^ i)
+                .scalar_collect();
+            assert_eq!(enumerated, expected);
+        }
+    }
+
+    #[test]
+    fn patterns() {
+        let mut array = [0u16; u16s::WIDTH];
+        for (i, x) in array.iter_mut().enumerate() {
+            *x = (i * 3) as u16;
+        }
+        let v = u16s::iota(0, 3);
+        assert_eq!(v, u16s::from_fn(|i| (i * 3) as u16));
+        assert_eq!(v, u16s::from_array(array));
+        assert_eq!(u8s::iota(255, 1).extract(1), 0);
+        assert_eq!(f32s::iota(0.5, -1.0).extract(f32s::WIDTH - 1), 0.5 - (f32s::WIDTH - 1) as f32);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn simd_iter_as() {
+        let data: Vec<u32> = (0..37u32).map(|x| x * 7 + 1).collect();
+
+        for len in 0..data.len() {
+            let expected: Vec<u32> = data[..len].iter().map(|x| x ^ 0xFF).collect();
+            let mapped = (&data[..len]).simd_iter_as::<u32x4>()
+                .simd_map(|v| v ^ u32x4::splat(0xFF))
+                .scalar_collect();
+            assert_eq!(mapped, expected);
+
+            let sum = data[..len].simd_iter_as::<u32x4>()
+                .simd_reduce(u32x4::splat(0), |acc, v| acc + v)
+                .sum();
+            assert_eq!(sum, data[..len].iter().sum::<u32>());
+
+            // The wider types work on every architecture
+            let mapped = (&data[..len]).simd_iter_as::<u32x8>()
+                .simd_map(|v| v ^ u32x8::splat(0xFF))
+                .scalar_collect();
+            assert_eq!(mapped, expected);
+
+            let sum = data[..len].simd_iter_as::<u32x16>()
+                .simd_reduce(u32x16::splat(0), |acc, v| acc + v)
+                .scalar_reduce(0, |acc, x| acc + x);
+            assert_eq!(sum, data[..len].iter().sum::<u32>());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn scalar_fill_streaming() {
+        let data: Vec<u32> = (0..100u32).map(|x| x.wrapping_mul(0x9E3779B9)).collect();
+
+        for len in 0..data.len() {
+            let expected: Vec<u32> = data[..len].iter().map(|x| x ^ 5).collect();
+
+            // Aligned, so every full vector is streamed
+            let mut aligned = AlignedVec::from_elem(0u32, len);
+            (&data[..len]).simd_iter(u32s(0))
+                .simd_map(|v| v ^ u32s(5))
+                .scalar_fill_streaming(&mut aligned[..]);
+            assert_eq!(&aligned[..], &expected[..]);
+
+            // Misaligned, so the vectors after the first aligned element are
+            // stitched together and streamed
+            for skip in 1..u32s::WIDTH {
+                let mut unaligned = AlignedVec::from_elem(!0u32, len + skip + 1);
+                (&data[..len]).simd_iter(u32s(0))
+                    .simd_map(|v| v ^ u32s(5))
+                    .scalar_fill_streaming(&mut unaligned[skip..skip + len]);
+                assert_eq!(&unaligned[skip..skip + len], &expected[..]);
+                assert!(unaligned[..skip].iter().all(|x| *x == !0));
+                assert_eq!(unaligned[skip + len], !0);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn prefetch() {
+        let data: Vec<u32> = (0..1000u32).collect();
+        for &locality in [Locality::L1, Locality::L2, Locality::L3, Locality::NonTemporal].iter() {
+            // Prefetching past the end of the data is harmless
+            for &distance in [0, 1, 64, 5000].iter() {
+                let sum = (&data[..]).simd_iter(u32s(0))
+                    .prefetch(distance, locality)
+                    .simd_reduce(u32s(0), |acc, v| acc + v)
+                    .sum();
+                assert_eq!(sum, data.iter().sum::<u32>());
+
+                let (evens, odds) = (&data[..]).stride_two((u32s(0), u32s(0)));
+                let evens = evens.prefetch(distance, locality).scalar_collect();
+                let odds = odds.prefetch(distance, locality).scalar_collect();
+                assert_eq!(evens, data.iter().cloned().filter(|x| x % 2 == 0).collect::<Vec<u32>>());
+                assert_eq!(odds, data.iter().cloned().filter(|x| x % 2 == 1).collect::<Vec<u32>>());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn gather_scatter() {
+        let table: Vec<f32> = (0..100u32).map(|x| x as f32 * 0.5).collect();
+        let indices = u32s::from_fn(|i| (i as u32 * 37 + 11) % 100);
+        let evens = u32s::from_fn(|i| i as u32 % 2).eq_mask(u32s(0));
+
+        let v = (&table[..]).gather(indices);
+        let aligned = AlignedVec::from_slice(&table[..], 0.0);
+        assert_eq!(aligned.gather(indices), v);
+        for i in 0..f32s::WIDTH {
+            assert_eq!(v.extract(i), table[indices.extract(i) as usize]);
+        }
+
+        // Masked-off indices are neither checked nor read
+        let wild = u32s::from_fn(|i| if i % 2 == 0 { i as u32 } else { !0 });
+        let v = (&table[..]).gather_masked(wild, evens, f32s(-1.0));
+        for i in 0..f32s::WIDTH {
+            assert_eq!(v.extract(i), if i % 2 == 0 { table[i] } else { -1.0 });
+        }
+
+        let mut out = vec![0u64; 100];
+        let values = u64s::from_fn(|i| i as u64 + 1);
+        let wide = u64s::from_fn(|i| (i as u64 * 37 + 11) % 100);
+        (&mut out[..]).scatter(wide, values);
+        for i in 0..u64s::WIDTH {
+            assert_eq!(out[wide.extract(i) as usize], values.extract(i));
+        }
+        assert_eq!((&out[..]).gather(wide), values);
+
+        // The highest lane wins when indices collide
+        let mut out = vec![0u64; 100];
+        (&mut out[..]).scatter(u64s(42), values);
+        assert_eq!(out[42], u64s::WIDTH as u64);
+
+        // Indices of an AlignedVec are checked against its logical length
+        let mut out = AlignedVec::from_elem(0u32, 10);
+        let evens = u32s::from_fn(|i| i as u32 % 2).eq_mask(u32s(0));
+        let wild = u32s::from_fn(|i| if i % 2 == 0 { (i % 10) as u32 } else { !0 });
+        out.scatter_masked(wild, u32s(7), evens);
+        for i in 0..10 {
+            assert_eq!(out[i], if i % 2 == 0 && i < u32s::WIDTH { 7 } else { 0 });
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_out_of_bounds() {
+        let table = [1u32, 2, 3];
+        (&table[..]).gather(u32s(3));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn gather_padding() {
+        let table = AlignedVec::from_elem(1u32, 3);
+        table.gather(u32s(3));
+    }
 }
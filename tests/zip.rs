@@ -75,4 +75,146 @@ mod tests {
         assert_eq!(result, 2 * 3 * 129);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn zip_simd_map_into() {
+        for len in 0..100 {
+            let a: Vec<u32> = (0..len as u32).collect();
+            let b: Vec<u32> = (0..len as u32).map(|x| x * 3).collect();
+            let c: Vec<u32> = (0..len as u32).map(|x| x + 7).collect();
+            let expected: Vec<u32> = (0..len).map(|i| a[i] * b[i] + c[i]).collect();
+
+            // Nothing past the end of the output is touched
+            let mut out = vec![0xDEADBEEFu32; len + 64];
+            (a.simd_iter(u32s(0)), b.simd_iter(u32s(0)), c.simd_iter(u32s(0))).zip()
+                .simd_map_into(out[..len].simd_iter_mut(u32s(0)), |(a, b, c)| a * b + c);
+            assert_eq!(&out[..len], &expected[..]);
+            assert!(out[len..].iter().all(|x| *x == 0xDEADBEEF));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unzip() {
+        for len in 0..100 {
+            let a: Vec<i32> = (0..len as i32).map(|x| x - 50).collect();
+            let b: Vec<i32> = (0..len as i32).map(|x| x * 7).collect();
+            let sums: Vec<i32> = (0..len).map(|i| a[i] + b[i]).collect();
+            let diffs: Vec<i32> = (0..len).map(|i| a[i] - b[i]).collect();
+            let squares: Vec<i32> = a.iter().map(|x| x * x).collect();
+
+            // Nothing past the end of the outputs is touched
+            let mut out_a = vec![0x7EADBEEFi32; len + 64];
+            let mut out_b = vec![0x7EADBEEFi32; len + 64];
+            let written = (a.simd_iter(i32s(0)), b.simd_iter(i32s(0))).zip()
+                .simd_map_tuple(|(a, b)| (a + b, a - b))
+                .unzip_into((&mut out_a[..len], &mut out_b[..len]));
+            assert_eq!(written, len);
+            assert_eq!(&out_a[..len], &sums[..]);
+            assert_eq!(&out_b[..len], &diffs[..]);
+            assert!(out_a[len..].iter().chain(out_b[len..].iter()).all(|x| *x == 0x7EADBEEF));
+
+            let (out_a, out_b, out_c) = a.simd_iter(i32s(0))
+                .simd_map_tuple(|v| (v, v * v, v + i32s(1)))
+                .scalar_collect_unzip();
+            assert_eq!(out_a, a);
+            assert_eq!(out_b, squares);
+            assert_eq!(out_c, a.iter().map(|x| x + 1).collect::<Vec<i32>>());
+
+            let (out_a, out_b) = (a.simd_iter(i32s(0)), b.simd_iter(i32s(0))).zip()
+                .scalar_collect_unzip();
+            assert_eq!(out_a, a);
+            assert_eq!(out_b, b);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn interleaved() {
+        for len in 0..100 {
+            let pairs: Vec<i32> = (0..len as i32 * 2).collect();
+            let expected: Vec<i32> = pairs.iter().enumerate()
+                .map(|(i, x)| if i % 2 == 0 { x * 2 } else { x + 1 })
+                .collect();
+
+            let out = (&pairs[..]).stride_two((i32s(0), i32s(0))).zip()
+                .simd_map_tuple(|(re, im)| (re * i32s(2), im + i32s(1)))
+                .scalar_collect_interleaved();
+            assert_eq!(out, expected);
+
+            // Nothing past the end of the output is touched
+            let mut out = vec![0x7EADBEEFi32; len * 2 + 64];
+            let written = (&pairs[..]).stride_two((i32s(0), i32s(0))).zip()
+                .simd_map_tuple(|(re, im)| (re * i32s(2), im + i32s(1)))
+                .scalar_fill_interleaved(&mut out[..len * 2]).len();
+            assert_eq!(written, len * 2);
+            assert_eq!(&out[..len * 2], &expected[..]);
+            assert!(out[len * 2..].iter().all(|x| *x == 0x7EADBEEF));
+
+            // Interleaving undoes striding
+            let quads: Vec<u16> = (0..len as u16 * 4).collect();
+            let out = (&quads[..]).stride_four((u16s(0), u16s(0), u16s(0), u16s(0))).zip()
+                .scalar_collect_interleaved();
+            assert_eq!(out, quads);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn stride_zip() {
+        for len in 0..100 {
+            let pairs: Vec<u8> = (0..len * 2).map(|x| x as u8).collect();
+            let out = (&pairs[..]).simd_iter(u8s(0)).stride_zip::<2>()
+                .simd_map(|(a, b)| a ^ b)
+                .scalar_collect();
+            let expected: Vec<u8> = pairs.chunks(2).map(|r| r[0] ^ r[1]).collect();
+            assert_eq!(out, expected);
+
+            let rgb: Vec<u8> = (0..len * 3).map(|x| (x * 7) as u8).collect();
+            let out = (&rgb[..]).simd_iter(u8s(0)).stride_zip::<3>()
+                .simd_map(|(r, g, b)| (r >> 2) + (g >> 1) + (b >> 2))
+                .scalar_collect();
+            let expected: Vec<u8> = rgb.chunks(3).map(|p| (p[0] >> 2) + (p[1] >> 1) + (p[2] >> 2)).collect();
+            assert_eq!(out, expected);
+
+            let quads: Vec<i16> = (0..len as i16 * 4).collect();
+            let out = (&quads[..]).simd_iter(i16s(0)).stride_zip::<4>()
+                .simd_map(|(a, b, c, d)| a - b + c - d)
+                .scalar_collect();
+            let expected: Vec<i16> = quads.chunks(4).map(|q| q[0] - q[1] + q[2] - q[3]).collect();
+            assert_eq!(out, expected);
+
+            // The last channel is picked out of each record
+            for n in [0, 1, 7].iter() {
+                let audio: Vec<i32> = (0..len as i32 * 8).map(|x| x * 3 - 100).collect();
+                let out = (&audio[..]).simd_iter(i32s(0)).stride_zip::<8>()
+                    .simd_map(|(a, b, c, d, e, f, g, h)| [a, b, c, d, e, f, g, h][*n])
+                    .scalar_collect();
+                let expected: Vec<i32> = audio.chunks(8).map(|c| c[*n]).collect();
+                assert_eq!(out, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn zip_unroll() {
+        let vec1 = [2u32; 129];
+        let vec2 = [3u32; 129];
+        let mut iter = (vec1.simd_iter(u32s(5u32)), vec2.simd_iter(u32s(7u32))).zip().unroll::<3>();
+        let mut acc = u32s(0u32);
+        for group in &mut iter {
+            for (x, y) in group.iter() {
+                acc = acc + *x * *y;
+            }
+        }
+        let (rest, n, empty) = iter.end().unwrap();
+        assert_eq!(empty, (u32s::WIDTH - 129 % u32s::WIDTH) % u32s::WIDTH);
+        for (x, y) in rest[..n].iter() {
+            acc = acc + *x * *y;
+        }
+        // The empty elements of the last tuple hold the defaults
+        assert_eq!(acc.sum(), 2 * 3 * 129 + 5 * 7 * empty as u32);
+        assert!(iter.end().is_none());
+    }
+
 }